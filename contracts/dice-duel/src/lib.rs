@@ -5,36 +5,35 @@
 //! A two-player dice game where each player rolls two dice.
 //! The player with the highest total wins (ties go to player 1).
 //!
+//! Each player commits to `keccak256(value)` for a secret random 32-byte
+//! value when they roll, and reveals it once both have rolled. The dice are
+//! seeded from both revealed values, so neither player can predict or bias them.
+//! Both phases run through a `game_common::beacon::Beacon`.
+//!
+//! A contract constructed with a randomness provider also requests a value
+//! from it for every session and mixes it into the dice seed, so the dice
+//! can't be known until the provider fulfills the request either. If the
+//! provider still hasn't answered once the reveal deadline has passed, the
+//! dice come from both revealed values alone, so a silent provider can't keep
+//! the stakes locked.
+//!
+//! Rolling and revealing each have an action deadline (the admin-configured
+//! action timeout). Once it passes, a player who acted can `claim_timeout`
+//! against an opponent who didn't.
+//!
+//! Either player can also `resign`, or end the game as a draw with
+//! `offer_draw` and the opponent's `accept_draw`.
+//!
 //! **Game Hub Integration:**
 //! This game is Game Hub-aware and enforces all games to be played through the
 //! Game Hub contract. Games cannot be started or completed without points involvement.
 
+use game_common::beacon::{Beacon, BeaconError, SeedRng};
+pub use game_common::GameOutcome;
 use soroban_sdk::{
-    Address, Bytes, BytesN, Env, IntoVal, contract, contractclient, contracterror, contractimpl,
-    contracttype, vec
+    Address, Bytes, BytesN, Env, IntoVal, contract, contracterror, contractimpl, contracttype, vec
 };
 
-// Import GameHub contract interface
-// This allows us to call into the GameHub contract
-#[contractclient(name = "GameHubClient")]
-pub trait GameHub {
-    fn start_game(
-        env: Env,
-        game_id: Address,
-        session_id: u32,
-        player1: Address,
-        player2: Address,
-        player1_points: i128,
-        player2_points: i128,
-    );
-
-    fn end_game(
-        env: Env,
-        session_id: u32,
-        player1_won: bool
-    );
-}
-
 // ============================================================================
 // Errors
 // ============================================================================
@@ -48,6 +47,32 @@ pub enum Error {
     AlreadyRolled = 3,
     BothPlayersNotRolled = 4,
     GameAlreadyEnded = 5,
+    SessionExists = 6,
+    AlreadyRevealed = 7,
+    CommitmentMismatch = 8,
+    BothPlayersNotRevealed = 9,
+    CommitDeadlinePassed = 10,
+    RevealDeadlinePassed = 11,
+    RandomnessNotReady = 12,
+    DeadlineNotReached = 13,
+    OpponentNotStalled = 14,
+    PlayerStalled = 15,
+    NoDrawOffer = 16,
+}
+
+impl From<BeaconError> for Error {
+    fn from(error: BeaconError) -> Self {
+        match error {
+            BeaconError::NotParty => Error::NotPlayer,
+            BeaconError::AlreadyCommitted => Error::AlreadyRolled,
+            BeaconError::NotAllCommitted => Error::BothPlayersNotRolled,
+            BeaconError::AlreadyRevealed => Error::AlreadyRevealed,
+            BeaconError::CommitmentMismatch => Error::CommitmentMismatch,
+            BeaconError::NotAllRevealed => Error::BothPlayersNotRevealed,
+            BeaconError::CommitDeadlinePassed => Error::CommitDeadlinePassed,
+            BeaconError::RevealDeadlinePassed => Error::RevealDeadlinePassed,
+        }
+    }
 }
 
 // ============================================================================
@@ -61,41 +86,25 @@ pub struct Game {
     pub player2: Address,
     pub player1_points: i128,
     pub player2_points: i128,
-    pub player1_rolled: bool,
-    pub player2_rolled: bool,
+    /// Roll commitments and their reveals
+    pub beacon: Beacon,
     pub player1_die1: Option<u32>,
     pub player1_die2: Option<u32>,
     pub player2_die1: Option<u32>,
     pub player2_die2: Option<u32>,
     pub winner: Option<Address>,
+    /// Player whose draw offer is waiting for the opponent to accept
+    pub draw_offer: Option<Address>,
+    /// Set when the players agreed to a draw, ending the game without a winner
+    pub drawn: bool,
+    /// Last ledger of the current roll or reveal phase
+    pub action_deadline: u32,
 }
 
 #[contracttype]
 #[derive(Clone)]
 pub enum DataKey {
     Game(u32),
-    GameHubAddress,
-    Admin,
-}
-
-// ============================================================================
-// Storage TTL Management
-// ============================================================================
-// TTL (Time To Live) ensures game data doesn't expire unexpectedly
-// Games are stored in temporary storage with a minimum 30-day retention
-
-/// TTL for game storage (30 days in ledgers, ~5 seconds per ledger)
-/// 30 days = 30 * 24 * 60 * 60 / 5 = 518,400 ledgers
-const GAME_TTL_LEDGERS: u32 = 518_400;
-
-// ============================================================================
-// Helper Functions
-// ============================================================================
-
-/// Roll a single die (1-6) using deterministic PRNG
-fn roll_die(env: &Env, seed: BytesN<32>) -> u32 {
-    env.prng().seed(seed.into());
-    env.prng().gen_range::<u64>(1..=6) as u32
 }
 
 // ============================================================================
@@ -112,12 +121,16 @@ impl DiceDuelContract {
     /// # Arguments
     /// * `admin` - Admin address (can upgrade contract)
     /// * `game_hub` - Address of the GameHub contract
-    pub fn __constructor(env: Env, admin: Address, game_hub: Address) {
+    /// * `randomness_provider` - Optional randomness contract mixed into every roll
+    pub fn __constructor(
+        env: Env,
+        admin: Address,
+        game_hub: Address,
+        randomness_provider: Option<Address>,
+    ) {
         // Store admin and GameHub address
-        env.storage().instance().set(&DataKey::Admin, &admin);
-        env.storage()
-            .instance()
-            .set(&DataKey::GameHubAddress, &game_hub);
+        game_common::init(&env, &admin, &game_hub);
+        game_common::init_randomness_provider(&env, &randomness_provider);
     }
 
     /// Start a new game between two players with points.
@@ -145,19 +158,17 @@ impl DiceDuelContract {
             panic!("Cannot play against yourself: Player 1 and Player 2 must be different addresses");
         }
 
+        // Refuse to overwrite a live game stored under the same session ID
+        if env.storage().temporary().has(&DataKey::Game(session_id)) {
+            return Err(Error::SessionExists);
+        }
+
         // Require authentication from both players (they consent to committing points)
         player1.require_auth_for_args(vec![&env, session_id.into_val(&env), player1_points.into_val(&env)]);
         player2.require_auth_for_args(vec![&env, session_id.into_val(&env), player2_points.into_val(&env)]);
 
-        // Get GameHub address
-        let game_hub_addr: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::GameHubAddress)
-            .expect("GameHub address not set");
-
         // Create GameHub client
-        let game_hub = GameHubClient::new(&env, &game_hub_addr);
+        let game_hub = game_common::hub_client(&env);
 
         // Call the Game Hub to start the session and lock points
         // This requires THIS contract's authorization (env.current_contract_address())
@@ -170,19 +181,30 @@ impl DiceDuelContract {
             &player2_points,
         );
 
+        // Ask the randomness provider (if any) for this session's value
+        game_common::request_randomness(&env, game_common::randomness_request_id(session_id, 0));
+
         // Create game (dice not rolled yet - will be generated in reveal_winner)
+        let action_timeout = game_common::get_action_timeout(&env);
         let game = Game {
             player1: player1.clone(),
             player2: player2.clone(),
             player1_points,
             player2_points,
-            player1_rolled: false,
-            player2_rolled: false,
+            beacon: Beacon::new(
+                &env,
+                vec![&env, player1.clone(), player2.clone()],
+                action_timeout,
+                action_timeout,
+            ),
             player1_die1: None,
             player1_die2: None,
             player2_die1: None,
             player2_die2: None,
             winner: None,
+            draw_offer: None,
+            drawn: false,
+            action_deadline: game_common::action_deadline(&env),
         };
 
         // Store game in temporary storage with 30-day TTL
@@ -190,9 +212,7 @@ impl DiceDuelContract {
         env.storage().temporary().set(&game_key, &game);
 
         // Set TTL to ensure game is retained for at least 30 days
-        env.storage()
-            .temporary()
-            .extend_ttl(&game_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+        game_common::extend_game_ttl(&env, &game_key);
 
         // Event emitted by GameHub contract (GameStarted)
 
@@ -200,12 +220,18 @@ impl DiceDuelContract {
     }
 
     /// Commit a roll for the current game.
-    /// Both players must roll before the winner can be revealed.
+    /// Both players must roll and reveal before the winner can be revealed.
     ///
     /// # Arguments
     /// * `session_id` - The session ID of the game
     /// * `player` - Address of the player rolling the dice
-    pub fn roll(env: Env, session_id: u32, player: Address) -> Result<(), Error> {
+    /// * `commitment` - `keccak256(value)` of a secret random 32-byte value
+    pub fn roll(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        commitment: BytesN<32>,
+    ) -> Result<(), Error> {
         player.require_auth();
 
         // Get game from temporary storage
@@ -216,24 +242,17 @@ impl DiceDuelContract {
             .get(&key)
             .ok_or(Error::GameNotFound)?;
 
-        // Check game is still active (no winner yet)
-        if game.winner.is_some() {
+        // Check game is still active (no winner or agreed draw yet)
+        if game.winner.is_some() || game.drawn {
             return Err(Error::GameAlreadyEnded);
         }
 
-        // Update roll commitment for the appropriate player
-        if player == game.player1 {
-            if game.player1_rolled {
-                return Err(Error::AlreadyRolled);
-            }
-            game.player1_rolled = true;
-        } else if player == game.player2 {
-            if game.player2_rolled {
-                return Err(Error::AlreadyRolled);
-            }
-            game.player2_rolled = true;
-        } else {
-            return Err(Error::NotPlayer);
+        // Store roll commitment for the appropriate player
+        game.beacon.commit(&env, &player, commitment)?;
+
+        // The last roll starts the reveal phase
+        if game.beacon.all_committed() {
+            game.action_deadline = game_common::action_deadline(&env);
         }
 
         // Store updated game in temporary storage
@@ -242,8 +261,47 @@ impl DiceDuelContract {
         Ok(())
     }
 
+    /// Reveal the secret value committed in `roll`.
+    /// Only allowed once both players have rolled, so neither value can be
+    /// chosen after seeing the other.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the player revealing
+    /// * `value` - The value whose hash was committed
+    pub fn reveal_roll(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        value: BytesN<32>,
+    ) -> Result<(), Error> {
+        player.require_auth();
+
+        // Get game from temporary storage
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        // Check game is still active (no winner or agreed draw yet)
+        if game.winner.is_some() || game.drawn {
+            return Err(Error::GameAlreadyEnded);
+        }
+
+        // Reveals only start once both rolls are committed, and must match
+        // the player's commitment
+        game.beacon.reveal(&env, &player, Bytes::from(value))?;
+
+        // Store updated game in temporary storage
+        env.storage().temporary().set(&key, &game);
+
+        Ok(())
+    }
+
     /// Reveal the winner of the game and submit outcome to GameHub.
-    /// Can only be called after both players have rolled.
+    /// Can only be called after both players have rolled and revealed.
     /// This generates dice rolls for both players, determines the winner, and ends the session.
     ///
     /// # Arguments
@@ -264,45 +322,36 @@ impl DiceDuelContract {
         if let Some(winner) = &game.winner {
             return Ok(winner.clone());
         }
+        if game.drawn {
+            return Err(Error::GameAlreadyEnded);
+        }
 
-        // Check both players have rolled
-        if !game.player1_rolled || !game.player2_rolled {
+        // Check both players have rolled and revealed
+        if !game.beacon.all_committed() {
             return Err(Error::BothPlayersNotRolled);
         }
 
-        // Generate deterministic dice rolls (1-6)
-        // Seed components (all deterministic and identical between sim/submit):
-        // 1. Session ID - unique per game
-        // 2. Player addresses - both players contribute
-        //
-        // Note: We do NOT include ledger sequence or timestamp because those differ
-        // between simulation and submission, which would cause different winners.
-        let mut seed_bytes = Bytes::new(&env);
-        seed_bytes.append(&Bytes::from_array(&env, &session_id.to_be_bytes()));
-        seed_bytes.append(&game.player1.to_string().to_bytes());
-        seed_bytes.append(&game.player2.to_string().to_bytes());
-        let base_seed = env.crypto().keccak256(&seed_bytes);
-
-        // Roll dice for both players using unique seeds
-        let mut roll_seed_bytes = Bytes::new(&env);
-        roll_seed_bytes.append(&Bytes::from(base_seed.clone()));
-        roll_seed_bytes.append(&Bytes::from_array(&env, &[1, 1]));
-        let player1_die1 = roll_die(&env, env.crypto().keccak256(&roll_seed_bytes).into());
-
-        let mut roll_seed_bytes = Bytes::new(&env);
-        roll_seed_bytes.append(&Bytes::from(base_seed.clone()));
-        roll_seed_bytes.append(&Bytes::from_array(&env, &[1, 2]));
-        let player1_die2 = roll_die(&env, env.crypto().keccak256(&roll_seed_bytes).into());
-
-        let mut roll_seed_bytes = Bytes::new(&env);
-        roll_seed_bytes.append(&Bytes::from(base_seed.clone()));
-        roll_seed_bytes.append(&Bytes::from_array(&env, &[2, 1]));
-        let player2_die1 = roll_die(&env, env.crypto().keccak256(&roll_seed_bytes).into());
-
-        let mut roll_seed_bytes = Bytes::new(&env);
-        roll_seed_bytes.append(&Bytes::from(base_seed.clone()));
-        roll_seed_bytes.append(&Bytes::from_array(&env, &[2, 2]));
-        let player2_die2 = roll_die(&env, env.crypto().keccak256(&roll_seed_bytes).into());
+        // Generate dice rolls (1-6) from the beacon seed
+        // The seed combines both revealed values, which stayed secret until
+        // both players had rolled, so nobody could predict or bias the dice.
+        // It is fully deterministic, so simulation and submission agree.
+        // With a randomness provider, its value for the session is mixed in,
+        // unless it still hasn't answered by the reveal deadline.
+        let beacon_seed = game.beacon.seed(&env)?;
+        let seed = match game_common::mix_provider_randomness(
+            &env,
+            game_common::randomness_request_id(session_id, 0),
+            beacon_seed.clone(),
+        ) {
+            Some(seed) => seed,
+            None if env.ledger().sequence() > game.action_deadline => beacon_seed,
+            None => return Err(Error::RandomnessNotReady),
+        };
+        let mut rng = SeedRng::new(&env, seed);
+        let player1_die1 = rng.range(1..=6);
+        let player1_die2 = rng.range(1..=6);
+        let player2_die1 = rng.range(1..=6);
+        let player2_die2 = rng.range(1..=6);
 
         game.player1_die1 = Some(player1_die1);
         game.player1_die2 = Some(player1_die2);
@@ -322,25 +371,199 @@ impl DiceDuelContract {
         game.winner = Some(winner.clone());
         env.storage().temporary().set(&key, &game);
 
-        // Get GameHub address
-        let game_hub_addr: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::GameHubAddress)
-            .expect("GameHub address not set");
-
         // Create GameHub client
-        let game_hub = GameHubClient::new(&env, &game_hub_addr);
+        let game_hub = game_common::hub_client(&env);
 
         // Call GameHub to end the session
         // This unlocks points and updates standings
         // Event emitted by the Game Hub contract (GameEnded)
-        let player1_won = winner == game.player1; // true if player1 won, false if player2 won
-        game_hub.end_game(&session_id, &player1_won);
+        let outcome = if winner == game.player1 {
+            GameOutcome::Player1Won
+        } else {
+            GameOutcome::Player2Won
+        };
+        game_hub.end_game(&session_id, &outcome);
 
         Ok(winner)
     }
 
+    /// Claim the win after the action deadline has passed.
+    /// The claimant must have done their part of the current phase (rolled,
+    /// or revealed once both rolled) while the opponent hasn't.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the player claiming the win
+    ///
+    /// # Returns
+    /// * `Address` - Address of the winning player
+    pub fn claim_timeout(env: Env, session_id: u32, player: Address) -> Result<Address, Error> {
+        player.require_auth();
+
+        // Get game from temporary storage
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        // Check game is still active (no winner or agreed draw yet)
+        if game.winner.is_some() || game.drawn {
+            return Err(Error::GameAlreadyEnded);
+        }
+
+        let opponent = if player == game.player1 {
+            game.player2.clone()
+        } else if player == game.player2 {
+            game.player1.clone()
+        } else {
+            return Err(Error::NotPlayer);
+        };
+
+        if env.ledger().sequence() <= game.action_deadline {
+            return Err(Error::DeadlineNotReached);
+        }
+        if game.beacon.awaiting(&player) {
+            return Err(Error::PlayerStalled);
+        }
+        if !game.beacon.awaiting(&opponent) {
+            return Err(Error::OpponentNotStalled);
+        }
+
+        // Update game with winner (this marks the game as ended)
+        game.winner = Some(player.clone());
+        env.storage().temporary().set(&key, &game);
+
+        // Report the forfeit to the GameHub
+        let outcome = if player == game.player1 {
+            GameOutcome::Player1Won
+        } else {
+            GameOutcome::Player2Won
+        };
+        game_common::hub_client(&env).end_game(&session_id, &outcome);
+
+        Ok(player)
+    }
+
+    /// Resign the game, handing the win to the opponent.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the resigning player
+    ///
+    /// # Returns
+    /// * `Address` - Address of the winning player
+    pub fn resign(env: Env, session_id: u32, player: Address) -> Result<Address, Error> {
+        player.require_auth();
+
+        // Get game from temporary storage
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        // Check game is still active (no winner or agreed draw yet)
+        if game.winner.is_some() || game.drawn {
+            return Err(Error::GameAlreadyEnded);
+        }
+
+        let (winner, outcome) = if player == game.player1 {
+            (game.player2.clone(), GameOutcome::Player2Won)
+        } else if player == game.player2 {
+            (game.player1.clone(), GameOutcome::Player1Won)
+        } else {
+            return Err(Error::NotPlayer);
+        };
+
+        // Update game with winner (this marks the game as ended)
+        game.winner = Some(winner.clone());
+        env.storage().temporary().set(&key, &game);
+
+        // Report the resignation to the GameHub
+        game_common::hub_client(&env).end_game(&session_id, &outcome);
+
+        Ok(winner)
+    }
+
+    /// Offer the opponent a draw. The offer stands until the opponent accepts
+    /// it or the game ends; offering again replaces the opponent's own offer.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the player offering the draw
+    pub fn offer_draw(env: Env, session_id: u32, player: Address) -> Result<(), Error> {
+        player.require_auth();
+
+        // Get game from temporary storage
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        // Check game is still active (no winner or agreed draw yet)
+        if game.winner.is_some() || game.drawn {
+            return Err(Error::GameAlreadyEnded);
+        }
+
+        if player != game.player1 && player != game.player2 {
+            return Err(Error::NotPlayer);
+        }
+
+        game.draw_offer = Some(player);
+        env.storage().temporary().set(&key, &game);
+
+        Ok(())
+    }
+
+    /// Accept the opponent's draw offer, ending the game as a draw.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the player accepting the draw
+    pub fn accept_draw(env: Env, session_id: u32, player: Address) -> Result<(), Error> {
+        player.require_auth();
+
+        // Get game from temporary storage
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        // Check game is still active (no winner or agreed draw yet)
+        if game.winner.is_some() || game.drawn {
+            return Err(Error::GameAlreadyEnded);
+        }
+
+        let opponent = if player == game.player1 {
+            game.player2.clone()
+        } else if player == game.player2 {
+            game.player1.clone()
+        } else {
+            return Err(Error::NotPlayer);
+        };
+
+        // Only the opponent's offer can be accepted
+        if game.draw_offer != Some(opponent) {
+            return Err(Error::NoDrawOffer);
+        }
+
+        // Mark the draw (this marks the game as ended)
+        game.drawn = true;
+        env.storage().temporary().set(&key, &game);
+
+        // Report the draw to the GameHub
+        game_common::hub_client(&env).end_game(&session_id, &GameOutcome::Draw);
+
+        Ok(())
+    }
+
     /// Get game information.
     ///
     /// # Arguments
@@ -365,10 +588,7 @@ impl DiceDuelContract {
     /// # Returns
     /// * `Address` - The admin address
     pub fn get_admin(env: Env) -> Address {
-        env.storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set")
+        game_common::get_admin(&env)
     }
 
     /// Set a new admin address
@@ -376,14 +596,7 @@ impl DiceDuelContract {
     /// # Arguments
     /// * `new_admin` - The new admin address
     pub fn set_admin(env: Env, new_admin: Address) {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
-
-        env.storage().instance().set(&DataKey::Admin, &new_admin);
+        game_common::set_admin(&env, &new_admin);
     }
 
     /// Get the current GameHub contract address
@@ -391,10 +604,7 @@ impl DiceDuelContract {
     /// # Returns
     /// * `Address` - The GameHub contract address
     pub fn get_hub(env: Env) -> Address {
-        env.storage()
-            .instance()
-            .get(&DataKey::GameHubAddress)
-            .expect("GameHub address not set")
+        game_common::get_hub(&env)
     }
 
     /// Set a new GameHub contract address
@@ -402,16 +612,31 @@ impl DiceDuelContract {
     /// # Arguments
     /// * `new_hub` - The new GameHub contract address
     pub fn set_hub(env: Env, new_hub: Address) {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
+        game_common::set_hub(&env, &new_hub);
+    }
 
-        env.storage()
-            .instance()
-            .set(&DataKey::GameHubAddress, &new_hub);
+    /// Get the randomness provider mixed into every roll, if any
+    ///
+    /// # Returns
+    /// * `Option<Address>` - The randomness provider contract address
+    pub fn get_randomness_provider(env: Env) -> Option<Address> {
+        game_common::get_randomness_provider(&env)
+    }
+
+    /// Get the number of ledgers players have to roll, and then to reveal
+    ///
+    /// # Returns
+    /// * `u32` - The action timeout in ledgers
+    pub fn get_action_timeout(env: Env) -> u32 {
+        game_common::get_action_timeout(&env)
+    }
+
+    /// Set the action timeout for new phases (games in progress keep their deadline)
+    ///
+    /// # Arguments
+    /// * `ledgers` - The action timeout in ledgers
+    pub fn set_action_timeout(env: Env, ledgers: u32) {
+        game_common::set_action_timeout(&env, ledgers);
     }
 
     /// Update the contract WASM hash (upgrade contract)
@@ -419,14 +644,7 @@ impl DiceDuelContract {
     /// # Arguments
     /// * `new_wasm_hash` - The hash of the new WASM binary
     pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
-
-        env.deployer().update_current_contract_wasm(new_wasm_hash);
+        game_common::upgrade(&env, new_wasm_hash);
     }
 }
 
@@ -3,9 +3,10 @@
 // Unit tests for the dice-duel contract using a simple mock GameHub.
 // These tests verify game logic independently of the full GameHub system.
 
-use crate::{DiceDuelContract, DiceDuelContractClient, Error};
+use crate::{DiceDuelContract, DiceDuelContractClient, Error, GameOutcome};
+use mock_randomness::{MockRandomness, MockRandomnessClient};
 use soroban_sdk::testutils::{Address as _, Ledger as _};
-use soroban_sdk::{contract, contractimpl, Address, BytesN, Env};
+use soroban_sdk::{contract, contractimpl, Address, Bytes, BytesN, Env};
 
 // ============================================================================
 // Mock GameHub for Unit Testing
@@ -28,8 +29,13 @@ impl MockGameHub {
         // Mock implementation - does nothing
     }
 
-    pub fn end_game(_env: Env, _session_id: u32, _player1_won: bool) {
-        // Mock implementation - does nothing
+    pub fn end_game(env: Env, session_id: u32, outcome: GameOutcome) {
+        // Record the outcome so tests can check what the game reported
+        env.storage().temporary().set(&session_id, &outcome);
+    }
+
+    pub fn get_outcome(env: Env, session_id: u32) -> Option<GameOutcome> {
+        env.storage().temporary().get(&session_id)
     }
 
     pub fn add_game(_env: Env, _game_address: Address) {
@@ -71,7 +77,7 @@ fn setup_test() -> (
     let admin = Address::generate(&env);
 
     // Deploy dice-duel with admin and GameHub address
-    let contract_id = env.register(DiceDuelContract, (&admin, &hub_addr));
+    let contract_id = env.register(DiceDuelContract, (&admin, &hub_addr, None::<Address>));
     let client = DiceDuelContractClient::new(&env, &contract_id);
 
     // Register dice-duel as a whitelisted game (mock does nothing)
@@ -83,6 +89,31 @@ fn setup_test() -> (
     (env, client, game_hub, player1, player2)
 }
 
+/// Secret value a test player commits to when rolling
+fn entropy(env: &Env, seed: u8) -> BytesN<32> {
+    BytesN::from_array(env, &[seed; 32])
+}
+
+/// Build the commitment `keccak256(value)` a frontend would submit
+fn commitment(env: &Env, value: &BytesN<32>) -> BytesN<32> {
+    env.crypto().keccak256(&Bytes::from(value.clone())).into()
+}
+
+/// Roll and then reveal for both players
+fn roll_both(
+    env: &Env,
+    client: &DiceDuelContractClient,
+    session_id: u32,
+    player1: &Address,
+    player2: &Address,
+) {
+    let (entropy1, entropy2) = (entropy(env, 1), entropy(env, 2));
+    client.roll(&session_id, player1, &commitment(env, &entropy1));
+    client.roll(&session_id, player2, &commitment(env, &entropy2));
+    client.reveal_roll(&session_id, player1, &entropy1);
+    client.reveal_roll(&session_id, player2, &entropy2);
+}
+
 /// Assert that a Result contains a specific dice_duel error
 fn assert_dice_duel_error<T, E>(
     result: &Result<Result<T, E>, Result<Error, soroban_sdk::InvokeError>>,
@@ -123,7 +154,7 @@ fn assert_dice_duel_error<T, E>(
 
 #[test]
 fn test_complete_game() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 1u32;
     let points = 100_0000000;
@@ -138,16 +169,15 @@ fn test_complete_game() {
     assert_eq!(game.player2, player2);
     assert_eq!(game.player1_points, points);
     assert_eq!(game.player2_points, points);
-    assert_eq!(game.player1_rolled, false);
-    assert_eq!(game.player2_rolled, false);
+    assert!(!game.beacon.is_committed(&player1));
+    assert!(!game.beacon.is_committed(&player2));
     assert!(game.player1_die1.is_none());
     assert!(game.player1_die2.is_none());
     assert!(game.player2_die1.is_none());
     assert!(game.player2_die2.is_none());
 
     // Players roll
-    client.roll(&session_id, &player1);
-    client.roll(&session_id, &player2);
+    roll_both(&env, &client, session_id, &player1, &player2);
 
     // Reveal winner
     let winner = client.reveal_winner(&session_id);
@@ -176,27 +206,27 @@ fn test_complete_game() {
 
 #[test]
 fn test_cannot_roll_twice() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 2u32;
     let points = 100_0000000;
 
     client.start_game(&session_id, &player1, &player2, &points, &points);
 
-    client.roll(&session_id, &player1);
-    let result = client.try_roll(&session_id, &player1);
+    client.roll(&session_id, &player1, &commitment(&env, &entropy(&env, 1)));
+    let result = client.try_roll(&session_id, &player1, &commitment(&env, &entropy(&env, 3)));
     assert_dice_duel_error(&result, Error::AlreadyRolled);
 }
 
 #[test]
 fn test_cannot_reveal_before_both_roll() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 3u32;
     let points = 100_0000000;
 
     client.start_game(&session_id, &player1, &player2, &points, &points);
-    client.roll(&session_id, &player1);
+    client.roll(&session_id, &player1, &commitment(&env, &entropy(&env, 1)));
 
     let result = client.try_reveal_winner(&session_id);
     assert_dice_duel_error(&result, Error::BothPlayersNotRolled);
@@ -204,34 +234,53 @@ fn test_cannot_reveal_before_both_roll() {
 
 #[test]
 fn test_non_player_cannot_roll() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 4u32;
     let points = 100_0000000;
 
     client.start_game(&session_id, &player1, &player2, &points, &points);
 
-    let non_player = Address::generate(&_env);
-    let result = client.try_roll(&session_id, &non_player);
+    let non_player = Address::generate(&env);
+    let result = client.try_roll(&session_id, &non_player, &commitment(&env, &entropy(&env, 3)));
     assert_dice_duel_error(&result, Error::NotPlayer);
 }
 
 #[test]
 fn test_cannot_roll_after_game_ended() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 5u32;
     let points = 100_0000000;
 
     client.start_game(&session_id, &player1, &player2, &points, &points);
-    client.roll(&session_id, &player1);
-    client.roll(&session_id, &player2);
+    roll_both(&env, &client, session_id, &player1, &player2);
     client.reveal_winner(&session_id);
 
-    let result = client.try_roll(&session_id, &player1);
+    let result = client.try_roll(&session_id, &player1, &commitment(&env, &entropy(&env, 1)));
     assert_dice_duel_error(&result, Error::GameAlreadyEnded);
 }
 
+#[test]
+fn test_cannot_start_existing_session() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 6u32;
+    let points = 100_0000000;
+
+    client.start_game(&session_id, &player1, &player2, &points, &points);
+    let commitment1 = commitment(&env, &entropy(&env, 1));
+    client.roll(&session_id, &player1, &commitment1);
+
+    // Starting again over the live session must not reset it
+    let result = client.try_start_game(&session_id, &player1, &player2, &points, &points);
+    assert_dice_duel_error(&result, Error::SessionExists);
+    assert_eq!(
+        client.get_game(&session_id).beacon.commitments.get(player1.clone()),
+        Some(commitment1)
+    );
+}
+
 #[test]
 fn test_upgrade_function_exists() {
     let (_env, client, _hub, _player1, _player2) = setup_test();
@@ -243,3 +292,303 @@ fn test_upgrade_function_exists() {
     // Should fail (WASM doesn't exist) but confirms function signature is correct
     assert!(result.is_err());
 }
+
+// ============================================================================
+// Commit-Reveal Tests
+// ============================================================================
+
+#[test]
+fn test_reveal_must_match_commitment() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 7u32;
+    let points = 100_0000000;
+
+    client.start_game(&session_id, &player1, &player2, &points, &points);
+    client.roll(&session_id, &player1, &commitment(&env, &entropy(&env, 1)));
+    client.roll(&session_id, &player2, &commitment(&env, &entropy(&env, 2)));
+
+    // A different value than the committed one is rejected
+    let result = client.try_reveal_roll(&session_id, &player1, &entropy(&env, 3));
+    assert_dice_duel_error(&result, Error::CommitmentMismatch);
+
+    client.reveal_roll(&session_id, &player1, &entropy(&env, 1));
+    let result = client.try_reveal_roll(&session_id, &player1, &entropy(&env, 1));
+    assert_dice_duel_error(&result, Error::AlreadyRevealed);
+}
+
+#[test]
+fn test_cannot_reveal_roll_before_both_roll() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 8u32;
+    let points = 100_0000000;
+
+    client.start_game(&session_id, &player1, &player2, &points, &points);
+    client.roll(&session_id, &player1, &commitment(&env, &entropy(&env, 1)));
+
+    let result = client.try_reveal_roll(&session_id, &player1, &entropy(&env, 1));
+    assert_dice_duel_error(&result, Error::BothPlayersNotRolled);
+}
+
+#[test]
+fn test_cannot_reveal_winner_before_both_reveal() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 9u32;
+    let points = 100_0000000;
+
+    client.start_game(&session_id, &player1, &player2, &points, &points);
+    client.roll(&session_id, &player1, &commitment(&env, &entropy(&env, 1)));
+    client.roll(&session_id, &player2, &commitment(&env, &entropy(&env, 2)));
+    client.reveal_roll(&session_id, &player1, &entropy(&env, 1));
+
+    let result = client.try_reveal_winner(&session_id);
+    assert_dice_duel_error(&result, Error::BothPlayersNotRevealed);
+}
+
+#[test]
+fn test_dice_depend_on_revealed_values() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let points = 100_0000000;
+
+    // Same players and session layout, different secret values per game
+    let mut totals = [0u32; 10];
+    for session_id in 10u32..20 {
+        let (entropy1, entropy2) = (entropy(&env, session_id as u8), entropy(&env, 100));
+        client.start_game(&session_id, &player1, &player2, &points, &points);
+        client.roll(&session_id, &player1, &commitment(&env, &entropy1));
+        client.roll(&session_id, &player2, &commitment(&env, &entropy2));
+        client.reveal_roll(&session_id, &player1, &entropy1);
+        client.reveal_roll(&session_id, &player2, &entropy2);
+        client.reveal_winner(&session_id);
+
+        let game = client.get_game(&session_id);
+        totals[(session_id - 10) as usize] = game.player1_die1.unwrap() + game.player1_die2.unwrap();
+    }
+
+    assert!(totals.iter().any(|total| *total != totals[0]));
+}
+
+#[test]
+fn test_commit_and_reveal_deadlines() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 20u32;
+    let points = 100_0000000;
+
+    client.start_game(&session_id, &player1, &player2, &points, &points);
+    client.roll(&session_id, &player1, &commitment(&env, &entropy(&env, 1)));
+
+    // Too late to roll once the commit window has passed
+    let deadline = client.get_game(&session_id).beacon.commit_deadline;
+    env.ledger().set_sequence_number(deadline + 1);
+    let result = client.try_roll(&session_id, &player2, &commitment(&env, &entropy(&env, 2)));
+    assert_dice_duel_error(&result, Error::CommitDeadlinePassed);
+
+    // The reveal window opens with the last roll
+    env.ledger().set_sequence_number(deadline);
+    client.roll(&session_id, &player2, &commitment(&env, &entropy(&env, 2)));
+    let reveal_deadline = client.get_game(&session_id).beacon.reveal_deadline.unwrap();
+    env.ledger().set_sequence_number(reveal_deadline + 1);
+    let result = client.try_reveal_roll(&session_id, &player1, &entropy(&env, 1));
+    assert_dice_duel_error(&result, Error::RevealDeadlinePassed);
+}
+
+// ============================================================================
+// Timeout Tests
+// ============================================================================
+
+#[test]
+fn test_claim_timeout_when_opponent_never_rolls() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 30u32;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    client.roll(&session_id, &player1, &commitment(&env, &entropy(&env, 1)));
+
+    // Player 2 still has time to roll
+    let result = client.try_claim_timeout(&session_id, &player1);
+    assert_dice_duel_error(&result, Error::DeadlineNotReached);
+
+    let deadline = client.get_game(&session_id).action_deadline;
+    env.ledger().set_sequence_number(deadline + 1);
+
+    let result = client.try_claim_timeout(&session_id, &player2);
+    assert_dice_duel_error(&result, Error::PlayerStalled);
+
+    assert_eq!(client.claim_timeout(&session_id, &player1), player1);
+    assert_eq!(client.get_game(&session_id).winner, Some(player1.clone()));
+
+    let result = client.try_claim_timeout(&session_id, &player1);
+    assert_dice_duel_error(&result, Error::GameAlreadyEnded);
+}
+
+#[test]
+fn test_claim_timeout_when_opponent_never_reveals() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    client.set_action_timeout(&10);
+    assert_eq!(client.get_action_timeout(), 10);
+
+    let session_id = 31u32;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    assert_eq!(client.get_game(&session_id).action_deadline, 110);
+
+    // The last roll starts a fresh reveal deadline
+    env.ledger().set_sequence_number(105);
+    client.roll(&session_id, &player1, &commitment(&env, &entropy(&env, 1)));
+    client.roll(&session_id, &player2, &commitment(&env, &entropy(&env, 2)));
+    assert_eq!(client.get_game(&session_id).action_deadline, 115);
+
+    client.reveal_roll(&session_id, &player2, &entropy(&env, 2));
+    env.ledger().set_sequence_number(116);
+
+    let result = client.try_claim_timeout(&session_id, &player1);
+    assert_dice_duel_error(&result, Error::PlayerStalled);
+    assert_eq!(client.claim_timeout(&session_id, &player2), player2);
+}
+
+#[test]
+fn test_claim_timeout_needs_stalled_opponent() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 32u32;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    roll_both(&env, &client, session_id, &player1, &player2);
+
+    // Both players did their part: the winner is revealed normally
+    let deadline = client.get_game(&session_id).action_deadline;
+    env.ledger().set_sequence_number(deadline + 1);
+    let result = client.try_claim_timeout(&session_id, &player1);
+    assert_dice_duel_error(&result, Error::OpponentNotStalled);
+    client.reveal_winner(&session_id);
+}
+
+// ============================================================================
+// Resign and Draw Tests
+// ============================================================================
+
+#[test]
+fn test_resign_hands_win_to_opponent() {
+    let (_env, client, hub, player1, player2) = setup_test();
+
+    let session_id = 70u32;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+
+    assert_eq!(client.resign(&session_id, &player2), player1);
+    assert_eq!(client.get_game(&session_id).winner, Some(player1.clone()));
+    assert_eq!(hub.get_outcome(&session_id), Some(GameOutcome::Player1Won));
+
+    let result = client.try_resign(&session_id, &player1);
+    assert_dice_duel_error(&result, Error::GameAlreadyEnded);
+}
+
+#[test]
+fn test_agreed_draw_ends_game() {
+    let (env, client, hub, player1, player2) = setup_test();
+
+    let session_id = 71u32;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+
+    // Nothing to accept yet, and a player can't accept their own offer
+    let result = client.try_accept_draw(&session_id, &player2);
+    assert_dice_duel_error(&result, Error::NoDrawOffer);
+    client.offer_draw(&session_id, &player1);
+    let result = client.try_accept_draw(&session_id, &player1);
+    assert_dice_duel_error(&result, Error::NoDrawOffer);
+
+    client.accept_draw(&session_id, &player2);
+    let game = client.get_game(&session_id);
+    assert!(game.drawn);
+    assert!(game.winner.is_none());
+    assert_eq!(hub.get_outcome(&session_id), Some(GameOutcome::Draw));
+
+    // The game is over for every other action
+    let result = client.try_roll(&session_id, &player1, &commitment(&env, &entropy(&env, 1)));
+    assert_dice_duel_error(&result, Error::GameAlreadyEnded);
+    let result = client.try_reveal_winner(&session_id);
+    assert_dice_duel_error(&result, Error::GameAlreadyEnded);
+    let result = client.try_resign(&session_id, &player1);
+    assert_dice_duel_error(&result, Error::GameAlreadyEnded);
+}
+
+#[test]
+fn test_non_player_cannot_resign_or_offer_draw() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 72u32;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+
+    let outsider = Address::generate(&env);
+    let result = client.try_resign(&session_id, &outsider);
+    assert_dice_duel_error(&result, Error::NotPlayer);
+    let result = client.try_offer_draw(&session_id, &outsider);
+    assert_dice_duel_error(&result, Error::NotPlayer);
+}
+
+// ============================================================================
+// Randomness Provider Tests
+// ============================================================================
+
+#[test]
+fn test_randomness_provider_is_mixed_into_dice() {
+    let (env, _client, hub, player1, player2) = setup_test();
+    let provider_addr = env.register(MockRandomness, ());
+    let provider = MockRandomnessClient::new(&env, &provider_addr);
+    let admin = Address::generate(&env);
+    let contract_id = env.register(DiceDuelContract, (&admin, &hub.address, Some(provider_addr.clone())));
+    let client = DiceDuelContractClient::new(&env, &contract_id);
+    assert_eq!(client.get_randomness_provider(), Some(provider_addr));
+
+    // Same rolls in two sessions; only the provider's value differs
+    let points = 100_0000000;
+    let mut totals = [0u32; 2];
+    for session_id in 1u32..=2 {
+        client.start_game(&session_id, &player1, &player2, &points, &points);
+        let request_id = (session_id as u64) << 32;
+        assert!(provider.is_pending(&contract_id, &request_id));
+        roll_both(&env, &client, session_id, &player1, &player2);
+
+        // The dice can't be rolled until the provider has answered
+        let result = client.try_reveal_winner(&session_id);
+        assert_dice_duel_error(&result, Error::RandomnessNotReady);
+
+        provider.fulfill(&contract_id, &request_id, &BytesN::from_array(&env, &[session_id as u8; 32]));
+        client.reveal_winner(&session_id);
+        let game = client.get_game(&session_id);
+        totals[(session_id - 1) as usize] = game.player1_die1.unwrap() * 10 + game.player1_die2.unwrap();
+    }
+
+    assert_ne!(totals[0], totals[1]);
+}
+
+#[test]
+fn test_dice_fall_back_to_beacon_when_provider_never_answers() {
+    let (env, plain_client, hub, player1, player2) = setup_test();
+    let provider_addr = env.register(MockRandomness, ());
+    let admin = Address::generate(&env);
+    let contract_id = env.register(DiceDuelContract, (&admin, &hub.address, Some(provider_addr)));
+    let client = DiceDuelContractClient::new(&env, &contract_id);
+
+    let points = 100_0000000;
+    client.start_game(&1, &player1, &player2, &points, &points);
+    roll_both(&env, &client, 1, &player1, &player2);
+
+    // The provider still has until the reveal deadline to answer
+    let result = client.try_reveal_winner(&1);
+    assert_dice_duel_error(&result, Error::RandomnessNotReady);
+
+    // After it, the dice come from the revealed values alone, as without a provider
+    let deadline = client.get_game(&1).action_deadline;
+    env.ledger().set_sequence_number(deadline + 1);
+    let winner = client.reveal_winner(&1);
+
+    plain_client.start_game(&2, &player1, &player2, &points, &points);
+    roll_both(&env, &plain_client, 2, &player1, &player2);
+    plain_client.reveal_winner(&2);
+    let game = client.get_game(&1);
+    let plain_game = plain_client.get_game(&2);
+    assert_eq!(game.winner, Some(winner));
+    assert_eq!(game.player1_die1, plain_game.player1_die1);
+    assert_eq!(game.player2_die2, plain_game.player2_die2);
+}
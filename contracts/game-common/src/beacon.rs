@@ -0,0 +1,184 @@
+//! Multi-party commit-reveal randomness beacon.
+//!
+//! Every party first commits to `keccak256(value)` for a secret value, then,
+//! once all parties have committed, reveals the value. The combined seed is
+//! the hash of every revealed value, so it is unpredictable as long as at
+//! least one party kept their value secret until everyone had committed.
+//!
+//! A `Beacon` is plain contract data: games store it with their own state and
+//! map `BeaconError` to their own error codes.
+
+use core::ops::RangeInclusive;
+use soroban_sdk::{contracttype, Address, Bytes, BytesN, Env, Map, Vec};
+
+/// Commitment/reveal failures, mapped to each game's own `Error`
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum BeaconError {
+    /// The address is not one of the beacon's parties
+    NotParty,
+    AlreadyCommitted,
+    /// Reveals only open once every party has committed
+    NotAllCommitted,
+    AlreadyRevealed,
+    /// The revealed value does not hash to the party's commitment
+    CommitmentMismatch,
+    /// The seed only exists once every party has revealed
+    NotAllRevealed,
+    CommitDeadlinePassed,
+    RevealDeadlinePassed,
+}
+
+/// Commitment/reveal state of one beacon round
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Beacon {
+    pub parties: Vec<Address>,
+    pub commitments: Map<Address, BytesN<32>>,
+    pub reveals: Map<Address, Bytes>,
+    /// Last ledger on which a commitment is accepted
+    pub commit_deadline: u32,
+    /// Ledgers parties have to reveal once the last commitment lands
+    pub reveal_window: u32,
+    /// Last ledger on which a reveal is accepted, set by the last commitment
+    pub reveal_deadline: Option<u32>,
+}
+
+/// The commitment a party submits for `value`: `keccak256(value)`
+pub fn commitment(env: &Env, value: &Bytes) -> BytesN<32> {
+    env.crypto().keccak256(value).into()
+}
+
+impl Beacon {
+    /// Open a beacon for `parties`, accepting commitments for `commit_ledgers`
+    /// ledgers from now. Reveals then get `reveal_ledgers` ledgers from the
+    /// last commitment.
+    pub fn new(env: &Env, parties: Vec<Address>, commit_ledgers: u32, reveal_ledgers: u32) -> Self {
+        Beacon {
+            parties,
+            commitments: Map::new(env),
+            reveals: Map::new(env),
+            commit_deadline: env.ledger().sequence().saturating_add(commit_ledgers),
+            reveal_window: reveal_ledgers,
+            reveal_deadline: None,
+        }
+    }
+
+    /// Record `party`'s commitment
+    pub fn commit(
+        &mut self,
+        env: &Env,
+        party: &Address,
+        commitment: BytesN<32>,
+    ) -> Result<(), BeaconError> {
+        if !self.parties.contains(party) {
+            return Err(BeaconError::NotParty);
+        }
+        if self.commitments.contains_key(party.clone()) {
+            return Err(BeaconError::AlreadyCommitted);
+        }
+        if env.ledger().sequence() > self.commit_deadline {
+            return Err(BeaconError::CommitDeadlinePassed);
+        }
+
+        self.commitments.set(party.clone(), commitment);
+
+        // The reveal window opens with the last commitment
+        if self.all_committed() {
+            self.reveal_deadline = Some(env.ledger().sequence().saturating_add(self.reveal_window));
+        }
+
+        Ok(())
+    }
+
+    /// Record `party`'s reveal after checking it against their commitment
+    pub fn reveal(&mut self, env: &Env, party: &Address, value: Bytes) -> Result<(), BeaconError> {
+        if !self.parties.contains(party) {
+            return Err(BeaconError::NotParty);
+        }
+        let reveal_deadline = self.reveal_deadline.ok_or(BeaconError::NotAllCommitted)?;
+        if self.reveals.contains_key(party.clone()) {
+            return Err(BeaconError::AlreadyRevealed);
+        }
+        if env.ledger().sequence() > reveal_deadline {
+            return Err(BeaconError::RevealDeadlinePassed);
+        }
+        if self.commitments.get(party.clone()) != Some(commitment(env, &value)) {
+            return Err(BeaconError::CommitmentMismatch);
+        }
+
+        self.reveals.set(party.clone(), value);
+        Ok(())
+    }
+
+    /// Whether `party` has committed
+    pub fn is_committed(&self, party: &Address) -> bool {
+        self.commitments.contains_key(party.clone())
+    }
+
+    /// Whether every party has committed
+    pub fn all_committed(&self) -> bool {
+        self.commitments.len() == self.parties.len()
+    }
+
+    /// The value `party` revealed, if any
+    pub fn revealed(&self, party: &Address) -> Option<Bytes> {
+        self.reveals.get(party.clone())
+    }
+
+    /// Whether every party has revealed
+    pub fn all_revealed(&self) -> bool {
+        self.reveals.len() == self.parties.len()
+    }
+
+    /// Whether the beacon is waiting on `party`: to commit, or to reveal once
+    /// every party has committed
+    pub fn awaiting(&self, party: &Address) -> bool {
+        !self.is_committed(party) || (self.all_committed() && self.revealed(party).is_none())
+    }
+
+    /// Combined seed: `keccak256` over every revealed value, each prefixed with
+    /// its big-endian `u32` length, in party order. The length prefix keeps
+    /// variable-length reveals from running into each other. The values are
+    /// hashed themselves, not their commitments, which are public as soon as
+    /// they land and would let the last party to commit predict the seed.
+    pub fn seed(&self, env: &Env) -> Result<BytesN<32>, BeaconError> {
+        let mut seed_bytes = Bytes::new(env);
+        for party in self.parties.iter() {
+            let value = self.reveals.get(party).ok_or(BeaconError::NotAllRevealed)?;
+            seed_bytes.append(&Bytes::from_array(env, &value.len().to_be_bytes()));
+            seed_bytes.append(&value);
+        }
+        Ok(env.crypto().keccak256(&seed_bytes).into())
+    }
+}
+
+/// Derives a sequence of bounded values from a seed. The `n`-th value only
+/// depends on the seed and `n`, so every caller replaying the same draws
+/// gets the same values, on-chain or off-chain with nothing but keccak256.
+pub struct SeedRng<'a> {
+    env: &'a Env,
+    seed: BytesN<32>,
+    next: u32,
+}
+
+impl<'a> SeedRng<'a> {
+    pub fn new(env: &'a Env, seed: BytesN<32>) -> Self {
+        SeedRng { env, seed, next: 0 }
+    }
+
+    /// Draw the next value within `bounds`, e.g. `range(1..=6)` for a die.
+    /// The `n`-th draw is `start + x % (end - start + 1)`, where `x` is the
+    /// first 8 bytes (big-endian) of `keccak256(seed || n)` with `n` as a
+    /// big-endian `u32`.
+    pub fn range(&mut self, bounds: RangeInclusive<u32>) -> u32 {
+        let mut draw_seed = Bytes::from(self.seed.clone());
+        draw_seed.append(&Bytes::from_array(self.env, &self.next.to_be_bytes()));
+        self.next += 1;
+
+        let hash = self.env.crypto().keccak256(&draw_seed).to_array();
+        let mut word = [0u8; 8];
+        word.copy_from_slice(&hash[..8]);
+        let span = (*bounds.end() - *bounds.start()) as u64 + 1;
+        *bounds.start() + (u64::from_be_bytes(word) % span) as u32
+    }
+}
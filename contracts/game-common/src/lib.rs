@@ -0,0 +1,284 @@
+#![no_std]
+
+//! # Game Common
+//!
+//! Building blocks shared by every game contract in the studio:
+//! - The `GameHub` contract client, the `GameOutcome` games report to it and
+//!   the `SessionStatus` they can read back
+//! - Admin and Game Hub address storage, with the admin-guarded setters and
+//!   `upgrade` behind each game's admin endpoints
+//! - `GAME_TTL_LEDGERS`, the retention period for game state
+//! - The admin-configurable action timeout games give players to act before
+//!   their opponent can claim the game
+//! - `beacon`, a commit-reveal randomness beacon and `SeedRng` for dice, cards
+//!   and other bounded draws
+//! - The `RandomnessProvider` client for games that mix randomness from an
+//!   external oracle contract into their seeds
+//!
+//! Game contracts keep their own `#[contractimpl]` endpoints and forward the
+//! admin ones here, so a fix to admin or hub handling lands in every game.
+
+pub mod beacon;
+
+use soroban_sdk::{
+    contractclient, contracttype, Address, Bytes, BytesN, Env, IntoVal, Val, Vec,
+};
+
+// ============================================================================
+// Game Hub Interface
+// ============================================================================
+
+// Import GameHub contract interface
+// This allows games to call into the GameHub contract
+#[contractclient(name = "GameHubClient")]
+pub trait GameHub {
+    fn start_game(
+        env: Env,
+        game_id: Address,
+        session_id: u32,
+        player1: Address,
+        player2: Address,
+        player1_points: i128,
+        player2_points: i128,
+    );
+
+    fn end_game(env: Env, session_id: u32, outcome: GameOutcome);
+
+    /// Whether a session is still active, or how the hub ended it (including
+    /// an `expire_session` refund)
+    fn get_session_status(env: Env, session_id: u32) -> SessionStatus;
+}
+
+/// Result of a session, reported to the Game Hub in `end_game`
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum GameOutcome {
+    Player1Won,
+    Player2Won,
+    Draw,
+    Cancelled,
+}
+
+/// How the Game Hub settled a session. Per-player values are indexed like the
+/// session's players.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SessionResult {
+    Ranking(Vec<u32>),
+    Payout(Vec<u32>),
+    Cancelled,
+}
+
+/// Lifecycle of a Game Hub session, as returned by `get_session_status`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SessionStatus {
+    Active,
+    Ended(SessionResult),
+}
+
+// ============================================================================
+// Randomness Provider Interface
+// ============================================================================
+
+/// Interface of an external randomness contract (an oracle, or
+/// `mock-randomness` in development). A game requests a value, the oracle
+/// fulfills it in a later transaction, and the game reads it back.
+#[contractclient(name = "RandomnessProviderClient")]
+pub trait RandomnessProvider {
+    /// Request randomness. `requester` must authorize.
+    fn request(env: Env, requester: Address, request_id: u64);
+
+    /// Deliver the randomness for a pending request
+    fn fulfill(env: Env, requester: Address, request_id: u64, randomness: BytesN<32>);
+
+    /// The randomness delivered for a request, or `None` while it is pending
+    fn get_randomness(env: Env, requester: Address, request_id: u64) -> Option<BytesN<32>>;
+}
+
+/// Request ID a game uses for one round of a session
+pub fn randomness_request_id(session_id: u32, round: u32) -> u64 {
+    ((session_id as u64) << 32) | round as u64
+}
+
+// ============================================================================
+// Storage
+// ============================================================================
+
+/// Instance storage keys shared by all games. They encode the same way as the
+/// `DataKey::Admin` / `DataKey::GameHubAddress` variants games used to define
+/// themselves, so upgraded contracts keep their stored values.
+#[contracttype]
+#[derive(Clone)]
+pub enum CommonKey {
+    GameHubAddress,
+    Admin,
+    RandomnessProvider,
+    ActionTimeout,
+}
+
+/// TTL for game storage (30 days in ledgers, ~5 seconds per ledger)
+/// 30 days = 30 * 24 * 60 * 60 / 5 = 518,400 ledgers
+pub const GAME_TTL_LEDGERS: u32 = 518_400;
+
+/// Default ledgers players have to act before a timeout can be claimed (1 day)
+pub const DEFAULT_ACTION_TIMEOUT_LEDGERS: u32 = 17_280;
+
+/// Extend a temporary game entry so it is retained for at least 30 days
+pub fn extend_game_ttl<K: IntoVal<Env, Val>>(env: &Env, key: &K) {
+    env.storage()
+        .temporary()
+        .extend_ttl(key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+}
+
+// ============================================================================
+// Admin and Game Hub
+// ============================================================================
+
+/// Store the admin and GameHub address. Called from a game's constructor.
+///
+/// # Arguments
+/// * `admin` - Admin address (can upgrade contract)
+/// * `game_hub` - Address of the GameHub contract
+pub fn init(env: &Env, admin: &Address, game_hub: &Address) {
+    env.storage().instance().set(&CommonKey::Admin, admin);
+    env.storage()
+        .instance()
+        .set(&CommonKey::GameHubAddress, game_hub);
+}
+
+/// Whether `init` has already run
+pub fn is_initialized(env: &Env) -> bool {
+    env.storage().instance().has(&CommonKey::Admin)
+}
+
+/// Get the current admin address
+pub fn get_admin(env: &Env) -> Address {
+    env.storage()
+        .instance()
+        .get(&CommonKey::Admin)
+        .expect("Admin not set")
+}
+
+/// Require the admin's authorization for the current call
+///
+/// # Returns
+/// * `Address` - The admin address
+pub fn require_admin(env: &Env) -> Address {
+    let admin = get_admin(env);
+    admin.require_auth();
+    admin
+}
+
+/// Set a new admin address. Requires the current admin's authorization.
+pub fn set_admin(env: &Env, new_admin: &Address) {
+    require_admin(env);
+    env.storage().instance().set(&CommonKey::Admin, new_admin);
+}
+
+/// Get the current GameHub contract address
+pub fn get_hub(env: &Env) -> Address {
+    env.storage()
+        .instance()
+        .get(&CommonKey::GameHubAddress)
+        .expect("GameHub address not set")
+}
+
+/// Set a new GameHub contract address. Requires the admin's authorization.
+pub fn set_hub(env: &Env, new_hub: &Address) {
+    require_admin(env);
+    env.storage()
+        .instance()
+        .set(&CommonKey::GameHubAddress, new_hub);
+}
+
+/// Create a client for the configured GameHub contract
+pub fn hub_client(env: &Env) -> GameHubClient<'_> {
+    GameHubClient::new(env, &get_hub(env))
+}
+
+/// Update the contract WASM hash. Requires the admin's authorization.
+pub fn upgrade(env: &Env, new_wasm_hash: BytesN<32>) {
+    require_admin(env);
+    env.deployer().update_current_contract_wasm(new_wasm_hash);
+}
+
+// ============================================================================
+// Action Timeout
+// ============================================================================
+
+/// Get the number of ledgers players have to act in each phase of a game
+pub fn get_action_timeout(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&CommonKey::ActionTimeout)
+        .unwrap_or(DEFAULT_ACTION_TIMEOUT_LEDGERS)
+}
+
+/// Set the action timeout for new game phases. Requires the admin's
+/// authorization. Phases already running keep their deadline.
+pub fn set_action_timeout(env: &Env, ledgers: u32) {
+    require_admin(env);
+    env.storage()
+        .instance()
+        .set(&CommonKey::ActionTimeout, &ledgers);
+}
+
+/// Deadline for a phase starting on the current ledger
+pub fn action_deadline(env: &Env) -> u32 {
+    env.ledger()
+        .sequence()
+        .saturating_add(get_action_timeout(env))
+}
+
+// ============================================================================
+// Randomness Provider
+// ============================================================================
+
+/// Store the randomness provider a game was constructed with, if any
+pub fn init_randomness_provider(env: &Env, provider: &Option<Address>) {
+    if let Some(provider) = provider {
+        env.storage()
+            .instance()
+            .set(&CommonKey::RandomnessProvider, provider);
+    }
+}
+
+/// Get the configured randomness provider, if any
+pub fn get_randomness_provider(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&CommonKey::RandomnessProvider)
+}
+
+/// Request randomness for `request_id` from the configured provider. Does
+/// nothing when the game has no provider.
+pub fn request_randomness(env: &Env, request_id: u64) {
+    if let Some(provider) = get_randomness_provider(env) {
+        RandomnessProviderClient::new(env, &provider)
+            .request(&env.current_contract_address(), &request_id);
+    }
+}
+
+/// Mix the provider's randomness for `request_id` into `seed`.
+///
+/// # Returns
+/// * `Some(seed)` unchanged when the game has no provider, or
+///   `keccak256(seed || randomness)` once the request is fulfilled
+/// * `None` while the request is still pending
+pub fn mix_provider_randomness(env: &Env, request_id: u64, seed: BytesN<32>) -> Option<BytesN<32>> {
+    let Some(provider) = get_randomness_provider(env) else {
+        return Some(seed);
+    };
+    let randomness = RandomnessProviderClient::new(env, &provider)
+        .get_randomness(&env.current_contract_address(), &request_id)?;
+
+    let mut seed_bytes = Bytes::from(seed);
+    seed_bytes.append(&Bytes::from(randomness));
+    Some(env.crypto().keccak256(&seed_bytes).into())
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,321 @@
+#![cfg(test)]
+
+// Unit tests for the shared game helpers, run through a minimal contract
+// that forwards its admin endpoints the same way the games do.
+
+use crate::beacon::{commitment, Beacon, BeaconError, SeedRng};
+use crate::{CommonKey, GameOutcome};
+use soroban_sdk::testutils::{Address as _, Ledger as _, MockAuth, MockAuthInvoke};
+use soroban_sdk::{contract, contractimpl, vec, Address, Bytes, BytesN, Env, IntoVal};
+
+#[contract]
+pub struct TestGame;
+
+#[contractimpl]
+impl TestGame {
+    pub fn __constructor(env: Env, admin: Address, game_hub: Address) {
+        crate::init(&env, &admin, &game_hub);
+    }
+
+    pub fn get_admin(env: Env) -> Address {
+        crate::get_admin(&env)
+    }
+
+    pub fn set_admin(env: Env, new_admin: Address) {
+        crate::set_admin(&env, &new_admin);
+    }
+
+    pub fn get_hub(env: Env) -> Address {
+        crate::get_hub(&env)
+    }
+
+    pub fn set_hub(env: Env, new_hub: Address) {
+        crate::set_hub(&env, &new_hub);
+    }
+}
+
+#[contract]
+pub struct MockGameHub;
+
+#[contractimpl]
+impl MockGameHub {
+    pub fn end_game(_env: Env, _session_id: u32, _outcome: GameOutcome) {}
+}
+
+fn setup_test() -> (Env, TestGameClient<'static>, Address, Address) {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    let hub = Address::generate(&env);
+    let contract_id = env.register(TestGame, (&admin, &hub));
+    let client = TestGameClient::new(&env, &contract_id);
+    (env, client, admin, hub)
+}
+
+#[test]
+fn test_init_stores_admin_and_hub() {
+    let (env, client, admin, hub) = setup_test();
+
+    assert_eq!(client.get_admin(), admin);
+    assert_eq!(client.get_hub(), hub);
+    env.as_contract(&client.address, || assert!(crate::is_initialized(&env)));
+}
+
+#[test]
+fn test_admin_can_change_admin_and_hub() {
+    let (env, client, admin, _hub) = setup_test();
+    env.mock_all_auths();
+
+    let new_hub = Address::generate(&env);
+    client.set_hub(&new_hub);
+    assert_eq!(client.get_hub(), new_hub);
+
+    let new_admin = Address::generate(&env);
+    client.set_admin(&new_admin);
+    assert_eq!(env.auths()[0].0, admin);
+    assert_eq!(client.get_admin(), new_admin);
+}
+
+#[test]
+fn test_set_hub_requires_admin() {
+    let (env, client, _admin, _hub) = setup_test();
+
+    let intruder = Address::generate(&env);
+    let new_hub = Address::generate(&env);
+    let result = client
+        .mock_auths(&[MockAuth {
+            address: &intruder,
+            invoke: &MockAuthInvoke {
+                contract: &client.address,
+                fn_name: "set_hub",
+                args: (&new_hub,).into_val(&env),
+                sub_invokes: &[],
+            },
+        }])
+        .try_set_hub(&new_hub);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_hub_client_targets_stored_hub() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    let hub = env.register(MockGameHub, ());
+    let game = env.register(TestGame, (&admin, &hub));
+
+    env.as_contract(&game, || {
+        let client = crate::hub_client(&env);
+        assert_eq!(client.address, hub);
+        client.end_game(&1, &GameOutcome::Draw);
+        // Keys are the shared instance keys
+        assert!(env.storage().instance().has(&CommonKey::GameHubAddress));
+    });
+}
+
+#[test]
+fn test_action_timeout_defaults_and_is_admin_set() {
+    let (env, client, admin, _hub) = setup_test();
+    env.mock_all_auths();
+    env.ledger().set_sequence_number(100);
+
+    env.as_contract(&client.address, || {
+        assert_eq!(
+            crate::get_action_timeout(&env),
+            crate::DEFAULT_ACTION_TIMEOUT_LEDGERS
+        );
+        assert_eq!(
+            crate::action_deadline(&env),
+            100 + crate::DEFAULT_ACTION_TIMEOUT_LEDGERS
+        );
+
+        crate::set_action_timeout(&env, 50);
+        assert_eq!(crate::get_action_timeout(&env), 50);
+        assert_eq!(crate::action_deadline(&env), 150);
+    });
+    assert_eq!(env.auths()[0].0, admin);
+}
+
+// ============================================================================
+// Beacon
+// ============================================================================
+
+fn value(env: &Env, seed: u8) -> Bytes {
+    Bytes::from_array(env, &[seed; 32])
+}
+
+fn setup_beacon() -> (Env, Address, Address, Address) {
+    let env = Env::default();
+    let (admin, hub) = (Address::generate(&env), Address::generate(&env));
+    let game = env.register(TestGame, (&admin, &hub));
+    let (alice, bob) = (Address::generate(&env), Address::generate(&env));
+    (env, game, alice, bob)
+}
+
+#[test]
+fn test_beacon_round() {
+    let (env, game, alice, bob) = setup_beacon();
+
+    env.as_contract(&game, || {
+        let mut beacon = Beacon::new(&env, vec![&env, alice.clone(), bob.clone()], 10, 10);
+        beacon
+            .commit(&env, &alice, commitment(&env, &value(&env, 1)))
+            .unwrap();
+
+        // Alice waits on Bob's commitment before she can reveal
+        assert!(!beacon.awaiting(&alice));
+        assert!(beacon.awaiting(&bob));
+
+        // Nobody reveals before everyone is committed
+        assert_eq!(
+            beacon.reveal(&env, &alice, value(&env, 1)),
+            Err(BeaconError::NotAllCommitted)
+        );
+        beacon
+            .commit(&env, &bob, commitment(&env, &value(&env, 2)))
+            .unwrap();
+        assert!(beacon.all_committed());
+
+        assert!(beacon.awaiting(&alice));
+
+        beacon.reveal(&env, &alice, value(&env, 1)).unwrap();
+        assert!(!beacon.awaiting(&alice));
+        assert_eq!(beacon.seed(&env), Err(BeaconError::NotAllRevealed));
+        beacon.reveal(&env, &bob, value(&env, 2)).unwrap();
+        assert!(beacon.all_revealed());
+        assert_eq!(beacon.revealed(&bob), Some(value(&env, 2)));
+
+        // The seed depends on every reveal
+        let seed = beacon.seed(&env).unwrap();
+        let mut other = Beacon::new(&env, vec![&env, alice.clone(), bob.clone()], 10, 10);
+        other
+            .commit(&env, &alice, commitment(&env, &value(&env, 1)))
+            .unwrap();
+        other
+            .commit(&env, &bob, commitment(&env, &value(&env, 3)))
+            .unwrap();
+        other.reveal(&env, &alice, value(&env, 1)).unwrap();
+        other.reveal(&env, &bob, value(&env, 3)).unwrap();
+        assert_ne!(other.seed(&env).unwrap(), seed);
+
+        // The seed hashes the values themselves, so it can't be worked out
+        // from the commitments, which are public before anyone reveals
+        let mut seed_bytes = Bytes::new(&env);
+        for seed_value in [value(&env, 1), value(&env, 2)] {
+            seed_bytes.append(&Bytes::from_array(&env, &32u32.to_be_bytes()));
+            seed_bytes.append(&seed_value);
+        }
+        assert_eq!(seed, BytesN::from(env.crypto().keccak256(&seed_bytes)));
+        let mut commitment_bytes = Bytes::from(commitment(&env, &value(&env, 1)));
+        commitment_bytes.append(&Bytes::from(commitment(&env, &value(&env, 2))));
+        assert_ne!(
+            seed,
+            BytesN::from(env.crypto().keccak256(&commitment_bytes))
+        );
+    });
+}
+
+#[test]
+fn test_beacon_rejects_bad_commits_and_reveals() {
+    let (env, game, alice, bob) = setup_beacon();
+    let outsider = Address::generate(&env);
+
+    env.as_contract(&game, || {
+        let mut beacon = Beacon::new(&env, vec![&env, alice.clone(), bob.clone()], 10, 10);
+        let alice_commitment = commitment(&env, &value(&env, 1));
+
+        assert_eq!(
+            beacon.commit(&env, &outsider, alice_commitment.clone()),
+            Err(BeaconError::NotParty)
+        );
+        beacon
+            .commit(&env, &alice, alice_commitment.clone())
+            .unwrap();
+        assert_eq!(
+            beacon.commit(&env, &alice, alice_commitment),
+            Err(BeaconError::AlreadyCommitted)
+        );
+        beacon
+            .commit(&env, &bob, commitment(&env, &value(&env, 2)))
+            .unwrap();
+
+        assert_eq!(
+            beacon.reveal(&env, &alice, value(&env, 2)),
+            Err(BeaconError::CommitmentMismatch)
+        );
+        beacon.reveal(&env, &alice, value(&env, 1)).unwrap();
+        assert_eq!(
+            beacon.reveal(&env, &alice, value(&env, 1)),
+            Err(BeaconError::AlreadyRevealed)
+        );
+    });
+}
+
+#[test]
+fn test_beacon_deadlines() {
+    let (env, game, alice, bob) = setup_beacon();
+    env.ledger().set_sequence_number(100);
+
+    env.as_contract(&game, || {
+        let mut beacon = Beacon::new(&env, vec![&env, alice.clone(), bob.clone()], 10, 5);
+        assert_eq!(beacon.commit_deadline, 110);
+        beacon
+            .commit(&env, &alice, commitment(&env, &value(&env, 1)))
+            .unwrap();
+
+        env.ledger().set_sequence_number(111);
+        assert_eq!(
+            beacon.commit(&env, &bob, commitment(&env, &value(&env, 2))),
+            Err(BeaconError::CommitDeadlinePassed)
+        );
+
+        // The reveal window starts at the last commitment
+        env.ledger().set_sequence_number(110);
+        beacon
+            .commit(&env, &bob, commitment(&env, &value(&env, 2)))
+            .unwrap();
+        assert_eq!(beacon.reveal_deadline, Some(115));
+
+        env.ledger().set_sequence_number(115);
+        beacon.reveal(&env, &alice, value(&env, 1)).unwrap();
+        env.ledger().set_sequence_number(116);
+        assert_eq!(
+            beacon.reveal(&env, &bob, value(&env, 2)),
+            Err(BeaconError::RevealDeadlinePassed)
+        );
+    });
+}
+
+#[test]
+fn test_seed_rng_draws_are_bounded_and_replayable() {
+    let (env, game, _alice, _bob) = setup_beacon();
+
+    env.as_contract(&game, || {
+        let seed = commitment(&env, &value(&env, 7));
+        let mut rng = SeedRng::new(&env, seed.clone());
+        let mut replay = SeedRng::new(&env, seed);
+
+        let mut seen = [false; 13];
+        for _ in 0..200 {
+            let card = rng.range(1..=13);
+            assert!((1..=13).contains(&card));
+            assert_eq!(replay.range(1..=13), card);
+            seen[(card - 1) as usize] = true;
+        }
+        assert!(seen.iter().all(|s| *s));
+    });
+}
+
+#[test]
+fn test_seed_rng_draws_follow_keccak_formula() {
+    let (env, _game, _alice, _bob) = setup_beacon();
+
+    // Draws can be replayed off-chain: start + first 8 bytes of keccak256(seed || n) mod span
+    let seed = commitment(&env, &value(&env, 7));
+    let mut rng = SeedRng::new(&env, seed.clone());
+    for n in 0u32..20 {
+        let mut draw_seed = Bytes::from(seed.clone());
+        draw_seed.append(&Bytes::from_array(&env, &n.to_be_bytes()));
+        let hash = env.crypto().keccak256(&draw_seed).to_array();
+        let word = u64::from_be_bytes(hash[..8].try_into().unwrap());
+        assert_eq!(rng.range(1..=6), 1 + (word % 6) as u32);
+    }
+}
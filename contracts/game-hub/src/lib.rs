@@ -0,0 +1,1254 @@
+#![no_std]
+
+//! # Game Hub
+//!
+//! Production Game Hub contract for the game studio.
+//!
+//! Exposes the same `start_game` / `end_game` interface as `mock-game-hub`,
+//! but actually settles the wagers:
+//! - Only game contracts registered by the admin can open sessions
+//! - Every player has a points balance held by the hub, or, for games
+//!   configured with a SEP-41 token, stakes are real token transfers
+//! - `start_game` locks both players' stakes in escrow for the session
+//! - `end_game` pays the whole pot out to the winner, refunds both stakes on
+//!   a draw and unlocks them when the session is cancelled
+//! - Table games use `start_session` / `end_session` for any number of
+//!   players, settling by ranking or by an explicit payout split; the
+//!   two-player calls are thin wrappers around them
+//! - Sessions that are never ended can be expired by anyone after their
+//!   deadline ledger, refunding both stakes
+//! - Every decided or drawn session updates both players' ELO rating for
+//!   that game contract
+//! - Per-player indexes of active sessions, finished session history and
+//!   win/loss/draw counters can be queried instead of scraping events
+//!
+//! Game contracts use this hub through their `GameHub` contract client, the
+//! same way they use the mock.
+
+use soroban_sdk::{
+    contract, contracterror, contractevent, contractimpl, contracttype, token, vec, Address,
+    BytesN, Env, Vec,
+};
+
+use core::cmp::Ordering;
+
+// ============================================================================
+// Errors
+// ============================================================================
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    SessionNotFound = 1,
+    SessionAlreadyEnded = 2,
+    InsufficientBalance = 3,
+    InvalidAmount = 4,
+    SelfPlay = 5,
+    GameNotRegistered = 6,
+    SessionExists = 7,
+    SessionNotExpired = 8,
+    InvalidFee = 9,
+    InvalidPlayers = 10,
+    InvalidResult = 11,
+}
+
+// ============================================================================
+// Events
+// ============================================================================
+
+#[contractevent]
+pub struct GameStarted {
+    pub session_id: u32,
+    pub game_id: Address,
+    pub player1: Address,
+    pub player2: Address,
+    pub player1_points: i128,
+    pub player2_points: i128,
+}
+
+#[contractevent]
+pub struct GameEnded {
+    pub session_id: u32,
+    pub outcome: GameOutcome,
+    /// Studio fee taken from the pot, in the session's stake unit
+    pub fee: i128,
+}
+
+#[contractevent]
+pub struct SessionStarted {
+    pub session_id: u32,
+    pub game_id: Address,
+    pub players: Vec<Address>,
+    pub stakes: Vec<i128>,
+}
+
+#[contractevent]
+pub struct SessionEnded {
+    pub session_id: u32,
+    pub result: SessionResult,
+    /// Studio fee taken from the pot, in the session's stake unit
+    pub fee: i128,
+}
+
+// ============================================================================
+// Data Types
+// ============================================================================
+
+/// Result of a session, reported by the game contract in `end_game`
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum GameOutcome {
+    Player1Won,
+    Player2Won,
+    Draw,
+    Cancelled,
+}
+
+/// Result of a session, reported by the game contract in `end_session`.
+/// Per-player values are indexed like the session's `players`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SessionResult {
+    /// Finishing position of each player, lower is better. Players sharing
+    /// the best position split the pot; if everyone shares it the session is
+    /// a draw.
+    Ranking(Vec<u32>),
+    /// Share of the pot (after the fee) each player receives, in basis points
+    /// summing to 10,000. Payout sessions are not rated.
+    Payout(Vec<u32>),
+    Cancelled,
+}
+
+/// Lifecycle of a hub session
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SessionStatus {
+    Active,
+    Ended(SessionResult),
+}
+
+/// Settings of a registered game contract
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GameConfig {
+    /// SEP-41 token used for stakes, or `None` for hub points
+    pub token: Option<Address>,
+    /// Studio fee taken from the pot of decided sessions, in basis points
+    pub fee_bps: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Session {
+    pub game_id: Address,
+    pub players: Vec<Address>,
+    /// Stake locked by each player, indexed like `players`
+    pub stakes: Vec<i128>,
+    pub status: SessionStatus,
+    /// Ledger sequence after which anyone may call `expire_session`
+    pub deadline_ledger: u32,
+    /// Token escrowed for this session (`None` for hub points), fixed at start
+    pub token: Option<Address>,
+    /// Studio fee in basis points, fixed at start
+    pub fee_bps: u32,
+}
+
+/// A player's rating in one game, as listed by `get_top_players`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RatingEntry {
+    pub player: Address,
+    pub rating: u32,
+}
+
+/// A player's results in one game contract
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct PlayerStats {
+    pub wins: u32,
+    pub losses: u32,
+    pub draws: u32,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    Admin,
+    Balance(Address),
+    Session(u32),
+    Game(Address),
+    SessionTimeout,
+    Rating(Address, Address), // (player, game_id)
+    Leaderboard(Address),     // game_id
+    Stats(Address, Address),  // (player, game_id)
+    ActiveSessions(Address),
+    HistoryLen(Address),
+    History(Address, u32), // (player, index) -> session_id
+    Treasury,
+    Fees(Option<Address>), // token (None for points) -> accrued fees
+}
+
+// ============================================================================
+// Storage TTL Management
+// ============================================================================
+// Balances and sessions hold real value, so they live in persistent storage
+// and are bumped on every write.
+
+/// TTL for balances and sessions (30 days in ledgers, ~5 seconds per ledger)
+/// 30 days = 30 * 24 * 60 * 60 / 5 = 518,400 ledgers
+const PERSISTENT_TTL_LEDGERS: u32 = 518_400;
+
+/// Default number of ledgers a session may stay open before it can be expired.
+/// Matches the 30-day TTL games use for their own temporary storage, so stakes
+/// become refundable around the time an abandoned game's state disappears.
+const DEFAULT_SESSION_TIMEOUT_LEDGERS: u32 = 518_400;
+
+/// Basis points in 100%; the upper bound for a game's fee
+const MAX_FEE_BPS: u32 = 10_000;
+
+/// Most players a single session can seat
+const MAX_PLAYERS: u32 = 10;
+
+// ============================================================================
+// Helper Functions
+// ============================================================================
+
+fn bump_persistent(env: &Env, key: &DataKey) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, PERSISTENT_TTL_LEDGERS, PERSISTENT_TTL_LEDGERS);
+}
+
+fn read_balance(env: &Env, player: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Balance(player.clone()))
+        .unwrap_or(0)
+}
+
+fn write_balance(env: &Env, player: &Address, amount: i128) {
+    let key = DataKey::Balance(player.clone());
+    env.storage().persistent().set(&key, &amount);
+    bump_persistent(env, &key);
+}
+
+/// Add `amount` to a player's available balance
+fn credit(env: &Env, player: &Address, amount: i128) {
+    write_balance(env, player, read_balance(env, player) + amount);
+}
+
+/// Move a player's stake into escrow: a token transfer into the hub, or a
+/// debit of their points balance
+fn lock_stake(
+    env: &Env,
+    token: &Option<Address>,
+    player: &Address,
+    amount: i128,
+) -> Result<(), Error> {
+    if amount == 0 {
+        return Ok(());
+    }
+    match token {
+        Some(token) => {
+            token::Client::new(env, token).transfer(
+                player,
+                env.current_contract_address(),
+                &amount,
+            );
+        }
+        None => {
+            let balance = read_balance(env, player);
+            if balance < amount {
+                return Err(Error::InsufficientBalance);
+            }
+            write_balance(env, player, balance - amount);
+        }
+    }
+    Ok(())
+}
+
+/// Pay `amount` out of a session's escrow to a player
+fn pay_out(env: &Env, session: &Session, player: &Address, amount: i128) {
+    if amount == 0 {
+        return;
+    }
+    match &session.token {
+        Some(token) => {
+            token::Client::new(env, token).transfer(
+                &env.current_contract_address(),
+                player,
+                &amount,
+            );
+        }
+        None => credit(env, player, amount),
+    }
+}
+
+fn read_fees(env: &Env, token: &Option<Address>) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Fees(token.clone()))
+        .unwrap_or(0)
+}
+
+/// Add a settled session's fee to the fees awaiting withdrawal
+fn accrue_fee(env: &Env, token: &Option<Address>, fee: i128) {
+    if fee == 0 {
+        return;
+    }
+    let key = DataKey::Fees(token.clone());
+    env.storage()
+        .persistent()
+        .set(&key, &(read_fees(env, token) + fee));
+    bump_persistent(env, &key);
+}
+
+fn read_game_config(env: &Env, game_id: &Address) -> Result<GameConfig, Error> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Game(game_id.clone()))
+        .ok_or(Error::GameNotRegistered)
+}
+
+fn write_game_config(env: &Env, game_id: &Address, config: &GameConfig) {
+    let key = DataKey::Game(game_id.clone());
+    env.storage().persistent().set(&key, config);
+    bump_persistent(env, &key);
+}
+
+fn write_session(env: &Env, session_id: u32, session: &Session) {
+    let key = DataKey::Session(session_id);
+    env.storage().persistent().set(&key, session);
+    bump_persistent(env, &key);
+}
+
+fn read_session(env: &Env, session_id: u32) -> Result<Session, Error> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Session(session_id))
+        .ok_or(Error::SessionNotFound)
+}
+
+/// Express a two-player outcome as a session result
+fn outcome_result(env: &Env, outcome: GameOutcome) -> SessionResult {
+    match outcome {
+        GameOutcome::Player1Won => SessionResult::Ranking(vec![env, 0, 1]),
+        GameOutcome::Player2Won => SessionResult::Ranking(vec![env, 1, 0]),
+        GameOutcome::Draw => SessionResult::Ranking(vec![env, 0, 0]),
+        GameOutcome::Cancelled => SessionResult::Cancelled,
+    }
+}
+
+/// Check a reported result has one entry per player and, for payouts, that
+/// the shares add up to the whole pot
+fn validate_result(session: &Session, result: &SessionResult) -> Result<(), Error> {
+    let valid = match result {
+        SessionResult::Ranking(ranks) => ranks.len() == session.players.len(),
+        SessionResult::Payout(shares) => {
+            shares.len() == session.players.len()
+                && shares.iter().map(|share| share as u64).sum::<u64>() == MAX_FEE_BPS as u64
+        }
+        SessionResult::Cancelled => true,
+    };
+    if valid {
+        Ok(())
+    } else {
+        Err(Error::InvalidResult)
+    }
+}
+
+/// Whether a ranking has every player sharing the best position
+fn is_draw(ranks: &Vec<u32>) -> bool {
+    let best = ranks.iter().min().unwrap_or(0);
+    ranks.iter().all(|rank| rank == best)
+}
+
+/// Pay out the locked stakes according to `result` and close the session.
+///
+/// # Returns
+/// * `i128` - The studio fee taken from the pot
+fn settle(env: &Env, session_id: u32, session: &mut Session, result: SessionResult) -> i128 {
+    let pot: i128 = session.stakes.iter().sum();
+    let net = pot - pot * session.fee_bps as i128 / MAX_FEE_BPS as i128;
+
+    // Amount paid to each player, indexed like `players`
+    let payouts: Vec<i128> = match &result {
+        // Nobody wins: each player gets their own stake back
+        SessionResult::Cancelled => session.stakes.clone(),
+        SessionResult::Ranking(ranks) if is_draw(ranks) => session.stakes.clone(),
+        // Players sharing the best position split the pot, minus the fee
+        SessionResult::Ranking(ranks) => {
+            let best = ranks.iter().min().unwrap_or(0);
+            let winners = ranks.iter().filter(|rank| *rank == best).count() as i128;
+            Vec::from_iter(
+                env,
+                ranks
+                    .iter()
+                    .map(|rank| if rank == best { net / winners } else { 0 }),
+            )
+        }
+        SessionResult::Payout(shares) => Vec::from_iter(
+            env,
+            shares
+                .iter()
+                .map(|share| net * share as i128 / MAX_FEE_BPS as i128),
+        ),
+    };
+
+    // Anything not paid out, including rounding dust, is the studio's fee
+    let fee = pot - payouts.iter().sum::<i128>();
+    accrue_fee(env, &session.token, fee);
+    for (player, amount) in session.players.iter().zip(payouts.iter()) {
+        pay_out(env, session, &player, amount);
+    }
+
+    session.status = SessionStatus::Ended(result.clone());
+    write_session(env, session_id, session);
+
+    update_ratings(env, session, &result);
+    record_result(env, session_id, session, &result);
+
+    fee
+}
+
+// ============================================================================
+// Ratings
+// ============================================================================
+// Standard ELO with integer math: expected scores are looked up from a table
+// and linearly interpolated, so the result is identical on every node.
+
+/// Rating assigned to a player's first game of a given game contract
+const DEFAULT_RATING: u32 = 1200;
+
+/// Maximum rating change per game
+const K_FACTOR: i64 = 32;
+
+/// Number of players kept in each game's leaderboard
+const LEADERBOARD_SIZE: u32 = 50;
+
+/// Expected score (per mille) of the higher-rated player for rating
+/// differences of 0, 50, 100, ... 800 points: 1000 / (1 + 10^(-diff / 400))
+const EXPECTED_SCORE_TABLE: [i64; 17] = [
+    500, 571, 640, 703, 760, 808, 849, 882, 909, 930, 947, 960, 969, 977, 983, 987, 990,
+];
+
+/// Expected score (per mille) of a player rated `rating` against `opponent`
+fn expected_score(rating: u32, opponent: u32) -> i64 {
+    let diff = (rating as i64 - opponent as i64).clamp(-800, 800);
+    let abs = diff.abs();
+    let idx = (abs / 50) as usize;
+    let favourite = if idx + 1 < EXPECTED_SCORE_TABLE.len() {
+        let lo = EXPECTED_SCORE_TABLE[idx];
+        let hi = EXPECTED_SCORE_TABLE[idx + 1];
+        lo + (hi - lo) * (abs % 50) / 50
+    } else {
+        EXPECTED_SCORE_TABLE[idx]
+    };
+    if diff >= 0 {
+        favourite
+    } else {
+        1000 - favourite
+    }
+}
+
+fn read_rating(env: &Env, player: &Address, game_id: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Rating(player.clone(), game_id.clone()))
+        .unwrap_or(DEFAULT_RATING)
+}
+
+fn write_rating(env: &Env, player: &Address, game_id: &Address, rating: u32) {
+    let key = DataKey::Rating(player.clone(), game_id.clone());
+    env.storage().persistent().set(&key, &rating);
+    bump_persistent(env, &key);
+}
+
+/// Move `player` to its sorted position in the game's leaderboard, dropping
+/// whoever falls off the end
+fn update_leaderboard(env: &Env, game_id: &Address, player: &Address, rating: u32) {
+    let key = DataKey::Leaderboard(game_id.clone());
+    let mut board: Vec<RatingEntry> = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(Vec::new(env));
+
+    if let Some(pos) = board.iter().position(|entry| entry.player == *player) {
+        board.remove(pos as u32);
+    }
+
+    let pos = board
+        .iter()
+        .position(|entry| entry.rating < rating)
+        .map_or(board.len(), |pos| pos as u32);
+    if pos < LEADERBOARD_SIZE {
+        board.insert(
+            pos,
+            RatingEntry {
+                player: player.clone(),
+                rating,
+            },
+        );
+        if board.len() > LEADERBOARD_SIZE {
+            board.pop_back();
+        }
+    }
+
+    env.storage().persistent().set(&key, &board);
+    bump_persistent(env, &key);
+}
+
+/// Apply a ranking to the players' ratings. Each player is rated against
+/// every other player, with K split across their opponents so a two-player
+/// session is plain ELO. Cancelled and payout sessions don't count.
+fn update_ratings(env: &Env, session: &Session, result: &SessionResult) {
+    let SessionResult::Ranking(ranks) = result else {
+        return;
+    };
+
+    let game_id = &session.game_id;
+    let ratings: Vec<u32> = Vec::from_iter(
+        env,
+        session
+            .players
+            .iter()
+            .map(|player| read_rating(env, &player, game_id)),
+    );
+    let opponents = session.players.len() as i64 - 1;
+
+    for i in 0..session.players.len() {
+        let (rating, rank) = (ratings.get_unchecked(i), ranks.get_unchecked(i));
+
+        // Sum of score minus expected score (per mille) against each opponent
+        let mut surplus = 0;
+        for j in 0..session.players.len() {
+            if i == j {
+                continue;
+            }
+            let opponent_rank = ranks.get_unchecked(j);
+            let score = match rank.cmp(&opponent_rank) {
+                Ordering::Less => 1000,
+                Ordering::Equal => 500,
+                Ordering::Greater => 0,
+            };
+            surplus += score - expected_score(rating, ratings.get_unchecked(j));
+        }
+
+        let change = K_FACTOR * surplus / (1000 * opponents);
+        let new_rating = (rating as i64 + change).max(0) as u32;
+        let player = session.players.get_unchecked(i);
+        write_rating(env, &player, game_id, new_rating);
+        update_leaderboard(env, game_id, &player, new_rating);
+    }
+}
+
+// ============================================================================
+// Player Indexes
+// ============================================================================
+
+fn read_active_sessions(env: &Env, player: &Address) -> Vec<u32> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ActiveSessions(player.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+fn add_active_session(env: &Env, player: &Address, session_id: u32) {
+    let key = DataKey::ActiveSessions(player.clone());
+    let mut sessions = read_active_sessions(env, player);
+    sessions.push_back(session_id);
+    env.storage().persistent().set(&key, &sessions);
+    bump_persistent(env, &key);
+}
+
+/// Move a session from the player's active list to their history
+fn archive_session(env: &Env, player: &Address, session_id: u32) {
+    let key = DataKey::ActiveSessions(player.clone());
+    let mut sessions = read_active_sessions(env, player);
+    if let Some(pos) = sessions.first_index_of(session_id) {
+        sessions.remove(pos);
+    }
+    if sessions.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &sessions);
+        bump_persistent(env, &key);
+    }
+
+    // History is append-only, one entry per key so it never has to be
+    // loaded as a whole
+    let len_key = DataKey::HistoryLen(player.clone());
+    let len: u32 = env.storage().persistent().get(&len_key).unwrap_or(0);
+    let entry_key = DataKey::History(player.clone(), len);
+    env.storage().persistent().set(&entry_key, &session_id);
+    bump_persistent(env, &entry_key);
+    env.storage().persistent().set(&len_key, &(len + 1));
+    bump_persistent(env, &len_key);
+}
+
+fn read_stats(env: &Env, player: &Address, game_id: &Address) -> PlayerStats {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Stats(player.clone(), game_id.clone()))
+        .unwrap_or_default()
+}
+
+fn update_stats(env: &Env, player: &Address, game_id: &Address, update: fn(&mut PlayerStats)) {
+    let key = DataKey::Stats(player.clone(), game_id.clone());
+    let mut stats = read_stats(env, player, game_id);
+    update(&mut stats);
+    env.storage().persistent().set(&key, &stats);
+    bump_persistent(env, &key);
+}
+
+/// Update every player's indexes and counters for a finished session.
+/// Cancelled and payout sessions go into history but don't count as a result.
+fn record_result(env: &Env, session_id: u32, session: &Session, result: &SessionResult) {
+    for player in session.players.iter() {
+        archive_session(env, &player, session_id);
+    }
+
+    let SessionResult::Ranking(ranks) = result else {
+        return;
+    };
+    let game_id = &session.game_id;
+    if is_draw(ranks) {
+        for player in session.players.iter() {
+            update_stats(env, &player, game_id, |s| s.draws += 1);
+        }
+        return;
+    }
+
+    let best = ranks.iter().min().unwrap_or(0);
+    for (player, rank) in session.players.iter().zip(ranks.iter()) {
+        if rank == best {
+            update_stats(env, &player, game_id, |s| s.wins += 1);
+        } else {
+            update_stats(env, &player, game_id, |s| s.losses += 1);
+        }
+    }
+}
+
+// ============================================================================
+// Session Lifecycle
+// ============================================================================
+
+/// Validate and open a session, locking every player's stake
+fn open_session(
+    env: &Env,
+    game_id: &Address,
+    session_id: u32,
+    players: &Vec<Address>,
+    stakes: &Vec<i128>,
+) -> Result<(), Error> {
+    game_id.require_auth();
+
+    let config = read_game_config(env, game_id)?;
+
+    if players.len() < 2 || players.len() > MAX_PLAYERS || stakes.len() != players.len() {
+        return Err(Error::InvalidPlayers);
+    }
+    for (i, player) in players.iter().enumerate() {
+        if players.first_index_of(&player) != Some(i as u32) {
+            return Err(Error::SelfPlay);
+        }
+    }
+
+    // Session IDs are global to the hub: never overwrite an existing session
+    if env
+        .storage()
+        .persistent()
+        .has(&DataKey::Session(session_id))
+    {
+        return Err(Error::SessionExists);
+    }
+    if stakes.iter().any(|stake| stake < 0) {
+        return Err(Error::InvalidAmount);
+    }
+
+    // Lock stakes: move points or tokens from the players into the session
+    for (player, stake) in players.iter().zip(stakes.iter()) {
+        lock_stake(env, &config.token, &player, stake)?;
+    }
+
+    let session = Session {
+        game_id: game_id.clone(),
+        players: players.clone(),
+        stakes: stakes.clone(),
+        status: SessionStatus::Active,
+        deadline_ledger: env.ledger().sequence()
+            + GameHubContract::get_session_timeout(env.clone()),
+        token: config.token,
+        fee_bps: config.fee_bps,
+    };
+    write_session(env, session_id, &session);
+
+    for player in players.iter() {
+        add_active_session(env, &player, session_id);
+    }
+
+    env.storage().instance().extend_ttl(17_280, 518_400);
+
+    Ok(())
+}
+
+// ============================================================================
+// Contract Definition
+// ============================================================================
+
+#[contract]
+pub struct GameHubContract;
+
+#[contractimpl]
+impl GameHubContract {
+    /// Initialize the hub with an admin
+    ///
+    /// # Arguments
+    /// * `admin` - Admin address (can credit points and upgrade contract)
+    pub fn __constructor(env: Env, admin: Address) {
+        env.storage().instance().set(&DataKey::Admin, &admin);
+    }
+
+    /// Start a game session and lock both players' stakes in escrow.
+    ///
+    /// Must be called by a registered game contract: `game_id.require_auth()`
+    /// is satisfied automatically when the game contract is the direct caller.
+    ///
+    /// # Arguments
+    /// * `game_id` - Address of the game contract calling this method
+    /// * `session_id` - Unique identifier for this game session
+    /// * `player1` - Address of first player
+    /// * `player2` - Address of second player
+    /// * `player1_points` - Points locked by player 1
+    /// * `player2_points` - Points locked by player 2
+    pub fn start_game(
+        env: Env,
+        game_id: Address,
+        session_id: u32,
+        player1: Address,
+        player2: Address,
+        player1_points: i128,
+        player2_points: i128,
+    ) -> Result<(), Error> {
+        open_session(
+            &env,
+            &game_id,
+            session_id,
+            &vec![&env, player1.clone(), player2.clone()],
+            &vec![&env, player1_points, player2_points],
+        )?;
+
+        GameStarted {
+            session_id,
+            game_id,
+            player1,
+            player2,
+            player1_points,
+            player2_points,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Start a session for any number of players and lock their stakes in
+    /// escrow. Same rules as `start_game`.
+    ///
+    /// # Arguments
+    /// * `game_id` - Address of the game contract calling this method
+    /// * `session_id` - Unique identifier for this game session
+    /// * `players` - Addresses of the players (2 to 10, all different)
+    /// * `stakes` - Amount locked by each player, indexed like `players`
+    pub fn start_session(
+        env: Env,
+        game_id: Address,
+        session_id: u32,
+        players: Vec<Address>,
+        stakes: Vec<i128>,
+    ) -> Result<(), Error> {
+        open_session(&env, &game_id, session_id, &players, &stakes)?;
+
+        SessionStarted {
+            session_id,
+            game_id,
+            players,
+            stakes,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// End a game session and settle the locked stakes.
+    ///
+    /// - `Player1Won` / `Player2Won`: the winner takes the whole pot
+    /// - `Draw`: both stakes are refunded
+    /// - `Cancelled`: both stakes are unlocked back to their owners
+    ///
+    /// Only the game contract that opened the session can end it. Sessions
+    /// of more than two players must be ended with `end_session`, except to
+    /// cancel them.
+    ///
+    /// # Arguments
+    /// * `session_id` - The game session being ended
+    /// * `outcome` - How the session ended
+    pub fn end_game(env: Env, session_id: u32, outcome: GameOutcome) -> Result<(), Error> {
+        let mut session = read_session(&env, session_id)?;
+
+        session.game_id.require_auth();
+
+        if session.status != SessionStatus::Active {
+            return Err(Error::SessionAlreadyEnded);
+        }
+        let result = outcome_result(&env, outcome);
+        validate_result(&session, &result)?;
+
+        let fee = settle(&env, session_id, &mut session, result);
+
+        GameEnded {
+            session_id,
+            outcome,
+            fee,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// End a session of any number of players and settle the locked stakes.
+    ///
+    /// - `Ranking`: players sharing the best position split the pot; if all
+    ///   players share it, every stake is refunded
+    /// - `Payout`: the pot is split by the given basis-point shares
+    /// - `Cancelled`: every stake is unlocked back to its owner
+    ///
+    /// Only the game contract that opened the session can end it.
+    ///
+    /// # Arguments
+    /// * `session_id` - The game session being ended
+    /// * `result` - How the session ended, indexed like the session's players
+    pub fn end_session(env: Env, session_id: u32, result: SessionResult) -> Result<(), Error> {
+        let mut session = read_session(&env, session_id)?;
+
+        session.game_id.require_auth();
+
+        if session.status != SessionStatus::Active {
+            return Err(Error::SessionAlreadyEnded);
+        }
+        validate_result(&session, &result)?;
+
+        let fee = settle(&env, session_id, &mut session, result.clone());
+
+        SessionEnded {
+            session_id,
+            result,
+            fee,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Expire an abandoned session, refunding every stake.
+    ///
+    /// Anyone can call this once the ledger sequence has passed the session's
+    /// `deadline_ledger`. The session is closed as `Cancelled`.
+    ///
+    /// # Arguments
+    /// * `session_id` - The game session to expire
+    pub fn expire_session(env: Env, session_id: u32) -> Result<(), Error> {
+        let mut session = read_session(&env, session_id)?;
+
+        if session.status != SessionStatus::Active {
+            return Err(Error::SessionAlreadyEnded);
+        }
+        if env.ledger().sequence() <= session.deadline_ledger {
+            return Err(Error::SessionNotExpired);
+        }
+
+        settle(&env, session_id, &mut session, SessionResult::Cancelled);
+
+        SessionEnded {
+            session_id,
+            result: SessionResult::Cancelled,
+            fee: 0,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Get a session's escrow state.
+    ///
+    /// # Arguments
+    /// * `session_id` - The game session
+    ///
+    /// # Returns
+    /// * `Session` - Players, locked stakes and status
+    pub fn get_session(env: Env, session_id: u32) -> Result<Session, Error> {
+        read_session(&env, session_id)
+    }
+
+    /// Get a session's status. Game contracts can use this to check whether
+    /// a session was settled or expired by the hub.
+    ///
+    /// # Arguments
+    /// * `session_id` - The game session
+    ///
+    /// # Returns
+    /// * `SessionStatus` - `Active`, or `Ended` with the final outcome
+    pub fn get_session_status(env: Env, session_id: u32) -> Result<SessionStatus, Error> {
+        Ok(read_session(&env, session_id)?.status)
+    }
+
+    /// Get the number of ledgers new sessions stay open before they can be expired
+    pub fn get_session_timeout(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::SessionTimeout)
+            .unwrap_or(DEFAULT_SESSION_TIMEOUT_LEDGERS)
+    }
+
+    /// Get a player's available (unlocked) points balance.
+    ///
+    /// # Arguments
+    /// * `player` - Address of the player
+    pub fn get_balance(env: Env, player: Address) -> i128 {
+        read_balance(&env, &player)
+    }
+
+    /// Get a player's ELO rating for a game contract.
+    ///
+    /// # Arguments
+    /// * `player` - Address of the player
+    /// * `game_id` - Address of the game contract
+    ///
+    /// # Returns
+    /// * `u32` - The rating (1200 if the player has no rated games yet)
+    pub fn get_rating(env: Env, player: Address, game_id: Address) -> u32 {
+        read_rating(&env, &player, &game_id)
+    }
+
+    /// Get the highest-rated players of a game contract, best first.
+    ///
+    /// # Arguments
+    /// * `game_id` - Address of the game contract
+    /// * `limit` - Maximum number of entries to return (at most 50 are kept)
+    pub fn get_top_players(env: Env, game_id: Address, limit: u32) -> Vec<RatingEntry> {
+        let board: Vec<RatingEntry> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Leaderboard(game_id))
+            .unwrap_or(Vec::new(&env));
+        board.slice(0..limit.min(board.len()))
+    }
+
+    /// Get the sessions a player is currently in.
+    ///
+    /// # Arguments
+    /// * `player` - Address of the player
+    pub fn get_active_sessions(env: Env, player: Address) -> Vec<u32> {
+        read_active_sessions(&env, &player)
+    }
+
+    /// Get a page of a player's finished sessions, most recent first.
+    ///
+    /// # Arguments
+    /// * `player` - Address of the player
+    /// * `offset` - Number of most recent sessions to skip
+    /// * `limit` - Maximum number of session IDs to return
+    pub fn get_session_history(env: Env, player: Address, offset: u32, limit: u32) -> Vec<u32> {
+        let len: u32 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::HistoryLen(player.clone()))
+            .unwrap_or(0);
+
+        let mut page = Vec::new(&env);
+        let end = len.saturating_sub(offset);
+        let start = end.saturating_sub(limit);
+        for index in (start..end).rev() {
+            let session_id: u32 = env
+                .storage()
+                .persistent()
+                .get(&DataKey::History(player.clone(), index))
+                .expect("History entry missing");
+            page.push_back(session_id);
+        }
+        page
+    }
+
+    /// Get a player's win/loss/draw counters for a game contract.
+    ///
+    /// # Arguments
+    /// * `player` - Address of the player
+    /// * `game_id` - Address of the game contract
+    pub fn get_player_stats(env: Env, player: Address, game_id: Address) -> PlayerStats {
+        read_stats(&env, &player, &game_id)
+    }
+
+    /// Check whether a game contract is registered with the hub.
+    ///
+    /// # Arguments
+    /// * `game_id` - Address of the game contract
+    pub fn is_game(env: Env, game_id: Address) -> bool {
+        env.storage().persistent().has(&DataKey::Game(game_id))
+    }
+
+    /// Get the settings of a registered game contract.
+    ///
+    /// # Arguments
+    /// * `game_id` - Address of the game contract
+    pub fn get_game_config(env: Env, game_id: Address) -> Result<GameConfig, Error> {
+        read_game_config(&env, &game_id)
+    }
+
+    // ========================================================================
+    // Admin Functions
+    // ========================================================================
+
+    /// Register a game contract so it can open sessions. Registering a game
+    /// that is already registered leaves its token and fee unchanged.
+    ///
+    /// # Arguments
+    /// * `game_id` - Address of the game contract
+    pub fn add_game(env: Env, game_id: Address) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        if read_game_config(&env, &game_id).is_ok() {
+            return;
+        }
+        write_game_config(
+            &env,
+            &game_id,
+            &GameConfig {
+                token: None,
+                fee_bps: 0,
+            },
+        );
+    }
+
+    /// Set the SEP-41 token a game's stakes are escrowed in. Sessions already
+    /// started keep the token they were opened with.
+    ///
+    /// # Arguments
+    /// * `game_id` - Address of a registered game contract
+    /// * `token` - Token contract address, or `None` to use hub points
+    pub fn set_game_token(env: Env, game_id: Address, token: Option<Address>) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        let mut config = read_game_config(&env, &game_id)?;
+        config.token = token;
+        write_game_config(&env, &game_id, &config);
+        Ok(())
+    }
+
+    /// Set the studio fee taken from the pot of a game's decided sessions.
+    /// Sessions already started keep the fee they were opened with.
+    ///
+    /// # Arguments
+    /// * `game_id` - Address of a registered game contract
+    /// * `fee_bps` - Fee in basis points (at most 10,000)
+    pub fn set_game_fee(env: Env, game_id: Address, fee_bps: u32) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        if fee_bps > MAX_FEE_BPS {
+            return Err(Error::InvalidFee);
+        }
+
+        let mut config = read_game_config(&env, &game_id)?;
+        config.fee_bps = fee_bps;
+        write_game_config(&env, &game_id, &config);
+        Ok(())
+    }
+
+    /// Get the address accrued fees are withdrawn to (the admin until set)
+    pub fn get_treasury(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::Treasury)
+            .unwrap_or_else(|| Self::get_admin(env.clone()))
+    }
+
+    /// Set the address accrued fees are withdrawn to
+    ///
+    /// # Arguments
+    /// * `treasury` - Address receiving withdrawn fees
+    pub fn set_treasury(env: Env, treasury: Address) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        env.storage().instance().set(&DataKey::Treasury, &treasury);
+    }
+
+    /// Get the fees accrued and not yet withdrawn.
+    ///
+    /// # Arguments
+    /// * `token` - Stake token, or `None` for fees taken in hub points
+    pub fn get_accrued_fees(env: Env, token: Option<Address>) -> i128 {
+        read_fees(&env, &token)
+    }
+
+    /// Pay all accrued fees in one stake unit out to the treasury. Point fees
+    /// are credited to the treasury's points balance.
+    ///
+    /// # Arguments
+    /// * `token` - Stake token, or `None` for fees taken in hub points
+    ///
+    /// # Returns
+    /// * `i128` - The amount withdrawn
+    pub fn withdraw_fees(env: Env, token: Option<Address>) -> i128 {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        let amount = read_fees(&env, &token);
+        if amount == 0 {
+            return 0;
+        }
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Fees(token.clone()));
+
+        let treasury = Self::get_treasury(env.clone());
+        match &token {
+            Some(token) => {
+                token::Client::new(&env, token).transfer(
+                    &env.current_contract_address(),
+                    &treasury,
+                    &amount,
+                );
+            }
+            None => credit(&env, &treasury, amount),
+        }
+
+        amount
+    }
+
+    /// Unregister a game contract. Sessions it already opened can still be ended.
+    ///
+    /// # Arguments
+    /// * `game_id` - Address of the game contract
+    pub fn remove_game(env: Env, game_id: Address) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        env.storage().persistent().remove(&DataKey::Game(game_id));
+    }
+
+    /// Credit points to a player's balance
+    ///
+    /// # Arguments
+    /// * `player` - Address of the player receiving points
+    /// * `amount` - Points to credit (must be positive)
+    pub fn add_points(env: Env, player: Address, amount: i128) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        credit(&env, &player, amount);
+
+        Ok(())
+    }
+
+    /// Set the number of ledgers new sessions stay open before they can be
+    /// expired. Sessions already open keep their deadline.
+    ///
+    /// # Arguments
+    /// * `ledgers` - Session timeout in ledgers
+    pub fn set_session_timeout(env: Env, ledgers: u32) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&DataKey::SessionTimeout, &ledgers);
+    }
+
+    /// Get the current admin address
+    ///
+    /// # Returns
+    /// * `Address` - The admin address
+    pub fn get_admin(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set")
+    }
+
+    /// Set a new admin address
+    ///
+    /// # Arguments
+    /// * `new_admin` - The new admin address
+    pub fn set_admin(env: Env, new_admin: Address) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        env.storage().instance().set(&DataKey::Admin, &new_admin);
+    }
+
+    /// Update the contract WASM hash (upgrade contract)
+    ///
+    /// # Arguments
+    /// * `new_wasm_hash` - The hash of the new WASM binary
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        env.deployer().update_current_contract_wasm(new_wasm_hash);
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,885 @@
+#![cfg(test)]
+
+// Unit tests for the Game Hub contract.
+// These tests call the hub directly, playing the role of a game contract.
+
+use crate::{
+    Error, GameEnded, GameHubContract, GameHubContractClient, GameOutcome, SessionEnded,
+    SessionResult, SessionStatus,
+};
+use soroban_sdk::testutils::{
+    Address as _, AuthorizedFunction, Events as _, Ledger as _, MockAuth, MockAuthInvoke,
+};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{vec, Address, BytesN, Env, Event as _, IntoVal};
+
+// ============================================================================
+// Test Helpers
+// ============================================================================
+
+fn setup_test() -> (
+    Env,
+    GameHubContractClient<'static>,
+    Address,
+    Address,
+    Address,
+) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    // Set ledger info for time-based operations
+    env.ledger().set(soroban_sdk::testutils::LedgerInfo {
+        timestamp: 1441065600,
+        protocol_version: 25,
+        sequence_number: 100,
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: u32::MAX / 2,
+        min_persistent_entry_ttl: u32::MAX / 2,
+        max_entry_ttl: u32::MAX / 2,
+    });
+
+    let admin = Address::generate(&env);
+    let contract_id = env.register(GameHubContract, (&admin,));
+    let client = GameHubContractClient::new(&env, &contract_id);
+
+    // Stand-in for a registered game contract address
+    let game_id = Address::generate(&env);
+    client.add_game(&game_id);
+
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+    client.add_points(&player1, &1_000);
+    client.add_points(&player2, &1_000);
+
+    (env, client, game_id, player1, player2)
+}
+
+/// Assert that a Result contains a specific game hub error
+fn assert_hub_error<T, E>(
+    result: &Result<Result<T, E>, Result<Error, soroban_sdk::InvokeError>>,
+    expected_error: Error,
+) {
+    match result {
+        Err(Ok(actual_error)) => {
+            assert_eq!(
+                *actual_error, expected_error,
+                "Expected error {:?} (code {}), but got {:?} (code {})",
+                expected_error, expected_error as u32, actual_error, *actual_error as u32
+            );
+        }
+        Err(Err(_invoke_error)) => {
+            panic!(
+                "Expected contract error {:?} (code {}), but got invocation error",
+                expected_error, expected_error as u32
+            );
+        }
+        Ok(Err(_conv_error)) => {
+            panic!(
+                "Expected contract error {:?} (code {}), but got conversion error",
+                expected_error, expected_error as u32
+            );
+        }
+        Ok(Ok(_)) => {
+            panic!(
+                "Expected error {:?} (code {}), but operation succeeded",
+                expected_error, expected_error as u32
+            );
+        }
+    }
+}
+
+// ============================================================================
+// Escrow and Settlement Tests
+// ============================================================================
+
+#[test]
+fn test_start_game_locks_stakes() {
+    let (env, client, game_id, player1, player2) = setup_test();
+
+    client.start_game(&game_id, &1, &player1, &player2, &300, &200);
+
+    assert_eq!(client.get_balance(&player1), 700);
+    assert_eq!(client.get_balance(&player2), 800);
+
+    let session = client.get_session(&1);
+    assert_eq!(session.game_id, game_id);
+    assert_eq!(session.players, vec![&env, player1, player2]);
+    assert_eq!(session.stakes, vec![&env, 300, 200]);
+    assert_eq!(session.status, SessionStatus::Active);
+}
+
+#[test]
+fn test_winner_takes_pot() {
+    let (env, client, game_id, player1, player2) = setup_test();
+
+    client.start_game(&game_id, &1, &player1, &player2, &300, &200);
+    client.end_game(&1, &GameOutcome::Player2Won);
+
+    assert_eq!(client.get_balance(&player1), 700);
+    assert_eq!(client.get_balance(&player2), 1_300);
+    assert_eq!(
+        client.get_session(&1).status,
+        SessionStatus::Ended(SessionResult::Ranking(vec![&env, 1, 0]))
+    );
+}
+
+#[test]
+fn test_draw_refunds_both_stakes() {
+    let (env, client, game_id, player1, player2) = setup_test();
+
+    client.start_game(&game_id, &1, &player1, &player2, &300, &200);
+    client.end_game(&1, &GameOutcome::Draw);
+
+    assert_eq!(client.get_balance(&player1), 1_000);
+    assert_eq!(client.get_balance(&player2), 1_000);
+    assert_eq!(
+        client.get_session(&1).status,
+        SessionStatus::Ended(SessionResult::Ranking(vec![&env, 0, 0]))
+    );
+}
+
+#[test]
+fn test_cancel_unlocks_both_stakes() {
+    let (_env, client, game_id, player1, player2) = setup_test();
+
+    client.start_game(&game_id, &1, &player1, &player2, &300, &200);
+    client.end_game(&1, &GameOutcome::Cancelled);
+
+    assert_eq!(client.get_balance(&player1), 1_000);
+    assert_eq!(client.get_balance(&player2), 1_000);
+    assert_eq!(
+        client.get_session(&1).status,
+        SessionStatus::Ended(SessionResult::Cancelled)
+    );
+}
+
+#[test]
+fn test_insufficient_balance() {
+    let (_env, client, game_id, player1, player2) = setup_test();
+
+    let result = client.try_start_game(&game_id, &1, &player1, &player2, &1_001, &100);
+    assert_hub_error(&result, Error::InsufficientBalance);
+
+    // Nothing was locked
+    assert_eq!(client.get_balance(&player1), 1_000);
+    assert_eq!(client.get_balance(&player2), 1_000);
+}
+
+#[test]
+fn test_negative_stake_rejected() {
+    let (_env, client, game_id, player1, player2) = setup_test();
+
+    let result = client.try_start_game(&game_id, &1, &player1, &player2, &-1, &100);
+    assert_hub_error(&result, Error::InvalidAmount);
+}
+
+#[test]
+fn test_self_play_rejected() {
+    let (_env, client, game_id, player1, _player2) = setup_test();
+
+    let result = client.try_start_game(&game_id, &1, &player1, &player1, &100, &100);
+    assert_hub_error(&result, Error::SelfPlay);
+}
+
+#[test]
+fn test_duplicate_session_rejected() {
+    let (env, client, game_id, player1, player2) = setup_test();
+
+    client.start_game(&game_id, &1, &player1, &player2, &100, &100);
+
+    // Reusing the ID is rejected even after the session ended, and across games
+    client.end_game(&1, &GameOutcome::Player1Won);
+    let result = client.try_start_game(&game_id, &1, &player1, &player2, &100, &100);
+    assert_hub_error(&result, Error::SessionExists);
+
+    let other_game = Address::generate(&env);
+    client.add_game(&other_game);
+    let result = client.try_start_game(&other_game, &1, &player1, &player2, &100, &100);
+    assert_hub_error(&result, Error::SessionExists);
+}
+
+#[test]
+fn test_cannot_end_twice() {
+    let (_env, client, game_id, player1, player2) = setup_test();
+
+    client.start_game(&game_id, &1, &player1, &player2, &100, &100);
+    client.end_game(&1, &GameOutcome::Player1Won);
+
+    let result = client.try_end_game(&1, &GameOutcome::Player1Won);
+    assert_hub_error(&result, Error::SessionAlreadyEnded);
+    assert_eq!(client.get_balance(&player1), 1_100);
+}
+
+#[test]
+fn test_cannot_end_unknown_session() {
+    let (_env, client, _game_id, _player1, _player2) = setup_test();
+
+    let result = client.try_end_game(&999, &GameOutcome::Player1Won);
+    assert_hub_error(&result, Error::SessionNotFound);
+}
+
+// ============================================================================
+// Multi-Player Session Tests
+// ============================================================================
+
+#[test]
+fn test_start_session_locks_every_stake() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    let player3 = Address::generate(&env);
+    client.add_points(&player3, &1_000);
+
+    let players = vec![&env, player1.clone(), player2.clone(), player3.clone()];
+    client.start_session(&game_id, &1, &players, &vec![&env, 100, 200, 300]);
+
+    assert_eq!(client.get_balance(&player1), 900);
+    assert_eq!(client.get_balance(&player2), 800);
+    assert_eq!(client.get_balance(&player3), 700);
+    assert_eq!(client.get_session(&1).players, players);
+    assert_eq!(client.get_active_sessions(&player3), vec![&env, 1]);
+}
+
+#[test]
+fn test_ranking_winner_takes_pot() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    let player3 = Address::generate(&env);
+    client.add_points(&player3, &1_000);
+
+    let players = vec![&env, player1.clone(), player2.clone(), player3.clone()];
+    client.start_session(&game_id, &1, &players, &vec![&env, 100, 100, 100]);
+    client.end_session(&1, &SessionResult::Ranking(vec![&env, 2, 0, 1]));
+
+    assert_eq!(client.get_balance(&player1), 900);
+    assert_eq!(client.get_balance(&player2), 1_200);
+    assert_eq!(client.get_balance(&player3), 900);
+
+    // Each player is rated against every other player
+    assert!(client.get_rating(&player2, &game_id) > 1200);
+    assert!(client.get_rating(&player1, &game_id) < client.get_rating(&player3, &game_id));
+    assert_eq!(client.get_player_stats(&player2, &game_id).wins, 1);
+    assert_eq!(client.get_player_stats(&player3, &game_id).losses, 1);
+}
+
+#[test]
+fn test_ranking_shared_first_place_splits_pot() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    let player3 = Address::generate(&env);
+    client.add_points(&player3, &1_000);
+
+    let players = vec![&env, player1.clone(), player2.clone(), player3.clone()];
+    client.start_session(&game_id, &1, &players, &vec![&env, 100, 100, 100]);
+    client.end_session(&1, &SessionResult::Ranking(vec![&env, 0, 0, 1]));
+
+    assert_eq!(client.get_balance(&player1), 1_050);
+    assert_eq!(client.get_balance(&player2), 1_050);
+    assert_eq!(client.get_balance(&player3), 900);
+}
+
+#[test]
+fn test_payout_split() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    let player3 = Address::generate(&env);
+    client.add_points(&player3, &1_000);
+    client.set_game_fee(&game_id, &1_000); // 10%
+
+    let players = vec![&env, player1.clone(), player2.clone(), player3.clone()];
+    client.start_session(&game_id, &1, &players, &vec![&env, 100, 100, 100]);
+    client.end_session(&1, &SessionResult::Payout(vec![&env, 5_000, 3_000, 2_000]));
+
+    let event = SessionEnded {
+        session_id: 1,
+        result: SessionResult::Payout(vec![&env, 5_000, 3_000, 2_000]),
+        fee: 30,
+    };
+    assert_eq!(env.events().all(), [event.to_xdr(&env, &client.address)]);
+
+    // 300 pot, 30 fee, 270 split 50/30/20
+    assert_eq!(client.get_balance(&player1), 1_035);
+    assert_eq!(client.get_balance(&player2), 981);
+    assert_eq!(client.get_balance(&player3), 954);
+    assert_eq!(client.get_accrued_fees(&None), 30);
+
+    // Payout sessions are not rated
+    assert_eq!(client.get_rating(&player1, &game_id), 1200);
+}
+
+#[test]
+fn test_invalid_session_results_rejected() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    let player3 = Address::generate(&env);
+
+    let players = vec![&env, player1.clone(), player2.clone(), player3.clone()];
+    client.start_session(&game_id, &1, &players, &vec![&env, 0, 0, 0]);
+
+    let result = client.try_end_session(&1, &SessionResult::Ranking(vec![&env, 0, 1]));
+    assert_hub_error(&result, Error::InvalidResult);
+    let result = client.try_end_session(&1, &SessionResult::Payout(vec![&env, 5_000, 5_000, 1]));
+    assert_hub_error(&result, Error::InvalidResult);
+}
+
+#[test]
+fn test_two_player_outcome_rejected_for_larger_session() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    let player3 = Address::generate(&env);
+    client.add_points(&player3, &1_000);
+
+    let players = vec![&env, player1.clone(), player2.clone(), player3.clone()];
+    client.start_session(&game_id, &1, &players, &vec![&env, 100, 100, 100]);
+
+    let result = client.try_end_game(&1, &GameOutcome::Player1Won);
+    assert_hub_error(&result, Error::InvalidResult);
+    let result = client.try_end_game(&1, &GameOutcome::Draw);
+    assert_hub_error(&result, Error::InvalidResult);
+
+    // Cancelling still works for any number of players
+    client.end_game(&1, &GameOutcome::Cancelled);
+    assert_eq!(client.get_balance(&player3), 1000);
+}
+
+#[test]
+fn test_invalid_session_players_rejected() {
+    let (env, client, game_id, player1, player2) = setup_test();
+
+    let result =
+        client.try_start_session(&game_id, &1, &vec![&env, player1.clone()], &vec![&env, 0]);
+    assert_hub_error(&result, Error::InvalidPlayers);
+
+    let players = vec![&env, player1.clone(), player2.clone()];
+    let result = client.try_start_session(&game_id, &1, &players, &vec![&env, 0]);
+    assert_hub_error(&result, Error::InvalidPlayers);
+
+    let players = vec![&env, player1.clone(), player2.clone(), player1.clone()];
+    let result = client.try_start_session(&game_id, &1, &players, &vec![&env, 0, 0, 0]);
+    assert_hub_error(&result, Error::SelfPlay);
+}
+
+// ============================================================================
+// Session Timeout Tests
+// ============================================================================
+
+#[test]
+fn test_session_records_deadline() {
+    let (_env, client, game_id, player1, player2) = setup_test();
+
+    client.set_session_timeout(&1_000);
+    client.start_game(&game_id, &1, &player1, &player2, &100, &100);
+
+    // Started at ledger 100 (see setup_test)
+    assert_eq!(client.get_session(&1).deadline_ledger, 1_100);
+}
+
+#[test]
+fn test_expire_session_refunds_after_deadline() {
+    let (env, client, game_id, player1, player2) = setup_test();
+
+    client.set_session_timeout(&1_000);
+    client.start_game(&game_id, &1, &player1, &player2, &300, &200);
+
+    // Not expirable up to and including the deadline ledger
+    env.ledger().set_sequence_number(1_100);
+    let result = client.try_expire_session(&1);
+    assert_hub_error(&result, Error::SessionNotExpired);
+
+    env.ledger().set_sequence_number(1_101);
+    client.expire_session(&1);
+
+    assert_eq!(client.get_balance(&player1), 1_000);
+    assert_eq!(client.get_balance(&player2), 1_000);
+    assert_eq!(
+        client.get_session_status(&1),
+        SessionStatus::Ended(SessionResult::Cancelled)
+    );
+
+    // The game can no longer settle an expired session
+    let result = client.try_end_game(&1, &GameOutcome::Player1Won);
+    assert_hub_error(&result, Error::SessionAlreadyEnded);
+}
+
+#[test]
+fn test_cannot_expire_ended_session() {
+    let (env, client, game_id, player1, player2) = setup_test();
+
+    client.start_game(&game_id, &1, &player1, &player2, &100, &100);
+    client.end_game(&1, &GameOutcome::Player1Won);
+
+    env.ledger().set_sequence_number(u32::MAX / 4);
+    let result = client.try_expire_session(&1);
+    assert_hub_error(&result, Error::SessionAlreadyEnded);
+    assert_eq!(client.get_balance(&player1), 1_100);
+}
+
+#[test]
+fn test_expire_session_is_permissionless() {
+    let (env, client, game_id, player1, player2) = setup_test();
+
+    client.set_session_timeout(&10);
+    client.start_game(&game_id, &1, &player1, &player2, &100, &100);
+    env.ledger().set_sequence_number(200);
+
+    client.expire_session(&1);
+    assert!(env.auths().is_empty());
+}
+
+// ============================================================================
+// Rating Tests
+// ============================================================================
+
+#[test]
+fn test_win_updates_ratings() {
+    let (_env, client, game_id, player1, player2) = setup_test();
+
+    assert_eq!(client.get_rating(&player1, &game_id), 1200);
+
+    client.start_game(&game_id, &1, &player1, &player2, &100, &100);
+    client.end_game(&1, &GameOutcome::Player1Won);
+
+    // Equal ratings: expected score 0.5, K = 32
+    assert_eq!(client.get_rating(&player1, &game_id), 1216);
+    assert_eq!(client.get_rating(&player2, &game_id), 1184);
+}
+
+#[test]
+fn test_draw_moves_ratings_together() {
+    let (_env, client, game_id, player1, player2) = setup_test();
+
+    client.start_game(&game_id, &1, &player1, &player2, &0, &0);
+    client.end_game(&1, &GameOutcome::Draw);
+
+    // A draw between equals changes nothing
+    assert_eq!(client.get_rating(&player1, &game_id), 1200);
+    assert_eq!(client.get_rating(&player2, &game_id), 1200);
+
+    client.start_game(&game_id, &2, &player1, &player2, &0, &0);
+    client.end_game(&2, &GameOutcome::Player1Won);
+    client.start_game(&game_id, &3, &player1, &player2, &0, &0);
+    client.end_game(&3, &GameOutcome::Draw);
+
+    // After 1216 vs 1184, a draw favours the lower-rated player
+    let rating1 = client.get_rating(&player1, &game_id);
+    let rating2 = client.get_rating(&player2, &game_id);
+    assert!(rating1 < 1216);
+    assert!(rating2 > 1184);
+    assert_eq!(rating1 + rating2, 2400);
+}
+
+#[test]
+fn test_cancelled_session_not_rated() {
+    let (env, client, game_id, player1, player2) = setup_test();
+
+    client.set_session_timeout(&10);
+    client.start_game(&game_id, &1, &player1, &player2, &100, &100);
+    client.start_game(&game_id, &2, &player1, &player2, &100, &100);
+    client.end_game(&1, &GameOutcome::Cancelled);
+    env.ledger().set_sequence_number(200);
+    client.expire_session(&2);
+
+    assert_eq!(client.get_rating(&player1, &game_id), 1200);
+    assert_eq!(client.get_rating(&player2, &game_id), 1200);
+    assert!(client.get_top_players(&game_id, &10).is_empty());
+}
+
+#[test]
+fn test_ratings_are_per_game() {
+    let (env, client, game_id, player1, player2) = setup_test();
+
+    let other_game = Address::generate(&env);
+    client.add_game(&other_game);
+
+    client.start_game(&game_id, &1, &player1, &player2, &0, &0);
+    client.end_game(&1, &GameOutcome::Player1Won);
+    client.start_game(&other_game, &2, &player1, &player2, &0, &0);
+    client.end_game(&2, &GameOutcome::Player2Won);
+
+    assert_eq!(client.get_rating(&player1, &game_id), 1216);
+    assert_eq!(client.get_rating(&player1, &other_game), 1184);
+}
+
+#[test]
+fn test_top_players_sorted() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    let player3 = Address::generate(&env);
+
+    client.start_game(&game_id, &1, &player1, &player2, &0, &0);
+    client.end_game(&1, &GameOutcome::Player1Won);
+    client.start_game(&game_id, &2, &player3, &player2, &0, &0);
+    client.end_game(&2, &GameOutcome::Player1Won);
+    client.start_game(&game_id, &3, &player3, &player1, &0, &0);
+    client.end_game(&3, &GameOutcome::Player1Won);
+
+    let top = client.get_top_players(&game_id, &10);
+    assert_eq!(top.len(), 3);
+    assert_eq!(top.get(0).unwrap().player, player3);
+    assert_eq!(top.get(1).unwrap().player, player1);
+    assert_eq!(top.get(2).unwrap().player, player2);
+    for entry in top.iter() {
+        assert_eq!(entry.rating, client.get_rating(&entry.player, &game_id));
+    }
+
+    let top1 = client.get_top_players(&game_id, &1);
+    assert_eq!(top1.len(), 1);
+    assert_eq!(top1.get(0).unwrap().player, player3);
+}
+
+// ============================================================================
+// Player Index Tests
+// ============================================================================
+
+#[test]
+fn test_active_sessions_tracked() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    let player3 = Address::generate(&env);
+
+    client.start_game(&game_id, &1, &player1, &player2, &0, &0);
+    client.start_game(&game_id, &2, &player1, &player3, &0, &0);
+
+    assert_eq!(client.get_active_sessions(&player1), vec![&env, 1, 2]);
+    assert_eq!(client.get_active_sessions(&player2), vec![&env, 1]);
+    assert_eq!(client.get_active_sessions(&player3), vec![&env, 2]);
+
+    client.end_game(&1, &GameOutcome::Player1Won);
+
+    assert_eq!(client.get_active_sessions(&player1), vec![&env, 2]);
+    assert!(client.get_active_sessions(&player2).is_empty());
+}
+
+#[test]
+fn test_session_history_paginated() {
+    let (env, client, game_id, player1, player2) = setup_test();
+
+    for session_id in 1..=5u32 {
+        client.start_game(&game_id, &session_id, &player1, &player2, &0, &0);
+        client.end_game(&session_id, &GameOutcome::Draw);
+    }
+
+    // Most recent first
+    assert_eq!(
+        client.get_session_history(&player1, &0, &2),
+        vec![&env, 5, 4]
+    );
+    assert_eq!(
+        client.get_session_history(&player1, &2, &2),
+        vec![&env, 3, 2]
+    );
+    assert_eq!(client.get_session_history(&player1, &4, &2), vec![&env, 1]);
+    assert!(client.get_session_history(&player1, &5, &2).is_empty());
+    assert_eq!(client.get_session_history(&player2, &0, &10).len(), 5);
+}
+
+#[test]
+fn test_player_stats_per_game() {
+    let (env, client, game_id, player1, player2) = setup_test();
+
+    let other_game = Address::generate(&env);
+    client.add_game(&other_game);
+
+    client.start_game(&game_id, &1, &player1, &player2, &0, &0);
+    client.end_game(&1, &GameOutcome::Player1Won);
+    client.start_game(&game_id, &2, &player1, &player2, &0, &0);
+    client.end_game(&2, &GameOutcome::Draw);
+    client.start_game(&game_id, &3, &player1, &player2, &0, &0);
+    client.end_game(&3, &GameOutcome::Cancelled);
+    client.start_game(&other_game, &4, &player1, &player2, &0, &0);
+    client.end_game(&4, &GameOutcome::Player2Won);
+
+    let stats1 = client.get_player_stats(&player1, &game_id);
+    assert_eq!((stats1.wins, stats1.losses, stats1.draws), (1, 0, 1));
+    let stats2 = client.get_player_stats(&player2, &game_id);
+    assert_eq!((stats2.wins, stats2.losses, stats2.draws), (0, 1, 1));
+
+    let other1 = client.get_player_stats(&player1, &other_game);
+    assert_eq!((other1.wins, other1.losses, other1.draws), (0, 1, 0));
+
+    // Cancelled sessions are still part of the history
+    assert_eq!(client.get_session_history(&player1, &0, &10).len(), 4);
+}
+
+// ============================================================================
+// Token Stake Tests
+// ============================================================================
+
+/// Configure `game_id` to stake a fresh test token and mint 1_000 to each player
+fn setup_token(
+    env: &Env,
+    client: &GameHubContractClient,
+    game_id: &Address,
+    player1: &Address,
+    player2: &Address,
+) -> TokenClient<'static> {
+    // The player's transfer authorization is a sub-invocation of start_game
+    env.mock_all_auths_allowing_non_root_auth();
+
+    let issuer = Address::generate(env);
+    let token_id = env.register_stellar_asset_contract_v2(issuer).address();
+    let asset = StellarAssetClient::new(env, &token_id);
+    asset.mint(player1, &1_000);
+    asset.mint(player2, &1_000);
+
+    client.set_game_token(game_id, &Some(token_id.clone()));
+    TokenClient::new(env, &token_id)
+}
+
+#[test]
+fn test_token_stakes_escrowed_and_paid_out() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    let token = setup_token(&env, &client, &game_id, &player1, &player2);
+
+    client.start_game(&game_id, &1, &player1, &player2, &100, &50);
+
+    assert_eq!(token.balance(&player1), 900);
+    assert_eq!(token.balance(&player2), 950);
+    assert_eq!(token.balance(&client.address), 150);
+    // Hub points are untouched
+    assert_eq!(client.get_balance(&player1), 1_000);
+
+    client.end_game(&1, &GameOutcome::Player2Won);
+
+    assert_eq!(token.balance(&player1), 900);
+    assert_eq!(token.balance(&player2), 1_100);
+    assert_eq!(token.balance(&client.address), 0);
+}
+
+#[test]
+fn test_token_stakes_refunded_on_draw() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    let token = setup_token(&env, &client, &game_id, &player1, &player2);
+
+    client.start_game(&game_id, &1, &player1, &player2, &100, &50);
+    client.end_game(&1, &GameOutcome::Draw);
+
+    assert_eq!(token.balance(&player1), 1_000);
+    assert_eq!(token.balance(&player2), 1_000);
+    assert_eq!(token.balance(&client.address), 0);
+}
+
+#[test]
+fn test_session_keeps_token_it_started_with() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    let token = setup_token(&env, &client, &game_id, &player1, &player2);
+
+    client.start_game(&game_id, &1, &player1, &player2, &100, &100);
+
+    // Switching the game back to points does not strand the escrowed tokens
+    client.set_game_token(&game_id, &None);
+    assert_eq!(client.get_game_config(&game_id).token, None);
+
+    client.end_game(&1, &GameOutcome::Player1Won);
+
+    assert_eq!(token.balance(&player1), 1_100);
+    assert_eq!(client.get_balance(&player1), 1_000);
+}
+
+#[test]
+fn test_set_game_token_requires_registered_game() {
+    let (env, client, _game_id, _player1, _player2) = setup_test();
+
+    let unknown = Address::generate(&env);
+    let token = Address::generate(&env);
+    let result = client.try_set_game_token(&unknown, &Some(token));
+    assert_hub_error(&result, Error::GameNotRegistered);
+}
+
+#[test]
+fn test_add_game_again_keeps_config() {
+    let (env, client, game_id, _player1, _player2) = setup_test();
+    let token = Address::generate(&env);
+    client.set_game_token(&game_id, &Some(token.clone()));
+    client.set_game_fee(&game_id, &500);
+
+    client.add_game(&game_id);
+
+    let config = client.get_game_config(&game_id);
+    assert_eq!(config.token, Some(token));
+    assert_eq!(config.fee_bps, 500);
+}
+
+// ============================================================================
+// Fee Tests
+// ============================================================================
+
+#[test]
+fn test_fee_taken_from_pot() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    let treasury = Address::generate(&env);
+    client.set_treasury(&treasury);
+    client.set_game_fee(&game_id, &500); // 5%
+
+    client.start_game(&game_id, &1, &player1, &player2, &100, &100);
+    client.end_game(&1, &GameOutcome::Player1Won);
+
+    assert_eq!(client.get_balance(&player1), 1_090);
+    assert_eq!(client.get_balance(&player2), 900);
+    assert_eq!(client.get_accrued_fees(&None), 10);
+
+    assert_eq!(client.withdraw_fees(&None), 10);
+    assert_eq!(client.get_balance(&treasury), 10);
+    assert_eq!(client.get_accrued_fees(&None), 0);
+}
+
+#[test]
+fn test_no_fee_on_draw() {
+    let (_env, client, game_id, player1, player2) = setup_test();
+    client.set_game_fee(&game_id, &500);
+
+    client.start_game(&game_id, &1, &player1, &player2, &100, &100);
+    client.end_game(&1, &GameOutcome::Draw);
+
+    assert_eq!(client.get_balance(&player1), 1_000);
+    assert_eq!(client.get_balance(&player2), 1_000);
+    assert_eq!(client.get_accrued_fees(&None), 0);
+}
+
+#[test]
+fn test_fee_change_applies_to_new_sessions_only() {
+    let (_env, client, game_id, player1, player2) = setup_test();
+
+    client.start_game(&game_id, &1, &player1, &player2, &100, &100);
+    client.set_game_fee(&game_id, &1_000);
+    client.start_game(&game_id, &2, &player1, &player2, &100, &100);
+
+    assert_eq!(client.get_session(&1).fee_bps, 0);
+    assert_eq!(client.get_session(&2).fee_bps, 1_000);
+
+    client.end_game(&1, &GameOutcome::Player1Won);
+    assert_eq!(client.get_accrued_fees(&None), 0);
+    client.end_game(&2, &GameOutcome::Player1Won);
+    assert_eq!(client.get_accrued_fees(&None), 20);
+}
+
+#[test]
+fn test_token_fees_withdrawn_to_treasury() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    let token = setup_token(&env, &client, &game_id, &player1, &player2);
+    let treasury = Address::generate(&env);
+    client.set_treasury(&treasury);
+    client.set_game_fee(&game_id, &250); // 2.5%
+
+    client.start_game(&game_id, &1, &player1, &player2, &200, &200);
+    client.end_game(&1, &GameOutcome::Player2Won);
+
+    assert_eq!(token.balance(&player2), 1_190);
+    assert_eq!(client.get_accrued_fees(&Some(token.address.clone())), 10);
+    // Point fees are tracked separately
+    assert_eq!(client.get_accrued_fees(&None), 0);
+
+    client.withdraw_fees(&Some(token.address.clone()));
+    assert_eq!(token.balance(&treasury), 10);
+    assert_eq!(token.balance(&client.address), 0);
+}
+
+#[test]
+fn test_fee_included_in_game_ended_event() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    client.set_game_fee(&game_id, &500);
+
+    client.start_game(&game_id, &1, &player1, &player2, &100, &100);
+    client.end_game(&1, &GameOutcome::Player1Won);
+
+    let event = GameEnded {
+        session_id: 1,
+        outcome: GameOutcome::Player1Won,
+        fee: 10,
+    };
+    assert_eq!(env.events().all(), [event.to_xdr(&env, &client.address)]);
+}
+
+#[test]
+fn test_set_game_fee_rejects_over_100_percent() {
+    let (_env, client, game_id, _player1, _player2) = setup_test();
+
+    let result = client.try_set_game_fee(&game_id, &10_001);
+    assert_hub_error(&result, Error::InvalidFee);
+}
+
+// ============================================================================
+// Game Registry Tests
+// ============================================================================
+
+#[test]
+fn test_unregistered_game_cannot_start() {
+    let (env, client, _game_id, player1, player2) = setup_test();
+
+    let rogue_game = Address::generate(&env);
+    let result = client.try_start_game(&rogue_game, &1, &player1, &player2, &100, &100);
+    assert_hub_error(&result, Error::GameNotRegistered);
+}
+
+#[test]
+fn test_removed_game_cannot_start_but_can_end() {
+    let (_env, client, game_id, player1, player2) = setup_test();
+
+    client.start_game(&game_id, &1, &player1, &player2, &100, &100);
+    client.remove_game(&game_id);
+    assert!(!client.is_game(&game_id));
+
+    let result = client.try_start_game(&game_id, &2, &player1, &player2, &100, &100);
+    assert_hub_error(&result, Error::GameNotRegistered);
+
+    // The session opened before removal can still be settled
+    client.end_game(&1, &GameOutcome::Player1Won);
+    assert_eq!(client.get_balance(&player1), 1_100);
+}
+
+#[test]
+fn test_start_and_end_require_game_auth() {
+    let (env, client, game_id, player1, player2) = setup_test();
+
+    client.start_game(&game_id, &1, &player1, &player2, &100, &100);
+    let auths = env.auths();
+    assert_eq!(auths.len(), 1);
+    assert_eq!(auths[0].0, game_id);
+    assert!(matches!(
+        auths[0].1.function,
+        AuthorizedFunction::Contract(_)
+    ));
+
+    // end_game must be authorized by the game that opened the session
+    client.end_game(&1, &GameOutcome::Draw);
+    let auths = env.auths();
+    assert_eq!(auths.len(), 1);
+    assert_eq!(auths[0].0, game_id);
+}
+
+#[test]
+fn test_other_game_cannot_end_session() {
+    let (env, client, game_id, player1, player2) = setup_test();
+
+    client.start_game(&game_id, &1, &player1, &player2, &100, &100);
+
+    // Only a different (registered) game authorizes the call
+    let other_game = Address::generate(&env);
+    client.add_game(&other_game);
+    env.mock_auths(&[MockAuth {
+        address: &other_game,
+        invoke: &MockAuthInvoke {
+            contract: &client.address,
+            fn_name: "end_game",
+            args: (1u32, GameOutcome::Player1Won).into_val(&env),
+            sub_invokes: &[],
+        },
+    }]);
+
+    let result = client.try_end_game(&1, &GameOutcome::Player1Won);
+    assert!(result.is_err());
+}
+
+// ============================================================================
+// Admin Function Tests
+// ============================================================================
+
+#[test]
+fn test_add_points_requires_positive_amount() {
+    let (_env, client, _game_id, player1, _player2) = setup_test();
+
+    let result = client.try_add_points(&player1, &0);
+    assert_hub_error(&result, Error::InvalidAmount);
+}
+
+#[test]
+fn test_upgrade_function_exists() {
+    let (env, client, _game_id, _player1, _player2) = setup_test();
+
+    // Should fail (WASM doesn't exist) but confirms function signature is correct
+    let new_wasm_hash = BytesN::from_array(&env, &[0u8; 32]);
+    let result = client.try_upgrade(&new_wasm_hash);
+    assert!(result.is_err());
+}
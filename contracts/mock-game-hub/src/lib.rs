@@ -1,15 +1,49 @@
 #![no_std]
 
-use soroban_sdk::{contract, contractevent, contractimpl, Address, Env};
+use soroban_sdk::{contract, contractevent, contractimpl, contracttype, vec, Address, Env, Vec};
 
 /// Mock Game Hub contract for game studio development
 ///
 /// This contract provides the same external interface that games expect
-/// (start_game, end_game) but does nothing internally. It exists purely
-/// for game contracts to compile and integrate during development.
+/// (start_game, end_game, get_session_status). It only records each
+/// session's status, and exists purely for game contracts to compile and
+/// integrate during development.
 #[contract]
 pub struct MockGameHub;
 
+/// Result of a session, reported by the game contract in `end_game`
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum GameOutcome {
+    Player1Won,
+    Player2Won,
+    Draw,
+    Cancelled,
+}
+
+/// How a session was settled, mirroring the Game Hub's `SessionResult`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SessionResult {
+    Ranking(Vec<u32>),
+    Payout(Vec<u32>),
+    Cancelled,
+}
+
+/// Lifecycle of a session, mirroring the Game Hub's `SessionStatus`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SessionStatus {
+    Active,
+    Ended(SessionResult),
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    Session(u32),
+}
+
 #[contractevent]
 pub struct GameStarted {
     pub session_id: u32,
@@ -23,7 +57,7 @@ pub struct GameStarted {
 #[contractevent]
 pub struct GameEnded {
     pub session_id: u32,
-    pub player1_won: bool,
+    pub outcome: GameOutcome,
 }
 
 #[contractimpl]
@@ -47,6 +81,9 @@ impl MockGameHub {
         player2_points: i128,
     ) {
         // No auth required for mock
+        env.storage()
+            .temporary()
+            .set(&DataKey::Session(session_id), &SessionStatus::Active);
         GameStarted {
             session_id,
             game_id,
@@ -60,23 +97,51 @@ impl MockGameHub {
         env.storage().instance().extend_ttl(17_280, 518_400);
     }
 
-    /// End a game session and declare winner
+    /// Register a game contract (no-op in mock: any game may start sessions)
+    ///
+    /// # Arguments
+    /// * `game_id` - Address of the game contract
+    pub fn add_game(_env: Env, _game_id: Address) {
+        // No registry in mock
+    }
+
+    /// End a game session and report its outcome
     ///
     /// # Arguments
     /// * `session_id` - The game session being ended
-    /// * `player1_won` - True if player1 won, false if player2 won
+    /// * `outcome` - Player1Won, Player2Won, Draw or Cancelled
     pub fn end_game(
         env: Env,
         session_id: u32,
-        player1_won: bool,
+        outcome: GameOutcome,
     ) {
         // No auth required for mock
+        let result = match outcome {
+            GameOutcome::Player1Won => SessionResult::Ranking(vec![&env, 0, 1]),
+            GameOutcome::Player2Won => SessionResult::Ranking(vec![&env, 1, 0]),
+            GameOutcome::Draw => SessionResult::Ranking(vec![&env, 0, 0]),
+            GameOutcome::Cancelled => SessionResult::Cancelled,
+        };
+        env.storage()
+            .temporary()
+            .set(&DataKey::Session(session_id), &SessionStatus::Ended(result));
         GameEnded {
             session_id,
-            player1_won,
+            outcome,
         }
         .publish(&env);
     }
+
+    /// Get a session's status
+    ///
+    /// # Arguments
+    /// * `session_id` - The game session
+    pub fn get_session_status(env: Env, session_id: u32) -> SessionStatus {
+        env.storage()
+            .temporary()
+            .get(&DataKey::Session(session_id))
+            .expect("Session not found")
+    }
 }
 
 #[cfg(test)]
@@ -86,12 +151,17 @@ mod test {
     #[test]
     fn test_start_and_end_game() {
         let env = Env::default();
-        let contract_id = env.register_contract(None, MockGameHub);
+        let contract_id = env.register(MockGameHub, ());
         let client = MockGameHubClient::new(&env, &contract_id);
         let game_id = Address::generate(&env);
         let player1 = Address::generate(&env);
         let player2 = Address::generate(&env);
         client.start_game(&game_id, &1, &player1, &player2, &1000, &1000);
-        client.end_game(&1, &true);
+        assert_eq!(client.get_session_status(&1), SessionStatus::Active);
+        client.end_game(&1, &GameOutcome::Player1Won);
+        assert_eq!(
+            client.get_session_status(&1),
+            SessionStatus::Ended(SessionResult::Ranking(vec![&env, 0, 1]))
+        );
     }
 }
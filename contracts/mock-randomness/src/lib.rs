@@ -0,0 +1,152 @@
+#![no_std]
+
+use soroban_sdk::{contract, contractevent, contractimpl, contracttype, Address, BytesN, Env};
+
+/// Mock randomness provider for game studio development
+///
+/// This contract provides the same external interface as a real randomness
+/// oracle (request, fulfill, get_randomness), but `fulfill` accepts whatever
+/// value it is given. Tests and local deployments play the oracle by
+/// fulfilling requests themselves, which makes games' random draws fully
+/// controllable.
+#[contract]
+pub struct MockRandomness;
+
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    Pending(Address, u64),
+    Randomness(Address, u64),
+}
+
+#[contractevent]
+pub struct RandomnessRequested {
+    pub requester: Address,
+    pub request_id: u64,
+}
+
+#[contractevent]
+pub struct RandomnessFulfilled {
+    pub requester: Address,
+    pub request_id: u64,
+    pub randomness: BytesN<32>,
+}
+
+/// TTL for requests (30 days in ledgers, matching game storage)
+const REQUEST_TTL_LEDGERS: u32 = 518_400;
+
+#[contractimpl]
+impl MockRandomness {
+    /// Request randomness
+    ///
+    /// # Arguments
+    /// * `requester` - Contract asking for randomness (must authorize)
+    /// * `request_id` - Identifier of the request, unique per requester
+    pub fn request(env: Env, requester: Address, request_id: u64) {
+        requester.require_auth();
+
+        let key = DataKey::Pending(requester.clone(), request_id);
+        if env.storage().temporary().has(&key)
+            || env
+                .storage()
+                .temporary()
+                .has(&DataKey::Randomness(requester.clone(), request_id))
+        {
+            panic!("Request already exists");
+        }
+        env.storage().temporary().set(&key, &true);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, REQUEST_TTL_LEDGERS, REQUEST_TTL_LEDGERS);
+
+        RandomnessRequested {
+            requester,
+            request_id,
+        }
+        .publish(&env);
+    }
+
+    /// Deliver randomness for a pending request (no auth or proof in mock)
+    ///
+    /// # Arguments
+    /// * `requester` - Contract that made the request
+    /// * `request_id` - The request being fulfilled
+    /// * `randomness` - The random value to deliver
+    pub fn fulfill(env: Env, requester: Address, request_id: u64, randomness: BytesN<32>) {
+        let pending = DataKey::Pending(requester.clone(), request_id);
+        if !env.storage().temporary().has(&pending) {
+            panic!("Request not pending");
+        }
+        env.storage().temporary().remove(&pending);
+
+        let key = DataKey::Randomness(requester.clone(), request_id);
+        env.storage().temporary().set(&key, &randomness);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, REQUEST_TTL_LEDGERS, REQUEST_TTL_LEDGERS);
+
+        RandomnessFulfilled {
+            requester,
+            request_id,
+            randomness,
+        }
+        .publish(&env);
+    }
+
+    /// Get the randomness delivered for a request, if it has been fulfilled
+    ///
+    /// # Arguments
+    /// * `requester` - Contract that made the request
+    /// * `request_id` - The request
+    pub fn get_randomness(env: Env, requester: Address, request_id: u64) -> Option<BytesN<32>> {
+        env.storage()
+            .temporary()
+            .get(&DataKey::Randomness(requester, request_id))
+    }
+
+    /// Whether a request is waiting to be fulfilled
+    ///
+    /// # Arguments
+    /// * `requester` - Contract that made the request
+    /// * `request_id` - The request
+    pub fn is_pending(env: Env, requester: Address, request_id: u64) -> bool {
+        env.storage()
+            .temporary()
+            .has(&DataKey::Pending(requester, request_id))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::testutils::Address as _;
+
+    #[test]
+    fn test_request_and_fulfill() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(MockRandomness, ());
+        let client = MockRandomnessClient::new(&env, &contract_id);
+        let requester = Address::generate(&env);
+        let randomness = BytesN::from_array(&env, &[7u8; 32]);
+
+        client.request(&requester, &1);
+        assert!(client.is_pending(&requester, &1));
+        assert_eq!(client.get_randomness(&requester, &1), None);
+
+        client.fulfill(&requester, &1, &randomness);
+        assert!(!client.is_pending(&requester, &1));
+        assert_eq!(client.get_randomness(&requester, &1), Some(randomness));
+    }
+
+    #[test]
+    #[should_panic(expected = "Request not pending")]
+    fn test_cannot_fulfill_unrequested() {
+        let env = Env::default();
+        let contract_id = env.register(MockRandomness, ());
+        let client = MockRandomnessClient::new(&env, &contract_id);
+        let requester = Address::generate(&env);
+
+        client.fulfill(&requester, &1, &BytesN::from_array(&env, &[7u8; 32]));
+    }
+}
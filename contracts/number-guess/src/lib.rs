@@ -5,35 +5,30 @@
 //! A simple two-player guessing game where players guess a number between 1 and 10.
 //! The player whose guess is closest to the randomly generated number wins.
 //!
+//! Guesses are played in two phases so the second player cannot read the first
+//! guess and pick a winning one: each player first commits to
+//! `keccak256(guess || salt)`, and only once both have committed are the
+//! guesses revealed and checked against their commitments. Both phases run
+//! through a `game_common::beacon::Beacon`, whose combined seed draws the
+//! winning number.
+//!
+//! Committing and revealing each have an action deadline (the admin-configured
+//! action timeout). Once it passes, a player who acted can `claim_timeout`
+//! against an opponent who didn't.
+//!
+//! Either player can also `resign`, or end the game as a draw with
+//! `offer_draw` and the opponent's `accept_draw`.
+//!
 //! **Game Hub Integration:**
 //! This game is Game Hub-aware and enforces all games to be played through the
 //! Game Hub contract. Games cannot be started or completed without points involvement.
 
+use game_common::beacon::{Beacon, BeaconError, SeedRng};
+pub use game_common::GameOutcome;
 use soroban_sdk::{
-    Address, Bytes, BytesN, Env, IntoVal, contract, contractclient, contracterror, contractimpl, contracttype, vec
+    Address, Bytes, BytesN, Env, IntoVal, contract, contracterror, contractimpl, contracttype, vec
 };
 
-// Import GameHub contract interface
-// This allows us to call into the GameHub contract
-#[contractclient(name = "GameHubClient")]
-pub trait GameHub {
-    fn start_game(
-        env: Env,
-        game_id: Address,
-        session_id: u32,
-        player1: Address,
-        player2: Address,
-        player1_points: i128,
-        player2_points: i128,
-    );
-
-    fn end_game(
-        env: Env,
-        session_id: u32,
-        player1_won: bool
-    );
-}
-
 // ============================================================================
 // Errors
 // ============================================================================
@@ -47,6 +42,31 @@ pub enum Error {
     AlreadyGuessed = 3,
     BothPlayersNotGuessed = 4,
     GameAlreadyEnded = 5,
+    SessionExists = 6,
+    AlreadyRevealed = 7,
+    CommitmentMismatch = 8,
+    BothPlayersNotRevealed = 9,
+    CommitDeadlinePassed = 10,
+    RevealDeadlinePassed = 11,
+    DeadlineNotReached = 12,
+    OpponentNotStalled = 13,
+    PlayerStalled = 14,
+    NoDrawOffer = 15,
+}
+
+impl From<BeaconError> for Error {
+    fn from(error: BeaconError) -> Self {
+        match error {
+            BeaconError::NotParty => Error::NotPlayer,
+            BeaconError::AlreadyCommitted => Error::AlreadyGuessed,
+            BeaconError::NotAllCommitted => Error::BothPlayersNotGuessed,
+            BeaconError::AlreadyRevealed => Error::AlreadyRevealed,
+            BeaconError::CommitmentMismatch => Error::CommitmentMismatch,
+            BeaconError::NotAllRevealed => Error::BothPlayersNotRevealed,
+            BeaconError::CommitDeadlinePassed => Error::CommitDeadlinePassed,
+            BeaconError::RevealDeadlinePassed => Error::RevealDeadlinePassed,
+        }
+    }
 }
 
 // ============================================================================
@@ -60,29 +80,37 @@ pub struct Game {
     pub player2: Address,
     pub player1_points: i128,
     pub player2_points: i128,
+    /// Commitments to `guess || salt` and their reveals
+    pub beacon: Beacon,
+    /// Revealed guesses, set once they match their commitment
     pub player1_guess: Option<u32>,
     pub player2_guess: Option<u32>,
     pub winning_number: Option<u32>,
     pub winner: Option<Address>,
+    /// Player whose draw offer is waiting for the opponent to accept
+    pub draw_offer: Option<Address>,
+    /// Set when the players agreed to a draw, ending the game without a winner
+    pub drawn: bool,
+    /// Last ledger of the current commit or reveal phase
+    pub action_deadline: u32,
 }
 
 #[contracttype]
 #[derive(Clone)]
 pub enum DataKey {
     Game(u32),
-    GameHubAddress,
-    Admin,
 }
 
 // ============================================================================
-// Storage TTL Management
+// Helper Functions
 // ============================================================================
-// TTL (Time To Live) ensures game data doesn't expire unexpectedly
-// Games are stored in temporary storage with a minimum 30-day retention
 
-/// TTL for game storage (30 days in ledgers, ~5 seconds per ledger)
-/// 30 days = 30 * 24 * 60 * 60 / 5 = 518,400 ledgers
-const GAME_TTL_LEDGERS: u32 = 518_400;
+/// The value a guess commitment opens to: `guess as 4 big-endian bytes || salt`
+fn guess_reveal(env: &Env, guess: u32, salt: &BytesN<32>) -> Bytes {
+    let mut data = Bytes::from_array(env, &guess.to_be_bytes());
+    data.append(&Bytes::from(salt.clone()));
+    data
+}
 
 // ============================================================================
 // Contract Definition
@@ -100,10 +128,7 @@ impl NumberGuessContract {
     /// * `game_hub` - Address of the GameHub contract
     pub fn __constructor(env: Env, admin: Address, game_hub: Address) {
         // Store admin and GameHub address
-        env.storage().instance().set(&DataKey::Admin, &admin);
-        env.storage()
-            .instance()
-            .set(&DataKey::GameHubAddress, &game_hub);
+        game_common::init(&env, &admin, &game_hub);
     }
 
     /// Start a new game between two players with points.
@@ -131,19 +156,17 @@ impl NumberGuessContract {
             panic!("Cannot play against yourself: Player 1 and Player 2 must be different addresses");
         }
 
+        // Refuse to overwrite a live game stored under the same session ID
+        if env.storage().temporary().has(&DataKey::Game(session_id)) {
+            return Err(Error::SessionExists);
+        }
+
         // Require authentication from both players (they consent to committing points)
         player1.require_auth_for_args(vec![&env, session_id.into_val(&env), player1_points.into_val(&env)]);
         player2.require_auth_for_args(vec![&env, session_id.into_val(&env), player2_points.into_val(&env)]);
 
-        // Get GameHub address
-        let game_hub_addr: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::GameHubAddress)
-            .expect("GameHub address not set");
-
         // Create GameHub client
-        let game_hub = GameHubClient::new(&env, &game_hub_addr);
+        let game_hub = game_common::hub_client(&env);
 
         // Call Game Hub to start the session and lock points
         // This requires THIS contract's authorization (env.current_contract_address())
@@ -157,15 +180,25 @@ impl NumberGuessContract {
         );
 
         // Create game (winning_number not set yet - will be generated in reveal_winner)
+        let action_timeout = game_common::get_action_timeout(&env);
         let game = Game {
             player1: player1.clone(),
             player2: player2.clone(),
             player1_points,
             player2_points,
+            beacon: Beacon::new(
+                &env,
+                vec![&env, player1.clone(), player2.clone()],
+                action_timeout,
+                action_timeout,
+            ),
             player1_guess: None,
             player2_guess: None,
             winning_number: None,
             winner: None,
+            draw_offer: None,
+            drawn: false,
+            action_deadline: game_common::action_deadline(&env),
         };
 
         // Store game in temporary storage with 30-day TTL
@@ -173,23 +206,72 @@ impl NumberGuessContract {
         env.storage().temporary().set(&game_key, &game);
 
         // Set TTL to ensure game is retained for at least 30 days
-        env.storage()
-            .temporary()
-            .extend_ttl(&game_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+        game_common::extend_game_ttl(&env, &game_key);
 
         // Event emitted by the Game Hub contract (GameStarted)
 
         Ok(())
     }
 
-    /// Make a guess for the current game.
-    /// Players can guess a number between 1 and 10.
+    /// Commit to a guess for the current game without revealing it.
     ///
     /// # Arguments
     /// * `session_id` - The session ID of the game
     /// * `player` - Address of the player making the guess
-    /// * `guess` - The guessed number (1-10)
-    pub fn make_guess(env: Env, session_id: u32, player: Address, guess: u32) -> Result<(), Error> {
+    /// * `commitment` - `keccak256(guess || salt)`, with the guess (1-10) as
+    ///   4 big-endian bytes and a secret 32-byte salt
+    pub fn commit_guess(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        commitment: BytesN<32>,
+    ) -> Result<(), Error> {
+        player.require_auth();
+
+        // Get game from temporary storage
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        // Check game is still active (no winner or agreed draw yet)
+        if game.winner.is_some() || game.drawn {
+            return Err(Error::GameAlreadyEnded);
+        }
+
+        // Store the commitment for the appropriate player
+        game.beacon.commit(&env, &player, commitment)?;
+
+        // The last commitment starts the reveal phase
+        if game.beacon.all_committed() {
+            game.action_deadline = game_common::action_deadline(&env);
+        }
+
+        // Store updated game in temporary storage
+        env.storage().temporary().set(&key, &game);
+
+        // No event emitted - game state can be queried via get_game()
+
+        Ok(())
+    }
+
+    /// Reveal a committed guess. Only allowed once both players have
+    /// committed, so neither reveal can influence the other player's guess.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the player revealing
+    /// * `guess` - The committed guess (1-10)
+    /// * `salt` - The salt used in the commitment
+    pub fn reveal_guess(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        guess: u32,
+        salt: BytesN<32>,
+    ) -> Result<(), Error> {
         player.require_auth();
 
         // Validate guess is in range
@@ -205,36 +287,31 @@ impl NumberGuessContract {
             .get(&key)
             .ok_or(Error::GameNotFound)?;
 
-        // Check game is still active (no winner yet)
-        if game.winner.is_some() {
+        // Check game is still active (no winner or agreed draw yet)
+        if game.winner.is_some() || game.drawn {
             return Err(Error::GameAlreadyEnded);
         }
 
-        // Update guess for the appropriate player
+        // Reveals only start once both guesses are locked in, and must match
+        // the player's commitment
+        game.beacon.reveal(&env, &player, guess_reveal(&env, guess, &salt))?;
+
+        // Record the guess for the appropriate player
         if player == game.player1 {
-            if game.player1_guess.is_some() {
-                return Err(Error::AlreadyGuessed);
-            }
             game.player1_guess = Some(guess);
-        } else if player == game.player2 {
-            if game.player2_guess.is_some() {
-                return Err(Error::AlreadyGuessed);
-            }
-            game.player2_guess = Some(guess);
         } else {
-            return Err(Error::NotPlayer);
+            game.player2_guess = Some(guess);
         }
 
         // Store updated game in temporary storage
         env.storage().temporary().set(&key, &game);
 
-        // No event emitted - game state can be queried via get_game()
-
         Ok(())
     }
 
     /// Reveal the winner of the game and submit outcome to GameHub.
-    /// Can only be called after both players have made their guesses.
+    /// Can only be called after both players have revealed guesses matching
+    /// their commitments.
     /// This generates the winning number, determines the winner, and ends the session.
     ///
     /// # Arguments
@@ -255,41 +332,26 @@ impl NumberGuessContract {
         if let Some(winner) = &game.winner {
             return Ok(winner.clone());
         }
+        if game.drawn {
+            return Err(Error::GameAlreadyEnded);
+        }
 
-        // Check both players have guessed
-        let guess1 = game.player1_guess.ok_or(Error::BothPlayersNotGuessed)?;
-        let guess2 = game.player2_guess.ok_or(Error::BothPlayersNotGuessed)?;
+        // Check both players have committed and revealed
+        if !game.beacon.all_committed() {
+            return Err(Error::BothPlayersNotGuessed);
+        }
+        let guess1 = game.player1_guess.ok_or(Error::BothPlayersNotRevealed)?;
+        let guess2 = game.player2_guess.ok_or(Error::BothPlayersNotRevealed)?;
 
-        // Generate random winning number between 1 and 10 using seeded PRNG
-        // This is done AFTER both players have committed their guesses
-        //
-        // Seed components (all deterministic and identical between sim/submit):
-        // 1. Session ID - unique per game, same between simulation and submission
-        // 2. Player addresses - both players contribute, same between sim/submit
-        // 3. Guesses - committed before reveal, same between sim/submit
+        // Generate random winning number between 1 and 10 from the beacon seed
+        // This is done AFTER both players have revealed their guesses
         //
-        // Note: We do NOT include ledger sequence or timestamp because those differ
-        // between simulation and submission, which would cause different winners.
-        //
-        // This ensures:
-        // - Same result between simulation and submission (fully deterministic)
-        // - Cannot be easily gamed (both players contribute to randomness)
-
-        // Build seed more efficiently using native arrays where possible
-        // Total: 12 bytes of fixed data (session_id + 2 guesses)
-        let mut fixed_data = [0u8; 12];
-        fixed_data[0..4].copy_from_slice(&session_id.to_be_bytes());
-        fixed_data[4..8].copy_from_slice(&guess1.to_be_bytes());
-        fixed_data[8..12].copy_from_slice(&guess2.to_be_bytes());
-
-        // Only use Bytes for the final concatenation with player addresses
-        let mut seed_bytes = Bytes::from_array(&env, &fixed_data);
-        seed_bytes.append(&game.player1.to_string().to_bytes());
-        seed_bytes.append(&game.player2.to_string().to_bytes());
-
-        let seed = env.crypto().keccak256(&seed_bytes);
-        env.prng().seed(seed.into());
-        let winning_number = env.prng().gen_range::<u64>(1..=10) as u32;
+        // The seed combines both players' reveals, and each salt stayed secret
+        // until both guesses were committed, so nobody knew the winning number
+        // while they could still change their guess. It is fully deterministic,
+        // so simulation and submission produce the same winner.
+        let seed = game.beacon.seed(&env)?;
+        let winning_number = SeedRng::new(&env, seed).range(1..=10);
         game.winning_number = Some(winning_number);
 
         // Calculate distances
@@ -316,25 +378,199 @@ impl NumberGuessContract {
         game.winner = Some(winner.clone());
         env.storage().temporary().set(&key, &game);
 
-        // Get GameHub address
-        let game_hub_addr: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::GameHubAddress)
-            .expect("GameHub address not set");
-
         // Create GameHub client
-        let game_hub = GameHubClient::new(&env, &game_hub_addr);
+        let game_hub = game_common::hub_client(&env);
 
         // Call GameHub to end the session
         // This unlocks points and updates standings
         // Event emitted by the Game Hub contract (GameEnded)
-        let player1_won = winner == game.player1; // true if player1 won, false if player2 won
-        game_hub.end_game(&session_id, &player1_won);
+        let outcome = if winner == game.player1 {
+            GameOutcome::Player1Won
+        } else {
+            GameOutcome::Player2Won
+        };
+        game_hub.end_game(&session_id, &outcome);
 
         Ok(winner)
     }
 
+    /// Claim the win after the action deadline has passed.
+    /// The claimant must have done their part of the current phase (committed,
+    /// or revealed once both committed) while the opponent hasn't.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the player claiming the win
+    ///
+    /// # Returns
+    /// * `Address` - Address of the winning player
+    pub fn claim_timeout(env: Env, session_id: u32, player: Address) -> Result<Address, Error> {
+        player.require_auth();
+
+        // Get game from temporary storage
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        // Check game is still active (no winner or agreed draw yet)
+        if game.winner.is_some() || game.drawn {
+            return Err(Error::GameAlreadyEnded);
+        }
+
+        let opponent = if player == game.player1 {
+            game.player2.clone()
+        } else if player == game.player2 {
+            game.player1.clone()
+        } else {
+            return Err(Error::NotPlayer);
+        };
+
+        if env.ledger().sequence() <= game.action_deadline {
+            return Err(Error::DeadlineNotReached);
+        }
+        if game.beacon.awaiting(&player) {
+            return Err(Error::PlayerStalled);
+        }
+        if !game.beacon.awaiting(&opponent) {
+            return Err(Error::OpponentNotStalled);
+        }
+
+        // Update game with winner (this marks the game as ended)
+        game.winner = Some(player.clone());
+        env.storage().temporary().set(&key, &game);
+
+        // Report the forfeit to the GameHub
+        let outcome = if player == game.player1 {
+            GameOutcome::Player1Won
+        } else {
+            GameOutcome::Player2Won
+        };
+        game_common::hub_client(&env).end_game(&session_id, &outcome);
+
+        Ok(player)
+    }
+
+    /// Resign the game, handing the win to the opponent.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the resigning player
+    ///
+    /// # Returns
+    /// * `Address` - Address of the winning player
+    pub fn resign(env: Env, session_id: u32, player: Address) -> Result<Address, Error> {
+        player.require_auth();
+
+        // Get game from temporary storage
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        // Check game is still active (no winner or agreed draw yet)
+        if game.winner.is_some() || game.drawn {
+            return Err(Error::GameAlreadyEnded);
+        }
+
+        let (winner, outcome) = if player == game.player1 {
+            (game.player2.clone(), GameOutcome::Player2Won)
+        } else if player == game.player2 {
+            (game.player1.clone(), GameOutcome::Player1Won)
+        } else {
+            return Err(Error::NotPlayer);
+        };
+
+        // Update game with winner (this marks the game as ended)
+        game.winner = Some(winner.clone());
+        env.storage().temporary().set(&key, &game);
+
+        // Report the resignation to the GameHub
+        game_common::hub_client(&env).end_game(&session_id, &outcome);
+
+        Ok(winner)
+    }
+
+    /// Offer the opponent a draw. The offer stands until the opponent accepts
+    /// it or the game ends; offering again replaces the opponent's own offer.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the player offering the draw
+    pub fn offer_draw(env: Env, session_id: u32, player: Address) -> Result<(), Error> {
+        player.require_auth();
+
+        // Get game from temporary storage
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        // Check game is still active (no winner or agreed draw yet)
+        if game.winner.is_some() || game.drawn {
+            return Err(Error::GameAlreadyEnded);
+        }
+
+        if player != game.player1 && player != game.player2 {
+            return Err(Error::NotPlayer);
+        }
+
+        game.draw_offer = Some(player);
+        env.storage().temporary().set(&key, &game);
+
+        Ok(())
+    }
+
+    /// Accept the opponent's draw offer, ending the game as a draw.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the player accepting the draw
+    pub fn accept_draw(env: Env, session_id: u32, player: Address) -> Result<(), Error> {
+        player.require_auth();
+
+        // Get game from temporary storage
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        // Check game is still active (no winner or agreed draw yet)
+        if game.winner.is_some() || game.drawn {
+            return Err(Error::GameAlreadyEnded);
+        }
+
+        let opponent = if player == game.player1 {
+            game.player2.clone()
+        } else if player == game.player2 {
+            game.player1.clone()
+        } else {
+            return Err(Error::NotPlayer);
+        };
+
+        // Only the opponent's offer can be accepted
+        if game.draw_offer != Some(opponent) {
+            return Err(Error::NoDrawOffer);
+        }
+
+        // Mark the draw (this marks the game as ended)
+        game.drawn = true;
+        env.storage().temporary().set(&key, &game);
+
+        // Report the draw to the GameHub
+        game_common::hub_client(&env).end_game(&session_id, &GameOutcome::Draw);
+
+        Ok(())
+    }
+
     /// Get game information.
     ///
     /// # Arguments
@@ -359,10 +595,7 @@ impl NumberGuessContract {
     /// # Returns
     /// * `Address` - The admin address
     pub fn get_admin(env: Env) -> Address {
-        env.storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set")
+        game_common::get_admin(&env)
     }
 
     /// Set a new admin address
@@ -370,14 +603,7 @@ impl NumberGuessContract {
     /// # Arguments
     /// * `new_admin` - The new admin address
     pub fn set_admin(env: Env, new_admin: Address) {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
-
-        env.storage().instance().set(&DataKey::Admin, &new_admin);
+        game_common::set_admin(&env, &new_admin);
     }
 
     /// Get the current GameHub contract address
@@ -385,10 +611,7 @@ impl NumberGuessContract {
     /// # Returns
     /// * `Address` - The GameHub contract address
     pub fn get_hub(env: Env) -> Address {
-        env.storage()
-            .instance()
-            .get(&DataKey::GameHubAddress)
-            .expect("GameHub address not set")
+        game_common::get_hub(&env)
     }
 
     /// Set a new GameHub contract address
@@ -396,16 +619,23 @@ impl NumberGuessContract {
     /// # Arguments
     /// * `new_hub` - The new GameHub contract address
     pub fn set_hub(env: Env, new_hub: Address) {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
+        game_common::set_hub(&env, &new_hub);
+    }
 
-        env.storage()
-            .instance()
-            .set(&DataKey::GameHubAddress, &new_hub);
+    /// Get the number of ledgers players have to commit, and then to reveal
+    ///
+    /// # Returns
+    /// * `u32` - The action timeout in ledgers
+    pub fn get_action_timeout(env: Env) -> u32 {
+        game_common::get_action_timeout(&env)
+    }
+
+    /// Set the action timeout for new phases (games in progress keep their deadline)
+    ///
+    /// # Arguments
+    /// * `ledgers` - The action timeout in ledgers
+    pub fn set_action_timeout(env: Env, ledgers: u32) {
+        game_common::set_action_timeout(&env, ledgers);
     }
 
     /// Update the contract WASM hash (upgrade contract)
@@ -413,14 +643,7 @@ impl NumberGuessContract {
     /// # Arguments
     /// * `new_wasm_hash` - The hash of the new WASM binary
     pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
-
-        env.deployer().update_current_contract_wasm(new_wasm_hash);
+        game_common::upgrade(&env, new_wasm_hash);
     }
 }
 
@@ -6,9 +6,9 @@
 // Note: These tests use a minimal mock for isolation and speed.
 // For full integration tests with the real Game Hub contract, see the platform repo.
 
-use crate::{Error, NumberGuessContract, NumberGuessContractClient};
+use crate::{Error, GameOutcome, NumberGuessContract, NumberGuessContractClient};
 use soroban_sdk::testutils::{Address as _, Ledger as _};
-use soroban_sdk::{contract, contractimpl, Address, BytesN, Env};
+use soroban_sdk::{contract, contractimpl, Address, Bytes, BytesN, Env};
 
 // ============================================================================
 // Mock GameHub for Unit Testing
@@ -31,8 +31,13 @@ impl MockGameHub {
         // Mock implementation - does nothing
     }
 
-    pub fn end_game(_env: Env, _session_id: u32, _player1_won: bool) {
-        // Mock implementation - does nothing
+    pub fn end_game(env: Env, session_id: u32, outcome: GameOutcome) {
+        // Record the outcome so tests can check what the game reported
+        env.storage().temporary().set(&session_id, &outcome);
+    }
+
+    pub fn get_outcome(env: Env, session_id: u32) -> Option<GameOutcome> {
+        env.storage().temporary().get(&session_id)
     }
 
     pub fn add_game(_env: Env, _game_address: Address) {
@@ -86,6 +91,35 @@ fn setup_test() -> (
     (env, client, game_hub, player1, player2)
 }
 
+/// Salt a test player uses for their commitment
+fn salt(env: &Env, seed: u8) -> BytesN<32> {
+    BytesN::from_array(env, &[seed; 32])
+}
+
+/// Build the commitment `keccak256(guess || salt)` a frontend would submit
+fn commitment(env: &Env, guess: u32, salt: &BytesN<32>) -> BytesN<32> {
+    let mut data = Bytes::from_array(env, &guess.to_be_bytes());
+    data.append(&Bytes::from(salt.clone()));
+    env.crypto().keccak256(&data).into()
+}
+
+/// Commit and then reveal both players' guesses
+fn play_guesses(
+    env: &Env,
+    client: &NumberGuessContractClient,
+    session_id: u32,
+    player1: &Address,
+    guess1: u32,
+    player2: &Address,
+    guess2: u32,
+) {
+    let (salt1, salt2) = (salt(env, 1), salt(env, 2));
+    client.commit_guess(&session_id, player1, &commitment(env, guess1, &salt1));
+    client.commit_guess(&session_id, player2, &commitment(env, guess2, &salt2));
+    client.reveal_guess(&session_id, player1, &guess1, &salt1);
+    client.reveal_guess(&session_id, player2, &guess2, &salt2);
+}
+
 /// Assert that a Result contains a specific number_guess error
 ///
 /// This helper provides type-safe error assertions following Stellar/Soroban best practices.
@@ -96,7 +130,7 @@ fn setup_test() -> (
 ///
 /// # Example
 /// ```
-/// let result = client.try_make_guess(&session_id, &player, &7);
+/// let result = client.try_commit_guess(&session_id, &player, &commitment);
 /// assert_number_guess_error(&result, Error::AlreadyGuessed);
 /// ```
 ///
@@ -145,7 +179,7 @@ fn assert_number_guess_error<T, E>(
 
 #[test]
 fn test_complete_game() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 1u32;
     let points = 100_0000000;
@@ -162,9 +196,8 @@ fn test_complete_game() {
     assert_eq!(game.player1_points, points);
     assert_eq!(game.player2_points, points);
 
-    // Make guesses
-    client.make_guess(&session_id, &player1, &5);
-    client.make_guess(&session_id, &player2, &7);
+    // Commit and reveal guesses
+    play_guesses(&env, &client, session_id, &player1, 5, &player2, 7);
 
     // Reveal winner
     let winner = client.reveal_winner(&session_id);
@@ -181,14 +214,13 @@ fn test_complete_game() {
 
 #[test]
 fn test_winning_number_in_range() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 2u32;
     client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
 
-    // Make guesses and reveal winner to generate winning number
-    client.make_guess(&session_id, &player1, &5);
-    client.make_guess(&session_id, &player2, &7);
+    // Play guesses and reveal winner to generate winning number
+    play_guesses(&env, &client, session_id, &player1, 5, &player2, 7);
     client.reveal_winner(&session_id);
 
     let game = client.get_game(&session_id);
@@ -227,15 +259,14 @@ fn test_multiple_sessions() {
 
 #[test]
 fn test_closest_guess_wins() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 5u32;
     client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
 
     // Player1 guesses closer (1 away from any number between 1-10)
     // Player2 guesses further (at least 2 away)
-    client.make_guess(&session_id, &player1, &5);
-    client.make_guess(&session_id, &player2, &10);
+    play_guesses(&env, &client, session_id, &player1, 5, &player2, 10);
 
     let winner = client.reveal_winner(&session_id);
 
@@ -268,14 +299,13 @@ fn test_closest_guess_wins() {
 
 #[test]
 fn test_tie_game_player1_wins() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 6u32;
     client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
 
     // Both players guess the same number (guaranteed tie)
-    client.make_guess(&session_id, &player1, &5);
-    client.make_guess(&session_id, &player2, &5);
+    play_guesses(&env, &client, session_id, &player1, 5, &player2, 5);
 
     let winner = client.reveal_winner(&session_id);
     assert_eq!(winner, player1, "Player1 should win in a tie");
@@ -283,15 +313,14 @@ fn test_tie_game_player1_wins() {
 
 #[test]
 fn test_exact_guess_wins() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 7u32;
     client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
 
     // Player1 guesses 5 (middle), player2 guesses 10 (edge)
     // Player1 is more likely to be closer to the winning number
-    client.make_guess(&session_id, &player1, &5);
-    client.make_guess(&session_id, &player2, &10);
+    play_guesses(&env, &client, session_id, &player1, 5, &player2, 10);
 
     let winner = client.reveal_winner(&session_id);
     let game = client.get_game(&session_id);
@@ -322,28 +351,88 @@ fn test_exact_guess_wins() {
 
 #[test]
 fn test_cannot_guess_twice() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 8u32;
     client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
 
-    // Make first guess
-    client.make_guess(&session_id, &player1, &5);
+    // Commit first guess
+    client.commit_guess(&session_id, &player1, &commitment(&env, 5, &salt(&env, 1)));
 
-    // Try to guess again - should fail
-    let result = client.try_make_guess(&session_id, &player1, &6);
+    // Try to commit again - should fail
+    let result = client.try_commit_guess(&session_id, &player1, &commitment(&env, 6, &salt(&env, 1)));
     assert_number_guess_error(&result, Error::AlreadyGuessed);
 }
 
+#[test]
+fn test_cannot_reveal_guess_twice() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 17u32;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    play_guesses(&env, &client, session_id, &player1, 5, &player2, 7);
+
+    let result = client.try_reveal_guess(&session_id, &player1, &5, &salt(&env, 1));
+    assert_number_guess_error(&result, Error::AlreadyRevealed);
+}
+
+#[test]
+fn test_reveal_must_match_commitment() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 18u32;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    client.commit_guess(&session_id, &player1, &commitment(&env, 5, &salt(&env, 1)));
+    client.commit_guess(&session_id, &player2, &commitment(&env, 7, &salt(&env, 2)));
+
+    // Changing the guess after seeing the other commitment is rejected
+    let result = client.try_reveal_guess(&session_id, &player1, &6, &salt(&env, 1));
+    assert_number_guess_error(&result, Error::CommitmentMismatch);
+
+    // So is revealing with a different salt
+    let result = client.try_reveal_guess(&session_id, &player1, &5, &salt(&env, 3));
+    assert_number_guess_error(&result, Error::CommitmentMismatch);
+
+    client.reveal_guess(&session_id, &player1, &5, &salt(&env, 1));
+    assert_eq!(client.get_game(&session_id).player1_guess, Some(5));
+}
+
+#[test]
+fn test_cannot_reveal_guess_before_both_commit() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 19u32;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    client.commit_guess(&session_id, &player1, &commitment(&env, 5, &salt(&env, 1)));
+
+    // Revealing early would let player2 pick a guess knowing player1's
+    let result = client.try_reveal_guess(&session_id, &player1, &5, &salt(&env, 1));
+    assert_number_guess_error(&result, Error::BothPlayersNotGuessed);
+}
+
+#[test]
+fn test_cannot_reveal_winner_before_both_reveals() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 22u32;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    client.commit_guess(&session_id, &player1, &commitment(&env, 5, &salt(&env, 1)));
+    client.commit_guess(&session_id, &player2, &commitment(&env, 7, &salt(&env, 2)));
+    client.reveal_guess(&session_id, &player1, &5, &salt(&env, 1));
+
+    let result = client.try_reveal_winner(&session_id);
+    assert_number_guess_error(&result, Error::BothPlayersNotRevealed);
+}
+
 #[test]
 fn test_cannot_reveal_before_both_guesses() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 9u32;
     client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
 
-    // Only player1 guesses
-    client.make_guess(&session_id, &player1, &5);
+    // Only player1 commits
+    client.commit_guess(&session_id, &player1, &commitment(&env, 5, &salt(&env, 1)));
 
     // Try to reveal winner - should fail
     let result = client.try_reveal_winner(&session_id);
@@ -353,37 +442,29 @@ fn test_cannot_reveal_before_both_guesses() {
 #[test]
 #[should_panic(expected = "Guess must be between 1 and 10")]
 fn test_cannot_guess_below_range() {
-    let (env, client, _hub, player1, _player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 10u32;
-    client.start_game(
-        &session_id,
-        &player1,
-        &Address::generate(&env),
-        &100_0000000,
-        &100_0000000,
-    );
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    client.commit_guess(&session_id, &player1, &commitment(&env, 0, &salt(&env, 1)));
+    client.commit_guess(&session_id, &player2, &commitment(&env, 5, &salt(&env, 2)));
 
-    // Try to guess 0 (below range) - should panic
-    client.make_guess(&session_id, &player1, &0);
+    // Try to reveal 0 (below range) - should panic
+    client.reveal_guess(&session_id, &player1, &0, &salt(&env, 1));
 }
 
 #[test]
 #[should_panic(expected = "Guess must be between 1 and 10")]
 fn test_cannot_guess_above_range() {
-    let (env, client, _hub, player1, _player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 11u32;
-    client.start_game(
-        &session_id,
-        &player1,
-        &Address::generate(&env),
-        &100_0000000,
-        &100_0000000,
-    );
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    client.commit_guess(&session_id, &player1, &commitment(&env, 11, &salt(&env, 1)));
+    client.commit_guess(&session_id, &player2, &commitment(&env, 5, &salt(&env, 2)));
 
-    // Try to guess 11 (above range) - should panic
-    client.make_guess(&session_id, &player1, &11);
+    // Try to reveal 11 (above range) - should panic
+    client.reveal_guess(&session_id, &player1, &11, &salt(&env, 1));
 }
 
 #[test]
@@ -395,7 +476,7 @@ fn test_non_player_cannot_guess() {
     client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
 
     // Non-player tries to guess
-    let result = client.try_make_guess(&session_id, &non_player, &5);
+    let result = client.try_commit_guess(&session_id, &non_player, &commitment(&env, 5, &salt(&env, 3)));
     assert_number_guess_error(&result, Error::NotPlayer);
 }
 
@@ -409,32 +490,30 @@ fn test_cannot_reveal_nonexistent_game() {
 
 #[test]
 fn test_cannot_guess_after_game_ended() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 12u32;
     client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
 
-    // Both players make guesses
-    client.make_guess(&session_id, &player1, &5);
-    client.make_guess(&session_id, &player2, &7);
+    // Both players play their guesses
+    play_guesses(&env, &client, session_id, &player1, 5, &player2, 7);
 
     // Reveal winner - game ends
     let _winner = client.reveal_winner(&session_id);
 
     // Try to make another guess after game has ended - should fail
-    let result = client.try_make_guess(&session_id, &player1, &3);
+    let result = client.try_commit_guess(&session_id, &player1, &commitment(&env, 3, &salt(&env, 1)));
     assert_number_guess_error(&result, Error::GameAlreadyEnded);
 }
 
 #[test]
 fn test_cannot_reveal_twice() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 14u32;
     client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
 
-    client.make_guess(&session_id, &player1, &5);
-    client.make_guess(&session_id, &player2, &7);
+    play_guesses(&env, &client, session_id, &player1, 5, &player2, 7);
 
     // First reveal succeeds
     let winner = client.reveal_winner(&session_id);
@@ -445,6 +524,177 @@ fn test_cannot_reveal_twice() {
     assert_eq!(winner, winner2);
 }
 
+#[test]
+fn test_cannot_start_existing_session() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 16u32;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    let commitment1 = commitment(&env, 5, &salt(&env, 1));
+    client.commit_guess(&session_id, &player1, &commitment1);
+
+    // Starting again over the live session must not wipe the commitment
+    let result =
+        client.try_start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    assert_number_guess_error(&result, Error::SessionExists);
+    assert_eq!(
+        client.get_game(&session_id).beacon.commitments.get(player1.clone()),
+        Some(commitment1)
+    );
+}
+
+#[test]
+fn test_commit_and_reveal_deadlines() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 23u32;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    client.commit_guess(&session_id, &player1, &commitment(&env, 5, &salt(&env, 1)));
+
+    // Too late to commit once the commit window has passed
+    let deadline = client.get_game(&session_id).beacon.commit_deadline;
+    env.ledger().set_sequence_number(deadline + 1);
+    let result = client.try_commit_guess(&session_id, &player2, &commitment(&env, 7, &salt(&env, 2)));
+    assert_number_guess_error(&result, Error::CommitDeadlinePassed);
+
+    // The reveal window opens with the last commitment
+    env.ledger().set_sequence_number(deadline);
+    client.commit_guess(&session_id, &player2, &commitment(&env, 7, &salt(&env, 2)));
+    let reveal_deadline = client.get_game(&session_id).beacon.reveal_deadline.unwrap();
+    env.ledger().set_sequence_number(reveal_deadline + 1);
+    let result = client.try_reveal_guess(&session_id, &player1, &5, &salt(&env, 1));
+    assert_number_guess_error(&result, Error::RevealDeadlinePassed);
+}
+
+// ============================================================================
+// Timeout Tests
+// ============================================================================
+
+#[test]
+fn test_claim_timeout_when_opponent_never_commits() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 30u32;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    client.commit_guess(&session_id, &player1, &commitment(&env, 5, &salt(&env, 1)));
+
+    // Player 2 still has time to commit
+    let result = client.try_claim_timeout(&session_id, &player1);
+    assert_number_guess_error(&result, Error::DeadlineNotReached);
+
+    let deadline = client.get_game(&session_id).action_deadline;
+    env.ledger().set_sequence_number(deadline + 1);
+
+    let result = client.try_claim_timeout(&session_id, &player2);
+    assert_number_guess_error(&result, Error::PlayerStalled);
+
+    assert_eq!(client.claim_timeout(&session_id, &player1), player1);
+    assert_eq!(client.get_game(&session_id).winner, Some(player1.clone()));
+
+    let result = client.try_reveal_winner(&session_id);
+    assert_eq!(result, Ok(Ok(player1)));
+}
+
+#[test]
+fn test_claim_timeout_when_opponent_never_reveals() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    client.set_action_timeout(&10);
+    assert_eq!(client.get_action_timeout(), 10);
+
+    let session_id = 31u32;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    assert_eq!(client.get_game(&session_id).action_deadline, 110);
+
+    // The last commitment starts a fresh reveal deadline
+    env.ledger().set_sequence_number(105);
+    client.commit_guess(&session_id, &player1, &commitment(&env, 5, &salt(&env, 1)));
+    client.commit_guess(&session_id, &player2, &commitment(&env, 7, &salt(&env, 2)));
+    assert_eq!(client.get_game(&session_id).action_deadline, 115);
+
+    client.reveal_guess(&session_id, &player1, &5, &salt(&env, 1));
+    env.ledger().set_sequence_number(116);
+
+    let result = client.try_claim_timeout(&session_id, &player2);
+    assert_number_guess_error(&result, Error::PlayerStalled);
+    assert_eq!(client.claim_timeout(&session_id, &player1), player1);
+}
+
+#[test]
+fn test_claim_timeout_needs_stalled_opponent() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 32u32;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    play_guesses(&env, &client, session_id, &player1, 3, &player2, 8);
+
+    let deadline = client.get_game(&session_id).action_deadline;
+    env.ledger().set_sequence_number(deadline + 1);
+    let result = client.try_claim_timeout(&session_id, &player1);
+    assert_number_guess_error(&result, Error::OpponentNotStalled);
+}
+
+// ============================================================================
+// Resign and Draw Tests
+// ============================================================================
+
+#[test]
+fn test_resign_hands_win_to_opponent() {
+    let (_env, client, hub, player1, player2) = setup_test();
+
+    let session_id = 70u32;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+
+    assert_eq!(client.resign(&session_id, &player2), player1);
+    assert_eq!(client.get_game(&session_id).winner, Some(player1.clone()));
+    assert_eq!(hub.get_outcome(&session_id), Some(GameOutcome::Player1Won));
+
+    let result = client.try_resign(&session_id, &player1);
+    assert_number_guess_error(&result, Error::GameAlreadyEnded);
+}
+
+#[test]
+fn test_agreed_draw_ends_game() {
+    let (env, client, hub, player1, player2) = setup_test();
+
+    let session_id = 71u32;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+
+    // Nothing to accept yet, and a player can't accept their own offer
+    let result = client.try_accept_draw(&session_id, &player2);
+    assert_number_guess_error(&result, Error::NoDrawOffer);
+    client.offer_draw(&session_id, &player1);
+    let result = client.try_accept_draw(&session_id, &player1);
+    assert_number_guess_error(&result, Error::NoDrawOffer);
+
+    client.accept_draw(&session_id, &player2);
+    let game = client.get_game(&session_id);
+    assert!(game.drawn);
+    assert!(game.winner.is_none());
+    assert_eq!(hub.get_outcome(&session_id), Some(GameOutcome::Draw));
+
+    // The game is over for every other action
+    let result = client.try_commit_guess(&session_id, &player1, &commitment(&env, 5, &salt(&env, 1)));
+    assert_number_guess_error(&result, Error::GameAlreadyEnded);
+    let result = client.try_reveal_winner(&session_id);
+    assert_number_guess_error(&result, Error::GameAlreadyEnded);
+    let result = client.try_resign(&session_id, &player1);
+    assert_number_guess_error(&result, Error::GameAlreadyEnded);
+}
+
+#[test]
+fn test_non_player_cannot_resign_or_offer_draw() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 72u32;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+
+    let outsider = Address::generate(&env);
+    let result = client.try_resign(&session_id, &outsider);
+    assert_number_guess_error(&result, Error::NotPlayer);
+    let result = client.try_offer_draw(&session_id, &outsider);
+    assert_number_guess_error(&result, Error::NotPlayer);
+}
+
 // ============================================================================
 // Multiple Games Tests
 // ============================================================================
@@ -463,10 +713,8 @@ fn test_multiple_games_independent() {
     client.start_game(&session2, &player3, &player4, &50_0000000, &50_0000000);
 
     // Play both games independently
-    client.make_guess(&session1, &player1, &3);
-    client.make_guess(&session2, &player3, &8);
-    client.make_guess(&session1, &player2, &7);
-    client.make_guess(&session2, &player4, &2);
+    play_guesses(&env, &client, session1, &player1, 3, &player2, 7);
+    play_guesses(&env, &client, session2, &player3, 8, &player4, 2);
 
     // Reveal both winners
     let winner1 = client.reveal_winner(&session1);
@@ -489,7 +737,7 @@ fn test_multiple_games_independent() {
 
 #[test]
 fn test_asymmetric_points() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 15u32;
     let points1 = 200_0000000;
@@ -501,8 +749,7 @@ fn test_asymmetric_points() {
     assert_eq!(game.player1_points, points1);
     assert_eq!(game.player2_points, points2);
 
-    client.make_guess(&session_id, &player1, &5);
-    client.make_guess(&session_id, &player2, &5);
+    play_guesses(&env, &client, session_id, &player1, 5, &player2, 5);
     client.reveal_winner(&session_id);
 
     // Game completes successfully with asymmetric points
@@ -8,31 +8,48 @@
 //! **Game Hub Integration:**
 //! This game is Game Hub-aware and enforces all games to be played through the
 //! Game Hub contract. Games cannot be started or completed without points involvement.
+//!
+//! **Timeouts:**
+//! Each phase (secret setup, then every guess/proof/verify round) has a
+//! deadline in ledger sequence numbers. Once it has passed, a player who has
+//! done everything the phase asks of them can `claim_timeout` against an
+//! opponent who hasn't, winning the game.
+//!
+//! **Proof Binding:**
+//! Besides the guess, the secret hash and the claimed feedback, every proof's
+//! public inputs carry the session ID, the round and a hash of the proving
+//! player's address. The contract fills them in from the game, so a proof
+//! can't be replayed in another game, a later round or by another player.
+//!
+//! **Rounds:**
+//! Every round moves through three phases. In `GuessPhase` both players
+//! submit a guess, which is locked once both are in. In `ProofPhase` each
+//! player answers the opponent's locked guess with a proof, and in
+//! `Verification` the proofs are checked. Calls made out of phase are
+//! rejected.
+//!
+//! Once both proofs of a round are in, anyone can `resolve_round` to verify
+//! both in one transaction, optionally earning a resolver reward; players can
+//! still verify the opponent's proof one at a time with `verify_proof`.
+//!
+//! With `set_verify_on_submit`, the admin can have new games verify each
+//! proof as soon as it is submitted against the opponent's locked guess. Only
+//! the verified feedback is stored: a failing proof loses the game on the
+//! spot, and otherwise a round resolves with its second proof.
+//!
+//! Either player can also `resign`, or end the game as a draw with
+//! `offer_draw` and the opponent's `accept_draw`.
 
+pub use game_common::GameOutcome;
 use soroban_sdk::{
-    contract, contractclient, contracterror, contractimpl, contracttype, vec, Address, Bytes,
-    BytesN, Env, IntoVal, Symbol, Vec,
+    contract, contracterror, contractimpl, contracttype, token, vec, Address,
+    Bytes, BytesN, Env, IntoVal, Vec,
 };
 
 // Import code for ZK verification
+#[cfg(not(test))]
 use ultrahonk_soroban_verifier::verifier::UltraHonkVerifier;
 
-// Import GameHub contract interface
-#[contractclient(name = "GameHubClient")]
-pub trait GameHub {
-    fn start_game(
-        env: Env,
-        game_id: Address,
-        session_id: u32,
-        player1: Address,
-        player2: Address,
-        player1_points: i128,
-        player2_points: i128,
-    );
-
-    fn end_game(env: Env, session_id: u32, player1_won: bool);
-}
-
 // ============================================================================
 // Errors
 // ============================================================================
@@ -48,6 +65,16 @@ pub enum Error {
     InvalidStatus = 6,
     SecretAlreadyRegistered = 7,
     BothPlayersNotGuessed = 8,
+    SessionExists = 9,
+    DeadlineNotReached = 10,
+    OpponentNotStalled = 11,
+    PlayerStalled = 12,
+    NoDrawOffer = 13,
+    NotGuessPhase = 14,
+    NotProofPhase = 15,
+    NotVerificationPhase = 16,
+    ProofAlreadySubmitted = 17,
+    VerificationKeyNotSet = 18,
 }
 
 // ============================================================================
@@ -63,6 +90,24 @@ pub struct GameResult {
     pub permutados: u32,
 }
 
+/// Record of one verified round, kept under its own `DataKey::Round` entry
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoundRecord {
+    pub round: u32,
+    pub player1_guess: u32,
+    pub player2_guess: u32,
+    /// Feedback player 2 claimed on player 1's guess
+    pub player1_result: GameResult,
+    /// Feedback player 1 claimed on player 2's guess
+    pub player2_result: GameResult,
+    /// Whether each player's proof failed verification
+    pub p1_is_fraud: bool,
+    pub p2_is_fraud: bool,
+    /// Ledger sequence the round was resolved on
+    pub ledger: u32,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct ProofData {
@@ -94,6 +139,19 @@ pub struct Game {
     pub status: GameStatus,
     pub player1_result: soroban_sdk::Vec<GameResult>,
     pub player2_result: soroban_sdk::Vec<GameResult>,
+    /// Last ledger on which the current phase can be completed
+    pub phase_deadline: u32,
+    /// Player whose draw offer is waiting for the opponent to accept
+    pub draw_offer: Option<Address>,
+    /// Current round, starting at 1; bound into every proof's public inputs
+    pub round: u32,
+    /// Phase of the current round while the game is `Playing`
+    pub round_phase: RoundPhase,
+    /// Whether `submit_proof` verifies proofs on the spot, pinned at `start_game`
+    pub verify_on_submit: bool,
+    /// Hash of the verification key the game's proofs are checked against,
+    /// pinned at `start_game`
+    pub vk_hash: BytesN<32>,
 }
 
 #[contracttype]
@@ -107,19 +165,157 @@ pub enum GameStatus {
     Finished,
 }
 
+/// Reward paid to whoever resolves a round with `resolve_round`, out of the
+/// contract's own balance of `token`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ResolverReward {
+    pub token: Address,
+    pub amount: i128,
+}
+
+/// Phases of a round, in order
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RoundPhase {
+    /// Players submit their guesses; each can still change theirs until both are in
+    GuessPhase,
+    /// Guesses are locked; players submit proofs answering the opponent's guess
+    ProofPhase,
+    /// Both proofs are in and wait to be verified
+    Verification,
+}
+
 #[contracttype]
 #[derive(Clone)]
 pub enum DataKey {
     Game(u32),
-    GameHubAddress,
-    Admin,
+    /// Legacy instance-storage VK; cleared by `register_vk`
     VerificationKey,
+    Round(u32, u32),
+    ResolverReward,
+    VerifyOnSubmit,
+    /// Registered verification key, in persistent storage under its hash
+    Vk(BytesN<32>),
+    /// Hash of the verification key new games are pinned to
+    ActiveVk,
 }
 
-// ============================================================================
-// Storage TTL Management
-// ============================================================================
-const GAME_TTL_LEDGERS: u32 = 518_400;
+/// Size of a proof's public inputs: ten 32-byte fields (guess digits, secret
+/// hash, acertos, permutados, erros, session ID, round, player hash)
+const PUBLIC_INPUTS_LEN: usize = 320;
+
+/// Hash of a player's address as bound into their proofs: `sha256` of the
+/// address string, with the top byte cleared so it fits in a circuit field
+fn player_hash(env: &Env, player: &Address) -> BytesN<32> {
+    let mut hash = env.crypto().sha256(&player.to_string().to_bytes()).to_array();
+    hash[0] = 0;
+    BytesN::from_array(env, &hash)
+}
+
+/// Write a `u32` into the last four bytes of a 32-byte public input field
+fn write_field(inputs: &mut [u8; PUBLIC_INPUTS_LEN], field: usize, value: u32) {
+    let end = (field + 1) * 32;
+    inputs[end - 4..end].copy_from_slice(&value.to_be_bytes());
+}
+
+/// Lay out a proof's public inputs in the order the circuit declares them:
+/// the opponent's three guess digits, the prover's secret hash, the claimed
+/// acertos, permutados and erros, then the session ID, the round and the
+/// prover's address hash
+fn build_public_inputs(
+    session_id: u32,
+    round: u32,
+    player_hash: &[u8; 32],
+    opponent_guess: u32,
+    secret_hash: &[u8; 32],
+    proof_data: &ProofData,
+) -> [u8; PUBLIC_INPUTS_LEN] {
+    let mut inputs = [0u8; PUBLIC_INPUTS_LEN];
+
+    let guess_digits = [
+        (opponent_guess / 100) % 10,
+        (opponent_guess / 10) % 10,
+        opponent_guess % 10,
+    ];
+    for (field, digit) in guess_digits.into_iter().enumerate() {
+        write_field(&mut inputs, field, digit);
+    }
+
+    inputs[96..128].copy_from_slice(secret_hash);
+
+    write_field(&mut inputs, 4, proof_data.acertos);
+    write_field(&mut inputs, 5, proof_data.permutados);
+    write_field(&mut inputs, 6, proof_data.erros);
+
+    // Bind the proof to this session, round and proving player
+    write_field(&mut inputs, 7, session_id);
+    write_field(&mut inputs, 8, round);
+    inputs[288..320].copy_from_slice(player_hash);
+
+    inputs
+}
+
+/// Check an UltraHonk proof against a verification key and its public inputs
+#[cfg(not(test))]
+fn check_proof(env: &Env, vk: &Bytes, proof: &Bytes, public_inputs: &Bytes) -> bool {
+    match UltraHonkVerifier::new(env, vk) {
+        Ok(verifier) => verifier.verify(proof, public_inputs).is_ok(),
+        Err(_) => false,
+    }
+}
+
+/// Unit tests can't produce real UltraHonk proofs: a proof counts as valid
+/// when its first byte is 1, and anything else is a fraud
+#[cfg(test)]
+fn check_proof(_env: &Env, _vk: &Bytes, proof: &Bytes, public_inputs: &Bytes) -> bool {
+    public_inputs.len() as usize == PUBLIC_INPUTS_LEN && proof.first() == Some(1)
+}
+
+/// Keep a registered verification key alive for at least as long as a game
+/// pinned to it; called wherever such a game's own TTL is extended
+fn extend_vk_ttl(env: &Env, vk_hash: &BytesN<32>) {
+    env.storage().persistent().extend_ttl(
+        &DataKey::Vk(vk_hash.clone()),
+        game_common::GAME_TTL_LEDGERS,
+        game_common::GAME_TTL_LEDGERS,
+    );
+}
+
+/// Whether a player still owes an action in the current phase.
+/// In a round each player guesses, then submits the proof answering the
+/// opponent's guess; once both proofs are in, anyone can resolve the round.
+fn is_stalled(game: &Game, is_player1: bool) -> bool {
+    let (secret_hash, last_guess, proof, proof_verified, is_fraud) = if is_player1 {
+        (
+            &game.player1_secret_hash,
+            game.player1_last_guess,
+            &game.player1_proof,
+            game.p1_proof_verified,
+            game.p1_is_fraud,
+        )
+    } else {
+        (
+            &game.player2_secret_hash,
+            game.player2_last_guess,
+            &game.player2_proof,
+            game.p2_proof_verified,
+            game.p2_is_fraud,
+        )
+    };
+
+    match game.status {
+        GameStatus::Setup => secret_hash.is_none(),
+        GameStatus::Playing => match game.round_phase {
+            RoundPhase::GuessPhase => last_guess.is_none(),
+            // Only a proof that passed verification counts as having acted
+            RoundPhase::ProofPhase => proof.is_empty() || !proof_verified || is_fraud,
+            // Anyone can verify both proofs, so nobody holds the round up
+            RoundPhase::Verification => false,
+        },
+        _ => false,
+    }
+}
 
 // ============================================================================
 // Contract Definition
@@ -131,13 +327,10 @@ pub struct PassContract;
 #[contractimpl]
 impl PassContract {
     pub fn initialize(env: Env, admin: Address, game_hub: Address) {
-        if env.storage().instance().has(&DataKey::Admin) {
+        if game_common::is_initialized(&env) {
             panic!("Already initialized");
         }
-        env.storage().instance().set(&DataKey::Admin, &admin);
-        env.storage()
-            .instance()
-            .set(&DataKey::GameHubAddress, &game_hub);
+        game_common::init(&env, &admin, &game_hub);
     }
 
     pub fn start_game(
@@ -152,6 +345,18 @@ impl PassContract {
             panic!("Cannot play against yourself");
         }
 
+        if env.storage().temporary().has(&DataKey::Game(session_id)) {
+            return Err(Error::SessionExists);
+        }
+
+        // Pin the current verification key for the whole game
+        let vk_hash: BytesN<32> = env
+            .storage()
+            .instance()
+            .get(&DataKey::ActiveVk)
+            .ok_or(Error::VerificationKeyNotSet)?;
+        extend_vk_ttl(&env, &vk_hash);
+
         player1.require_auth_for_args(vec![
             &env,
             session_id.into_val(&env),
@@ -163,13 +368,7 @@ impl PassContract {
             player2_points.into_val(&env),
         ]);
 
-        let game_hub_addr: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::GameHubAddress)
-            .expect("GameHub address not set");
-
-        let game_hub = GameHubClient::new(&env, &game_hub_addr);
+        let game_hub = game_common::hub_client(&env);
 
         game_hub.start_game(
             &env.current_contract_address(),
@@ -199,14 +398,18 @@ impl PassContract {
             status: GameStatus::Setup,
             player1_result: soroban_sdk::Vec::new(&env),
             player2_result: soroban_sdk::Vec::new(&env),
+            phase_deadline: game_common::action_deadline(&env),
+            draw_offer: None,
+            round: 1,
+            round_phase: RoundPhase::GuessPhase,
+            verify_on_submit: Self::get_verify_on_submit(env.clone()),
+            vk_hash,
         };
 
         let game_key = DataKey::Game(session_id);
         env.storage().temporary().set(&game_key, &game);
 
-        env.storage()
-            .temporary()
-            .extend_ttl(&game_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+        game_common::extend_game_ttl(&env, &game_key);
 
         Ok(())
     }
@@ -246,6 +449,7 @@ impl PassContract {
 
         if game.player1_secret_hash.is_some() && game.player2_secret_hash.is_some() {
             game.status = GameStatus::Playing;
+            game.phase_deadline = game_common::action_deadline(&env);
         }
 
         env.storage().temporary().set(&key, &game);
@@ -271,6 +475,10 @@ impl PassContract {
             return Err(Error::InvalidStatus);
         }
 
+        if game.round_phase != RoundPhase::GuessPhase {
+            return Err(Error::NotGuessPhase);
+        }
+
         if player == game.player1 {
             game.player1_last_guess = Some(guess);
         } else if player == game.player2 {
@@ -279,6 +487,11 @@ impl PassContract {
             return Err(Error::NotPlayer);
         }
 
+        // Both guesses are in: lock them and move on to the proofs
+        if game.player1_last_guess.is_some() && game.player2_last_guess.is_some() {
+            game.round_phase = RoundPhase::ProofPhase;
+        }
+
         env.storage().temporary().set(&key, &game);
         Ok(())
     }
@@ -305,6 +518,28 @@ impl PassContract {
             return Err(Error::InvalidStatus);
         }
 
+        if game.round_phase != RoundPhase::ProofPhase {
+            return Err(Error::NotProofPhase);
+        }
+
+        let is_player1 = if player == game.player1 {
+            true
+        } else if player == game.player2 {
+            false
+        } else {
+            return Err(Error::NotPlayer);
+        };
+
+        // A verified proof can't be replaced, or a fraud could retry
+        let already_verified = if is_player1 {
+            game.p1_proof_verified
+        } else {
+            game.p2_proof_verified
+        };
+        if already_verified {
+            return Err(Error::ProofAlreadySubmitted);
+        }
+
         let proof_data = ProofData {
             player: player.clone(),
             acertos,
@@ -313,18 +548,72 @@ impl PassContract {
             proof,
         };
 
-        if player == game.player1 {
+        if is_player1 {
             game.player1_proof = vec![&env, proof_data];
-        } else if player == game.player2 {
-            game.player2_proof = vec![&env, proof_data];
         } else {
-            return Err(Error::NotPlayer);
+            game.player2_proof = vec![&env, proof_data];
+        }
+
+        if game.verify_on_submit {
+            Self::verify_player_proof(&env, session_id, &mut game, is_player1)?;
+
+            // Only the verified feedback is kept; the proof bytes are dropped
+            let stored = if is_player1 {
+                &mut game.player1_proof
+            } else {
+                &mut game.player2_proof
+            };
+            let mut verified = stored.get_unchecked(0);
+            verified.proof = Bytes::new(&env);
+            stored.set(0, verified);
+
+            // A proof that fails verification loses the game on the spot
+            let is_fraud = if is_player1 {
+                game.p1_is_fraud
+            } else {
+                game.p2_is_fraud
+            };
+            if is_fraud {
+                let p1_guess = game
+                    .player1_last_guess
+                    .ok_or(Error::BothPlayersNotGuessed)?;
+                let p2_guess = game
+                    .player2_last_guess
+                    .ok_or(Error::BothPlayersNotGuessed)?;
+                Self::record_round(&env, session_id, &game, p1_guess, p2_guess);
+
+                let (winner, outcome) = if is_player1 {
+                    (game.player2.clone(), GameOutcome::Player2Won)
+                } else {
+                    (game.player1.clone(), GameOutcome::Player1Won)
+                };
+                game.status = GameStatus::Winner;
+                game.winner = Some(winner);
+                env.storage().temporary().set(&key, &game);
+
+                game_common::hub_client(&env).end_game(&session_id, &outcome);
+                return Ok(());
+            }
+        }
+
+        if !game.player1_proof.is_empty() && !game.player2_proof.is_empty() {
+            if game.verify_on_submit {
+                // Both proofs are already verified: resolve the round right away
+                let p1_guess = game
+                    .player1_last_guess
+                    .ok_or(Error::BothPlayersNotGuessed)?;
+                let p2_guess = game
+                    .player2_last_guess
+                    .ok_or(Error::BothPlayersNotGuessed)?;
+                Self::finish_round(&env, session_id, &mut game, p1_guess, p2_guess);
+                return Ok(());
+            }
+            game.round_phase = RoundPhase::Verification;
         }
 
         env.storage().temporary().set(&key, &game);
-        env.storage()
-            .temporary()
-            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+        game_common::extend_game_ttl(&env, &key);
+        extend_vk_ttl(&env, &game.vk_hash);
 
         Ok(())
     }
@@ -354,6 +643,10 @@ impl PassContract {
             return Err(Error::InvalidStatus);
         }
 
+        if game.round_phase != RoundPhase::Verification {
+            return Err(Error::NotVerificationPhase);
+        }
+
         let p1_guess = game
             .player1_last_guess
             .ok_or(Error::BothPlayersNotGuessed)?;
@@ -361,183 +654,447 @@ impl PassContract {
             .player2_last_guess
             .ok_or(Error::BothPlayersNotGuessed)?;
 
-        // VERIFICA APENAS A PROVA DE QUEM CHAMOU A TRANSAÇÃO
+        // VERIFICA APENAS A PROVA DO OPONENTE DE QUEM CHAMOU A TRANSAÇÃO
         if caller == game.player1 && !game.p2_proof_verified {
-            let p2_proof = game.player2_proof.get(0).ok_or(Error::InvalidStatus)?;
-            let p2_secret = game
-                .player2_secret_hash
-                .clone()
-                .ok_or(Error::InvalidStatus)?;
-
-            // Player 1 prova seu segredo contra o palpite do Player 2
-            let is_valid = Self::verify_zk_proof_internal(&env, &p2_proof, &p2_secret, p1_guess);
-            if !is_valid {
-                // Fraude detectada. Player 2 ganha automaticamente.
-                game.p2_is_fraud = true;
-                env.storage().temporary().set(&key, &game);
-            }
-            // Marca a prova 1 como verificada
-            game.p2_proof_verified = true;
-            env.storage().temporary().set(&key, &game);
+            Self::verify_player_proof(&env, session_id, &mut game, false)?;
         } else if caller == game.player2 && !game.p1_proof_verified {
-            let p1_proof = game.player1_proof.get(0).ok_or(Error::InvalidStatus)?;
-            let p1_secret = game
-                .player1_secret_hash
-                .clone()
-                .ok_or(Error::InvalidStatus)?;
+            Self::verify_player_proof(&env, session_id, &mut game, true)?;
+        }
+
+        if game.p1_proof_verified && game.p2_proof_verified {
+            let results = Self::finish_round(&env, session_id, &mut game, p1_guess, p2_guess);
+            return Ok(Some(results));
+        }
 
-            // Player 2 prova seu segredo contra o palpite do Player 1
-            let is_valid = Self::verify_zk_proof_internal(&env, &p1_proof, &p1_secret, p2_guess);
+        env.storage().temporary().set(&key, &game);
 
-            if !is_valid {
-                game.p1_is_fraud = true;
-                env.storage().temporary().set(&key, &game);
-            }
-            // Marca a prova 2 como verificada
-            game.p1_proof_verified = true;
-            env.storage().temporary().set(&key, &game);
+        // Se chegou aqui, apenas uma das provas foi verificada (ou P1 ou P2).
+        // Retornamos um erro "benigno" indicando que falta a verificação do oponente.
+        // O frontend pode ignorar esse erro especificamente.
+        Ok(None)
+    }
+
+    /// Verify both stored proofs of the current round and resolve it in a
+    /// single call. Anyone can call it once both proofs are in; `verify_proof`
+    /// remains for splitting the verification cost across two transactions.
+    /// If a resolver reward is configured and the contract holds enough of
+    /// the reward token, the submitter is paid it, but only for a staked game
+    /// in which at least one of the proofs verified: a zero-stake game between
+    /// two addresses one person controls, or a round of junk proofs, earns nothing.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `submitter` - Address submitting the transaction, paid any reward
+    pub fn resolve_round(
+        env: Env,
+        session_id: u32,
+        submitter: Address,
+    ) -> Result<(GameResult, GameResult), Error> {
+        submitter.require_auth();
+
+        let key = DataKey::Game(session_id);
+        let mut game = Self::load_active_game(&env, &key)?;
+
+        if game.status != GameStatus::Playing || game.round_phase != RoundPhase::Verification {
+            return Err(Error::NotVerificationPhase);
         }
 
-        if game.p1_proof_verified && game.p2_proof_verified {
-            if game.p1_is_fraud && game.p2_is_fraud {
-                game.status = GameStatus::Draw;
-                game.winner = None;
-            } else if game.p1_is_fraud {
-                game.status = GameStatus::Winner;
-                game.winner = Some(game.player2.clone());
-            } else if game.p2_is_fraud {
-                game.status = GameStatus::Winner;
-                game.winner = Some(game.player1.clone());
+        let results = Self::verify_and_finish_round(&env, session_id, &mut game)?;
+
+        let staked = game.player1_points > 0 && game.player2_points > 0;
+        let any_valid = !game.p1_is_fraud || !game.p2_is_fraud;
+        if !staked || !any_valid {
+            return Ok(results);
+        }
+
+        if let Some(reward) = Self::get_resolver_reward(env.clone()) {
+            let token = token::Client::new(&env, &reward.token);
+            let contract = env.current_contract_address();
+            if reward.amount > 0 && token.balance(&contract) >= reward.amount {
+                token.transfer(&contract, &submitter, &reward.amount);
             }
+        }
+
+        Ok(results)
+    }
 
-            let p1_proof = game.player1_proof.get(0).unwrap();
-            let p2_proof = game.player2_proof.get(0).unwrap();
+    /// Claim the win after the current phase's deadline has passed.
+    /// The claimant must have completed every action the phase asks of them,
+    /// while the opponent still owes one. If both players stalled, neither can
+    /// claim and the session is left for the Game Hub to expire.
+    /// Once both proofs of a round are in nobody is stalled, so the call
+    /// verifies them and resolves the round instead, like `resolve_round`.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - The responsive player claiming the win
+    pub fn claim_timeout(env: Env, session_id: u32, player: Address) -> Result<(), Error> {
+        player.require_auth();
 
-            let p2_guessed_correctly = p1_proof.acertos == 3;
-            let p1_guessed_correctly = p2_proof.acertos == 3;
+        let key = DataKey::Game(session_id);
+        let mut game = Self::load_active_game(&env, &key)?;
 
-            let result_p1 = GameResult {
-                player: game.player1.clone(),
-                acertos: p2_proof.acertos,
-                erros: p2_proof.erros,
-                permutados: p2_proof.permutados,
-            };
+        let is_player1 = if player == game.player1 {
+            true
+        } else if player == game.player2 {
+            false
+        } else {
+            return Err(Error::NotPlayer);
+        };
+
+        if env.ledger().sequence() <= game.phase_deadline {
+            return Err(Error::DeadlineNotReached);
+        }
+
+        // Both proofs are in, so nobody is stalled: settle the round instead,
+        // as `resolve_round` would, and let the proofs decide the outcome
+        if game.status == GameStatus::Playing && game.round_phase == RoundPhase::Verification {
+            Self::verify_and_finish_round(&env, session_id, &mut game)?;
+            return Ok(());
+        }
 
-            let result_p2 = GameResult {
-                player: game.player2.clone(),
-                acertos: p1_proof.acertos,
-                erros: p1_proof.erros,
-                permutados: p1_proof.permutados,
+        // A proof submitted but not yet verified is checked before it counts
+        if game.status == GameStatus::Playing && game.round_phase == RoundPhase::ProofPhase {
+            let (proof, verified) = if is_player1 {
+                (&game.player1_proof, game.p1_proof_verified)
+            } else {
+                (&game.player2_proof, game.p2_proof_verified)
             };
+            if !proof.is_empty() && !verified {
+                Self::verify_player_proof(&env, session_id, &mut game, is_player1)?;
+            }
+        }
+
+        if is_stalled(&game, is_player1) {
+            return Err(Error::PlayerStalled);
+        }
+        if !is_stalled(&game, !is_player1) {
+            return Err(Error::OpponentNotStalled);
+        }
+
+        game.status = GameStatus::Winner;
+        game.winner = Some(player);
+        env.storage().temporary().set(&key, &game);
+
+        let outcome = if is_player1 {
+            GameOutcome::Player1Won
+        } else {
+            GameOutcome::Player2Won
+        };
+        game_common::hub_client(&env).end_game(&session_id, &outcome);
+
+        Ok(())
+    }
+
+    /// Resign the game, handing the win to the opponent.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - The resigning player
+    pub fn resign(env: Env, session_id: u32, player: Address) -> Result<(), Error> {
+        player.require_auth();
+
+        let key = DataKey::Game(session_id);
+        let mut game = Self::load_active_game(&env, &key)?;
+
+        let (winner, outcome) = if player == game.player1 {
+            (game.player2.clone(), GameOutcome::Player2Won)
+        } else if player == game.player2 {
+            (game.player1.clone(), GameOutcome::Player1Won)
+        } else {
+            return Err(Error::NotPlayer);
+        };
+
+        game.status = GameStatus::Winner;
+        game.winner = Some(winner);
+        env.storage().temporary().set(&key, &game);
+
+        game_common::hub_client(&env).end_game(&session_id, &outcome);
+
+        Ok(())
+    }
+
+    /// Offer the opponent a draw. The offer stands until the opponent accepts
+    /// it or the game ends; offering again replaces the opponent's own offer.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - The player offering the draw
+    pub fn offer_draw(env: Env, session_id: u32, player: Address) -> Result<(), Error> {
+        player.require_auth();
+
+        let key = DataKey::Game(session_id);
+        let mut game = Self::load_active_game(&env, &key)?;
+
+        if player != game.player1 && player != game.player2 {
+            return Err(Error::NotPlayer);
+        }
+
+        game.draw_offer = Some(player);
+        env.storage().temporary().set(&key, &game);
+
+        Ok(())
+    }
+
+    /// Accept the opponent's draw offer, ending the game as a draw.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - The player accepting the draw
+    pub fn accept_draw(env: Env, session_id: u32, player: Address) -> Result<(), Error> {
+        player.require_auth();
+
+        let key = DataKey::Game(session_id);
+        let mut game = Self::load_active_game(&env, &key)?;
+
+        let opponent = if player == game.player1 {
+            game.player2.clone()
+        } else if player == game.player2 {
+            game.player1.clone()
+        } else {
+            return Err(Error::NotPlayer);
+        };
+
+        if game.draw_offer != Some(opponent) {
+            return Err(Error::NoDrawOffer);
+        }
+
+        game.status = GameStatus::Draw;
+        game.winner = None;
+        env.storage().temporary().set(&key, &game);
+
+        game_common::hub_client(&env).end_game(&session_id, &GameOutcome::Draw);
+
+        Ok(())
+    }
+
+    /// Load a game that is still in `Setup` or `Playing`
+    fn load_active_game(env: &Env, key: &DataKey) -> Result<Game, Error> {
+        let game: Game = env
+            .storage()
+            .temporary()
+            .get(key)
+            .ok_or(Error::GameNotFound)?;
+
+        if game.status != GameStatus::Setup && game.status != GameStatus::Playing {
+            return Err(Error::GameAlreadyEnded);
+        }
+
+        Ok(game)
+    }
+
+    /// Verify one player's stored proof, which answers the opponent's guess
+    /// with the player's secret, and record whether it was a fraud
+    fn verify_player_proof(
+        env: &Env,
+        session_id: u32,
+        game: &mut Game,
+        of_player1: bool,
+    ) -> Result<(), Error> {
+        let (proof, secret, opponent_guess) = if of_player1 {
+            (&game.player1_proof, &game.player1_secret_hash, game.player2_last_guess)
+        } else {
+            (&game.player2_proof, &game.player2_secret_hash, game.player1_last_guess)
+        };
+        let proof = proof.get(0).ok_or(Error::InvalidStatus)?;
+        let secret = secret.clone().ok_or(Error::InvalidStatus)?;
+        let opponent_guess = opponent_guess.ok_or(Error::BothPlayersNotGuessed)?;
+
+        let is_valid = Self::verify_zk_proof_internal(
+            env,
+            &game.vk_hash,
+            session_id,
+            game.round,
+            &proof,
+            &secret,
+            opponent_guess,
+        );
+
+        // Uma prova inválida é fraude: o oponente ganha automaticamente
+        if of_player1 {
+            game.p1_is_fraud = !is_valid;
+            game.p1_proof_verified = true;
+        } else {
+            game.p2_is_fraud = !is_valid;
+            game.p2_proof_verified = true;
+        }
+
+        Ok(())
+    }
+
+    /// Verify whichever of the round's proofs are still unchecked, then
+    /// resolve the round
+    fn verify_and_finish_round(
+        env: &Env,
+        session_id: u32,
+        game: &mut Game,
+    ) -> Result<(GameResult, GameResult), Error> {
+        let p1_guess = game
+            .player1_last_guess
+            .ok_or(Error::BothPlayersNotGuessed)?;
+        let p2_guess = game
+            .player2_last_guess
+            .ok_or(Error::BothPlayersNotGuessed)?;
+
+        if !game.p1_proof_verified {
+            Self::verify_player_proof(env, session_id, game, true)?;
+        }
+        if !game.p2_proof_verified {
+            Self::verify_player_proof(env, session_id, game, false)?;
+        }
+
+        Ok(Self::finish_round(env, session_id, game, p1_guess, p2_guess))
+    }
+
+    /// Resolve a round once both proofs are verified: record it in the history,
+    /// then either end the game through the Game Hub or start the next round.
+    fn finish_round(
+        env: &Env,
+        session_id: u32,
+        game: &mut Game,
+        p1_guess: u32,
+        p2_guess: u32,
+    ) -> (GameResult, GameResult) {
+        let key = DataKey::Game(session_id);
+
+        if game.p1_is_fraud && game.p2_is_fraud {
+            game.status = GameStatus::Draw;
+            game.winner = None;
+        } else if game.p1_is_fraud {
+            game.status = GameStatus::Winner;
+            game.winner = Some(game.player2.clone());
+        } else if game.p2_is_fraud {
+            game.status = GameStatus::Winner;
+            game.winner = Some(game.player1.clone());
+        }
+
+        // Keep the round in the history before the next one resets the game
+        let (result_p1, result_p2) = Self::record_round(env, session_id, game, p1_guess, p2_guess);
+
+        let p1_guessed_correctly = result_p1.acertos == 3;
+        let p2_guessed_correctly = result_p2.acertos == 3;
 
-            if !game.p1_is_fraud && !game.p2_is_fraud {
-                match (p1_guessed_correctly, p2_guessed_correctly) {
-                    (true, true) => {
-                        game.status = GameStatus::Draw;
-                        game.winner = None;
-                    }
-                    (true, false) => {
-                        game.status = GameStatus::Winner;
-                        game.winner = Some(game.player1.clone());
-                    }
-                    (false, true) => {
-                        game.status = GameStatus::Winner;
-                        game.winner = Some(game.player2.clone());
-                    }
-                    (false, false) => {
-                        game.status = GameStatus::Playing;
-                        // Prepara para o próximo round
-                        game.player1_proof = vec![&env];
-                        game.player2_proof = vec![&env];
-                        game.player1_last_guess = None;
-                        game.player2_last_guess = None;
-                        game.p1_proof_verified = false; // Reseta as flags!
-                        game.p2_proof_verified = false;
-                    }
+        if !game.p1_is_fraud && !game.p2_is_fraud {
+            match (p1_guessed_correctly, p2_guessed_correctly) {
+                (true, true) => {
+                    game.status = GameStatus::Draw;
+                    game.winner = None;
+                }
+                (true, false) => {
+                    game.status = GameStatus::Winner;
+                    game.winner = Some(game.player1.clone());
+                }
+                (false, true) => {
+                    game.status = GameStatus::Winner;
+                    game.winner = Some(game.player2.clone());
+                }
+                (false, false) => {
+                    game.status = GameStatus::Playing;
+                    // Prepara para o próximo round
+                    game.player1_proof = vec![env];
+                    game.player2_proof = vec![env];
+                    game.player1_last_guess = None;
+                    game.player2_last_guess = None;
+                    game.p1_proof_verified = false; // Reseta as flags!
+                    game.p2_proof_verified = false;
+                    game.phase_deadline = game_common::action_deadline(env);
+                    game.round += 1;
+                    game.round_phase = RoundPhase::GuessPhase;
                 }
             }
+        }
 
-            game.player1_result = vec![&env, result_p1.clone()];
-            game.player2_result = vec![&env, result_p2.clone()];
+        game.player1_result = vec![env, result_p1.clone()];
+        game.player2_result = vec![env, result_p2.clone()];
 
-            env.storage().temporary().set(&key, &game);
-            env.storage()
-                .temporary()
-                .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
-
-            if game.status == GameStatus::Draw || game.status == GameStatus::Winner {
-                let hub_addr: Address = env
-                    .storage()
-                    .instance()
-                    .get(&DataKey::GameHubAddress)
-                    .unwrap();
-                let hub = GameHubClient::new(&env, &hub_addr);
-                let player1_won = game.winner.as_ref().map_or(false, |w| w == &game.player1);
-                hub.end_game(&session_id, &player1_won);
-            }
+        env.storage().temporary().set(&key, game);
+        game_common::extend_game_ttl(env, &key);
+        extend_vk_ttl(env, &game.vk_hash);
 
-            return Ok(Some((result_p1, result_p2)));
+        if game.status == GameStatus::Draw || game.status == GameStatus::Winner {
+            let hub = game_common::hub_client(env);
+            let outcome = match &game.winner {
+                None => GameOutcome::Draw,
+                Some(w) if w == &game.player1 => GameOutcome::Player1Won,
+                Some(_) => GameOutcome::Player2Won,
+            };
+            hub.end_game(&session_id, &outcome);
         }
 
-        // Se chegou aqui, apenas uma das provas foi verificada (ou P1 ou P2).
-        // Retornamos um erro "benigno" indicando que falta a verificação do oponente.
-        // O frontend pode ignorar esse erro especificamente.
-        Ok(None)
+        (result_p1, result_p2)
+    }
+
+    /// Write the current round to the history and return each player's
+    /// feedback, as claimed in the opponent's proof. A proof that was never
+    /// submitted, when a fraud ends the round early, counts as no feedback.
+    fn record_round(
+        env: &Env,
+        session_id: u32,
+        game: &Game,
+        p1_guess: u32,
+        p2_guess: u32,
+    ) -> (GameResult, GameResult) {
+        let feedback = |player: &Address, proof: Option<ProofData>| match proof {
+            Some(proof) => GameResult {
+                player: player.clone(),
+                acertos: proof.acertos,
+                erros: proof.erros,
+                permutados: proof.permutados,
+            },
+            None => GameResult {
+                player: player.clone(),
+                acertos: 0,
+                erros: 0,
+                permutados: 0,
+            },
+        };
+        let result_p1 = feedback(&game.player1, game.player2_proof.get(0));
+        let result_p2 = feedback(&game.player2, game.player1_proof.get(0));
+
+        let round_key = DataKey::Round(session_id, game.round);
+        let record = RoundRecord {
+            round: game.round,
+            player1_guess: p1_guess,
+            player2_guess: p2_guess,
+            player1_result: result_p1.clone(),
+            player2_result: result_p2.clone(),
+            p1_is_fraud: game.p1_is_fraud,
+            p2_is_fraud: game.p2_is_fraud,
+            ledger: env.ledger().sequence(),
+        };
+        env.storage().temporary().set(&round_key, &record);
+        game_common::extend_game_ttl(env, &round_key);
+
+        (result_p1, result_p2)
     }
 
     // Renamed to avoid conflicts and made internal-only (not a contract endpoint)
     fn verify_zk_proof_internal(
         env: &Env,
+        vk_hash: &BytesN<32>,
+        session_id: u32,
+        round: u32,
         proof_data: &ProofData,
         secret_hash: &BytesN<32>,
         opponent_guess: u32,
     ) -> bool {
         let vk: Bytes = env
             .storage()
-            .instance()
-            .get(&DataKey::VerificationKey)
+            .persistent()
+            .get(&DataKey::Vk(vk_hash.clone()))
             .expect("VK not set");
 
-        let d1 = (opponent_guess / 100) % 10;
-        let d2 = (opponent_guess / 10) % 10;
-        let d3 = opponent_guess % 10;
-
-        let mut full_inputs = [0u8; 224];
-
-        let guess_digits = [d1, d2, d3];
-        for i in 0..3 {
-            let offset = i * 32;
-            let b = guess_digits[i].to_be_bytes();
-            full_inputs[offset + 28] = b[0];
-            full_inputs[offset + 29] = b[1];
-            full_inputs[offset + 30] = b[2];
-            full_inputs[offset + 31] = b[3];
-        }
-
-        let hash_bytes = secret_hash.to_array();
-        for i in 0..32 {
-            full_inputs[96 + i] = hash_bytes[i];
-        }
-
-        let results = [proof_data.acertos, proof_data.permutados, proof_data.erros];
-        for i in 0..3 {
-            let offset = (4 + i) * 32;
-            let b = results[i].to_be_bytes();
-            full_inputs[offset + 28] = b[0];
-            full_inputs[offset + 29] = b[1];
-            full_inputs[offset + 30] = b[2];
-            full_inputs[offset + 31] = b[3];
-        }
-
+        let full_inputs = build_public_inputs(
+            session_id,
+            round,
+            &player_hash(env, &proof_data.player).to_array(),
+            opponent_guess,
+            &secret_hash.to_array(),
+            proof_data,
+        );
         let public_inputs_bytes = Bytes::from_array(env, &full_inputs);
 
-        match UltraHonkVerifier::new(env, &vk) {
-            Ok(verifier) => {
-                let result = verifier.verify(&proof_data.proof, &public_inputs_bytes);
-                result.is_ok()
-            }
-            Err(_) => false,
-        }
+        check_proof(env, &vk, &proof_data.proof, &public_inputs_bytes)
     }
 
     pub fn has_game_ended(env: Env, session_id: u32) -> Result<Option<Address>, Error> {
@@ -587,6 +1144,34 @@ impl PassContract {
         }
     }
 
+    /// Every resolved round of a game, oldest first
+    pub fn get_round_history(env: Env, session_id: u32) -> Result<Vec<RoundRecord>, Error> {
+        let game: Game = env
+            .storage()
+            .temporary()
+            .get(&DataKey::Game(session_id))
+            .ok_or(Error::GameNotFound)?;
+
+        let mut history = Vec::new(&env);
+        for round in 1..=game.round {
+            if let Some(record) = env
+                .storage()
+                .temporary()
+                .get(&DataKey::Round(session_id, round))
+            {
+                history.push_back(record);
+            }
+        }
+
+        Ok(history)
+    }
+
+    /// The address hash a player binds into their proofs as the `player`
+    /// public input
+    pub fn get_player_hash(env: Env, player: Address) -> BytesN<32> {
+        player_hash(&env, &player)
+    }
+
     pub fn get_game(env: Env, session_id: u32) -> Result<Game, Error> {
         let key = DataKey::Game(session_id);
         env.storage()
@@ -600,66 +1185,100 @@ impl PassContract {
     // ========================================================================
 
     pub fn get_admin(env: Env) -> Address {
-        env.storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set")
+        game_common::get_admin(&env)
     }
 
     pub fn set_admin(env: Env, new_admin: Address) {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
-
-        env.storage().instance().set(&DataKey::Admin, &new_admin);
+        game_common::set_admin(&env, &new_admin);
     }
 
     pub fn get_hub(env: Env) -> Address {
-        env.storage()
-            .instance()
-            .get(&DataKey::GameHubAddress)
-            .expect("GameHub address not set")
+        game_common::get_hub(&env)
     }
 
     pub fn set_hub(env: Env, new_hub: Address) {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
+        game_common::set_hub(&env, &new_hub);
+    }
+
+    /// Get the number of ledgers players have to complete each phase: the
+    /// secret setup, then every round
+    pub fn get_action_timeout(env: Env) -> u32 {
+        game_common::get_action_timeout(&env)
+    }
+
+    /// Set the action timeout for new phases (games in progress keep their deadline)
+    pub fn set_action_timeout(env: Env, ledgers: u32) {
+        game_common::set_action_timeout(&env, ledgers);
+    }
 
+    /// Register a verification key and make it the one new games are pinned
+    /// to. Keys live in persistent storage under their `sha256` hash, so games
+    /// already in progress keep verifying against the key they started with.
+    ///
+    /// # Returns
+    /// The hash the key is registered under
+    pub fn register_vk(env: Env, vk: Bytes) -> BytesN<32> {
+        game_common::require_admin(&env);
+
+        let vk_hash: BytesN<32> = env.crypto().sha256(&vk).into();
         env.storage()
-            .instance()
-            .set(&DataKey::GameHubAddress, &new_hub);
+            .persistent()
+            .set(&DataKey::Vk(vk_hash.clone()), &vk);
+        extend_vk_ttl(&env, &vk_hash);
+
+        env.storage().instance().set(&DataKey::ActiveVk, &vk_hash);
+        // Drop the key an older version kept in instance storage
+        env.storage().instance().remove(&DataKey::VerificationKey);
+
+        vk_hash
     }
 
-    pub fn set_verification_key(env: Env, vk: Bytes) {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
+    /// A registered verification key by hash
+    pub fn get_vk(env: Env, vk_hash: BytesN<32>) -> Option<Bytes> {
+        env.storage().persistent().get(&DataKey::Vk(vk_hash))
+    }
 
-        env.storage().instance().set(&DataKey::VerificationKey, &vk);
+    /// Hash of the verification key new games are pinned to
+    pub fn get_active_vk_hash(env: Env) -> Option<BytesN<32>> {
+        env.storage().instance().get(&DataKey::ActiveVk)
     }
 
-    pub fn get_verification_key(env: Env) -> Option<Bytes> {
-        env.storage().instance().get(&DataKey::VerificationKey)
+    /// Set the reward paid to `resolve_round` submitters, or `None` to pay
+    /// nothing. The contract must be funded with the reward token; rounds
+    /// still resolve, unrewarded, once it runs out.
+    pub fn set_resolver_reward(env: Env, reward: Option<ResolverReward>) {
+        game_common::require_admin(&env);
+
+        match reward {
+            Some(reward) => env.storage().instance().set(&DataKey::ResolverReward, &reward),
+            None => env.storage().instance().remove(&DataKey::ResolverReward),
+        }
     }
 
-    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
-        let admin: Address = env
-            .storage()
+    pub fn get_resolver_reward(env: Env) -> Option<ResolverReward> {
+        env.storage().instance().get(&DataKey::ResolverReward)
+    }
+
+    /// Choose whether games started from now on verify each proof as it is
+    /// submitted, storing only the verified feedback instead of the proof.
+    /// Games already started keep the mode they were started with.
+    pub fn set_verify_on_submit(env: Env, enabled: bool) {
+        game_common::require_admin(&env);
+
+        env.storage().instance().set(&DataKey::VerifyOnSubmit, &enabled);
+    }
+
+    pub fn get_verify_on_submit(env: Env) -> bool {
+        env.storage()
             .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
+            .get(&DataKey::VerifyOnSubmit)
+            .unwrap_or(false)
+    }
 
-        env.deployer().update_current_contract_wasm(new_wasm_hash);
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
+        game_common::upgrade(&env, new_wasm_hash);
     }
 }
+
+#[cfg(test)]
+mod test;
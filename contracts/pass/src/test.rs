@@ -2,9 +2,13 @@
 
 // Unit tests for the Mastermind contract logic.
 
-use crate::{Error, GameStatus, PassContract, PassContractClient};
+use crate::{
+    build_public_inputs, Error, GameOutcome, GameStatus, PassContract, PassContractClient,
+    ProofData, ResolverReward, RoundPhase, PUBLIC_INPUTS_LEN,
+};
 use soroban_sdk::testutils::{Address as _, Ledger as _};
-use soroban_sdk::{contract, contractimpl, vec, Address, Bytes, BytesN, Env};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{contract, contractimpl, Address, Bytes, BytesN, Env};
 
 extern crate std;
 use std::println;
@@ -30,8 +34,13 @@ impl MockGameHub {
         // Mock implementation
     }
 
-    pub fn end_game(_env: Env, _session_id: u32, _player1_won: bool) {
-        // Mock implementation
+    pub fn end_game(env: Env, session_id: u32, outcome: GameOutcome) {
+        // Record the outcome so tests can check what the game reported
+        env.storage().temporary().set(&session_id, &outcome);
+    }
+
+    pub fn get_outcome(env: Env, session_id: u32) -> Option<GameOutcome> {
+        env.storage().temporary().get(&session_id)
     }
 
     pub fn add_game(_env: Env, _game_address: Address) {
@@ -73,8 +82,9 @@ fn setup_test() -> (
     let admin = Address::generate(&env);
 
     // Deploy contract
-    let contract_id = env.register(PassContract, (&admin, &hub_addr));
+    let contract_id = env.register(PassContract, ());
     let client = PassContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &hub_addr);
 
     game_hub.add_game(&contract_id);
 
@@ -82,18 +92,23 @@ fn setup_test() -> (
     let player2 = Address::generate(&env);
 
     // Set a dummy Verification Key
-    client.set_verification_key(&Bytes::new(&env));
+    client.register_vk(&Bytes::new(&env));
 
     (env, client, game_hub, player1, player2)
 }
 
+/// A proof the test verifier accepts; `Bytes::new` is always a fraud
+fn valid_proof(env: &Env) -> Bytes {
+    Bytes::from_array(env, &[1u8; 32])
+}
+
 // ============================================================================
 // Mastermind Game Flow Tests
 // ============================================================================
 
 #[test]
 fn test_mastermind_flow() {
-    let (env, client, _hub, player1, player2) = setup_test();
+    let (env, client, hub, player1, player2) = setup_test();
     let session_id = 1u32;
     let points = 100_0000000;
 
@@ -113,30 +128,32 @@ fn test_mastermind_flow() {
     let game_playing = client.get_game(&session_id);
     assert_eq!(game_playing.status, GameStatus::Playing);
 
-    // 3. Submit Guesses (optional in lib.rs logic for verify_proof, but good for flow)
-    client.submit_guess(&session_id, &player1, &2232);
-    client.submit_guess(&session_id, &player2, &9999);
+    // 3. Submit Guesses
+    client.submit_guess(&session_id, &player1, &123);
+    client.submit_guess(&session_id, &player2, &456);
 
-    // 4. Submit Proofs
-    // Player 1 got it right (acertos = 4)
-    client.submit_proof(&session_id, &player1, &4, &0, &0, &Bytes::new(&env));
-    // Player 2 got it wrong
-    client.submit_proof(&session_id, &player2, &1, &2, &1, &Bytes::new(&env));
+    // 4. Submit Proofs, each answering the opponent's guess
+    // Player 1's secret shares one digit with player 2's guess
+    client.submit_proof(&session_id, &player1, &1, &0, &2, &valid_proof(&env));
+    // Player 1 got it right
+    client.submit_proof(&session_id, &player2, &3, &0, &0, &valid_proof(&env));
 
-    // 5. Verify Proof and End Game
-    let (res_p1, res_p2) = client.verify_proof(&session_id);
+    // 5. Each player verifies the opponent's proof; the second ends the game
+    assert_eq!(client.verify_proof(&session_id, &player1), None);
+    let (res_p1, res_p2) = client.verify_proof(&session_id, &player2).unwrap();
 
-    assert_eq!(res_p1.acertos, 4);
+    assert_eq!(res_p1.acertos, 3);
     assert_eq!(res_p2.acertos, 1);
 
     let final_game = client.get_game(&session_id);
     assert_eq!(final_game.status, GameStatus::Winner);
     assert_eq!(final_game.winner, Some(player1));
+    assert_eq!(hub.get_outcome(&session_id), Some(GameOutcome::Player1Won));
 }
 
 #[test]
 fn test_mastermind_multi_round_flow() {
-    let (env, client, _hub, player1, player2) = setup_test();
+    let (env, client, hub, player1, player2) = setup_test();
     let session_id = 1u32;
     let points = 100_0000000;
 
@@ -148,42 +165,51 @@ fn test_mastermind_multi_round_flow() {
 
     println!("\n--- FASE 1: RODADA DE ERRO ---\n");
 
-    // Both players submit wrong proofs
-    client.submit_proof(&session_id, &player1, &1, &3, &0, &Bytes::new(&env));
-    client.submit_proof(&session_id, &player2, &2, &2, &0, &Bytes::new(&env));
+    // Both players guess wrong
+    client.submit_guess(&session_id, &player1, &123);
+    client.submit_guess(&session_id, &player2, &456);
+    client.submit_proof(&session_id, &player1, &2, &0, &1, &valid_proof(&env));
+    client.submit_proof(&session_id, &player2, &1, &2, &0, &valid_proof(&env));
 
-    let (res_p1, res_p2) = client.verify_proof(&session_id);
+    client.verify_proof(&session_id, &player1);
+    let (res_p1, res_p2) = client.verify_proof(&session_id, &player2).unwrap();
     assert_eq!(res_p1.acertos, 1);
     assert_eq!(res_p2.acertos, 2);
 
-    // Status should still be Playing because no one got acertos == 4
+    // Status should still be Playing because no one got acertos == 3
     let game_after_round1 = client.get_game(&session_id);
     assert_eq!(game_after_round1.status, GameStatus::Playing);
-    // Proofs should be reset
+    assert_eq!(game_after_round1.round, 2);
+    // Guesses and proofs should be reset
+    assert!(game_after_round1.player1_last_guess.is_none());
     assert!(game_after_round1.player1_proof.is_empty());
     assert!(game_after_round1.player2_proof.is_empty());
 
     println!("\n--- FASE 2: RODADA DE VITORIA (PLAYER 2) ---\n");
 
-    // Player 1 remains wrong
-    client.submit_proof(&session_id, &player1, &0, &4, &0, &Bytes::new(&env));
+    client.submit_guess(&session_id, &player1, &789);
+    client.submit_guess(&session_id, &player2, &111);
     // Player 2 hits it!
-    client.submit_proof(&session_id, &player2, &4, &0, &0, &Bytes::new(&env));
+    client.submit_proof(&session_id, &player1, &3, &0, &0, &valid_proof(&env));
+    // Player 1 remains wrong
+    client.submit_proof(&session_id, &player2, &0, &3, &0, &valid_proof(&env));
 
-    let (res_p1_v2, res_p2_v2) = client.verify_proof(&session_id);
+    client.verify_proof(&session_id, &player1);
+    let (res_p1_v2, res_p2_v2) = client.verify_proof(&session_id, &player2).unwrap();
     assert_eq!(res_p1_v2.acertos, 0);
-    assert_eq!(res_p2_v2.acertos, 4);
+    assert_eq!(res_p2_v2.acertos, 3);
 
     let final_game = client.get_game(&session_id);
     assert_eq!(final_game.status, GameStatus::Winner);
     assert_eq!(final_game.winner, Some(player2.clone()));
+    assert_eq!(hub.get_outcome(&session_id), Some(GameOutcome::Player2Won));
 
     println!("\n--- TESTE CONCLUIDO COM SUCESSO ---\n");
 }
 
 #[test]
 fn test_draw_scenario() {
-    let (env, client, _hub, player1, player2) = setup_test();
+    let (env, client, hub, player1, player2) = setup_test();
     let session_id = 5u32;
 
     client.start_game(&session_id, &player1, &player2, &100, &100);
@@ -191,21 +217,26 @@ fn test_draw_scenario() {
     client.register_secret(&session_id, &player2, &BytesN::from_array(&env, &[2u8; 32]));
 
     // Both players hit it in the same round
-    client.submit_proof(&session_id, &player1, &4, &0, &0, &Bytes::new(&env));
-    client.submit_proof(&session_id, &player2, &4, &0, &0, &Bytes::new(&env));
+    client.submit_guess(&session_id, &player1, &123);
+    client.submit_guess(&session_id, &player2, &456);
+    client.submit_proof(&session_id, &player1, &3, &0, &0, &valid_proof(&env));
+    client.submit_proof(&session_id, &player2, &3, &0, &0, &valid_proof(&env));
 
-    let (res_p1, res_p2) = client.verify_proof(&session_id);
-    assert_eq!(res_p1.acertos, 4);
-    assert_eq!(res_p2.acertos, 4);
+    client.verify_proof(&session_id, &player1);
+    let (res_p1, res_p2) = client.verify_proof(&session_id, &player2).unwrap();
+    assert_eq!(res_p1.acertos, 3);
+    assert_eq!(res_p2.acertos, 3);
 
     let game = client.get_game(&session_id);
     assert_eq!(game.status, GameStatus::Draw);
     assert!(game.winner.is_none());
+    // A draw refunds both stakes instead of handing player 2 the session
+    assert_eq!(hub.get_outcome(&session_id), Some(GameOutcome::Draw));
 }
 
 #[test]
 fn test_cannot_play_without_secrets() {
-    let (env, client, _hub, player1, player2) = setup_test();
+    let (_env, client, _hub, player1, player2) = setup_test();
     let session_id = 2u32;
 
     client.start_game(&session_id, &player1, &player2, &100, &100);
@@ -232,3 +263,717 @@ fn test_cannot_register_twice() {
         _ => panic!("Expected SecretAlreadyRegistered error"),
     }
 }
+
+// ============================================================================
+// Timeout Tests
+// ============================================================================
+
+#[test]
+fn test_claim_timeout_during_setup() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let session_id = 10u32;
+
+    client.start_game(&session_id, &player1, &player2, &100, &100);
+    client.register_secret(&session_id, &player1, &BytesN::from_array(&env, &[1u8; 32]));
+
+    // Player 2 still has time to register
+    let result = client.try_claim_timeout(&session_id, &player1);
+    assert_eq!(result, Err(Ok(Error::DeadlineNotReached)));
+
+    let deadline = client.get_game(&session_id).phase_deadline;
+    env.ledger().set_sequence_number(deadline + 1);
+
+    // The stalled player can't claim against the responsive one
+    let result = client.try_claim_timeout(&session_id, &player2);
+    assert_eq!(result, Err(Ok(Error::PlayerStalled)));
+
+    client.claim_timeout(&session_id, &player1);
+
+    let game = client.get_game(&session_id);
+    assert_eq!(game.status, GameStatus::Winner);
+    assert_eq!(game.winner, Some(player1.clone()));
+    assert_eq!(client.has_game_ended(&session_id), Some(player1));
+}
+
+#[test]
+fn test_phase_deadlines_use_action_timeout() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    client.set_action_timeout(&10);
+    assert_eq!(client.get_action_timeout(), 10);
+
+    let session_id = 12u32;
+    env.ledger().set_sequence_number(100);
+    client.start_game(&session_id, &player1, &player2, &100, &100);
+    assert_eq!(client.get_game(&session_id).phase_deadline, 110);
+
+    // Each phase gets a fresh deadline when it starts
+    env.ledger().set_sequence_number(105);
+    client.register_secret(&session_id, &player1, &BytesN::from_array(&env, &[1u8; 32]));
+    client.register_secret(&session_id, &player2, &BytesN::from_array(&env, &[2u8; 32]));
+    assert_eq!(client.get_game(&session_id).phase_deadline, 115);
+}
+
+#[test]
+fn test_claim_timeout_during_round() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let session_id = 11u32;
+
+    client.start_game(&session_id, &player1, &player2, &100, &100);
+    client.register_secret(&session_id, &player1, &BytesN::from_array(&env, &[1u8; 32]));
+    client.register_secret(&session_id, &player2, &BytesN::from_array(&env, &[2u8; 32]));
+
+    // Both guess, but only player 2 answers with a proof
+    client.submit_guess(&session_id, &player1, &123);
+    client.submit_guess(&session_id, &player2, &456);
+    client.submit_proof(&session_id, &player2, &0, &1, &2, &valid_proof(&env));
+
+    let deadline = client.get_game(&session_id).phase_deadline;
+    env.ledger().set_sequence_number(deadline + 1);
+
+    // Player 1 still owes their proof (and the verification of player 2's)
+    let result = client.try_claim_timeout(&session_id, &player1);
+    assert_eq!(result, Err(Ok(Error::PlayerStalled)));
+
+    client.claim_timeout(&session_id, &player2);
+
+    let game = client.get_game(&session_id);
+    assert_eq!(game.status, GameStatus::Winner);
+    assert_eq!(game.winner, Some(player2));
+
+    let result = client.try_claim_timeout(&session_id, &player1);
+    assert_eq!(result, Err(Ok(Error::GameAlreadyEnded)));
+}
+
+#[test]
+fn test_fraudulent_proof_cannot_claim_timeout() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let session_id = 13u32;
+
+    client.start_game(&session_id, &player1, &player2, &100, &100);
+    client.register_secret(&session_id, &player1, &BytesN::from_array(&env, &[1u8; 32]));
+    client.register_secret(&session_id, &player2, &BytesN::from_array(&env, &[2u8; 32]));
+    client.submit_guess(&session_id, &player1, &123);
+    client.submit_guess(&session_id, &player2, &456);
+
+    // Player 2's proof fails verification, so it doesn't count as acting
+    client.submit_proof(&session_id, &player2, &0, &1, &2, &Bytes::new(&env));
+
+    let deadline = client.get_game(&session_id).phase_deadline;
+    env.ledger().set_sequence_number(deadline + 1);
+
+    let result = client.try_claim_timeout(&session_id, &player2);
+    assert_eq!(result, Err(Ok(Error::PlayerStalled)));
+    assert_eq!(client.get_game(&session_id).status, GameStatus::Playing);
+}
+
+#[test]
+fn test_claim_timeout_needs_stalled_opponent() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let session_id = 12u32;
+
+    client.start_game(&session_id, &player1, &player2, &100, &100);
+    client.register_secret(&session_id, &player1, &BytesN::from_array(&env, &[1u8; 32]));
+    client.register_secret(&session_id, &player2, &BytesN::from_array(&env, &[2u8; 32]));
+
+    // The round deadline starts once both secrets are in
+    let deadline = client.get_game(&session_id).phase_deadline;
+    env.ledger().set_sequence_number(deadline + 1);
+
+    // Neither player has guessed: nobody can claim
+    let result = client.try_claim_timeout(&session_id, &player1);
+    assert_eq!(result, Err(Ok(Error::PlayerStalled)));
+
+    client.submit_guess(&session_id, &player1, &123);
+    client.submit_guess(&session_id, &player2, &456);
+    client.submit_proof(&session_id, &player1, &0, &1, &2, &valid_proof(&env));
+    client.submit_proof(&session_id, &player2, &0, &1, &2, &valid_proof(&env));
+
+    // Both proofs are in: the claim resolves the round instead of winning it
+    client.claim_timeout(&session_id, &player1);
+
+    let game = client.get_game(&session_id);
+    assert_eq!(game.status, GameStatus::Playing);
+    assert_eq!(game.round, 2);
+    assert!(game.winner.is_none());
+}
+
+#[test]
+fn test_claim_timeout_in_verification_checks_own_proof() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let session_id = 14u32;
+
+    client.start_game(&session_id, &player1, &player2, &100, &100);
+    client.register_secret(&session_id, &player1, &BytesN::from_array(&env, &[1u8; 32]));
+    client.register_secret(&session_id, &player2, &BytesN::from_array(&env, &[2u8; 32]));
+    client.submit_guess(&session_id, &player1, &123);
+    client.submit_guess(&session_id, &player2, &456);
+    client.submit_proof(&session_id, &player1, &0, &1, &2, &Bytes::new(&env));
+    client.submit_proof(&session_id, &player2, &0, &1, &2, &valid_proof(&env));
+
+    // Player 1 verified player 2's proof, but their own is a fraud
+    client.verify_proof(&session_id, &player1);
+    let deadline = client.get_game(&session_id).phase_deadline;
+    env.ledger().set_sequence_number(deadline + 1);
+
+    client.claim_timeout(&session_id, &player1);
+
+    let game = client.get_game(&session_id);
+    assert!(game.p1_is_fraud);
+    assert_eq!(game.status, GameStatus::Winner);
+    assert_eq!(game.winner, Some(player2));
+}
+
+// ============================================================================
+// Resign and Draw Tests
+// ============================================================================
+
+#[test]
+fn test_resign_hands_win_to_opponent() {
+    let (env, client, hub, player1, player2) = setup_test();
+    let session_id = 20u32;
+
+    client.start_game(&session_id, &player1, &player2, &100, &100);
+    client.register_secret(&session_id, &player1, &BytesN::from_array(&env, &[1u8; 32]));
+
+    client.resign(&session_id, &player1);
+
+    let game = client.get_game(&session_id);
+    assert_eq!(game.status, GameStatus::Winner);
+    assert_eq!(game.winner, Some(player2));
+    assert_eq!(hub.get_outcome(&session_id), Some(GameOutcome::Player2Won));
+
+    let result = client.try_resign(&session_id, &player1);
+    assert_eq!(result, Err(Ok(Error::GameAlreadyEnded)));
+}
+
+#[test]
+fn test_agreed_draw_ends_game() {
+    let (env, client, hub, player1, player2) = setup_test();
+    let session_id = 21u32;
+
+    client.start_game(&session_id, &player1, &player2, &100, &100);
+    client.register_secret(&session_id, &player1, &BytesN::from_array(&env, &[1u8; 32]));
+    client.register_secret(&session_id, &player2, &BytesN::from_array(&env, &[2u8; 32]));
+
+    // Nothing to accept yet, and a player can't accept their own offer
+    let result = client.try_accept_draw(&session_id, &player1);
+    assert_eq!(result, Err(Ok(Error::NoDrawOffer)));
+    client.offer_draw(&session_id, &player2);
+    let result = client.try_accept_draw(&session_id, &player2);
+    assert_eq!(result, Err(Ok(Error::NoDrawOffer)));
+
+    client.accept_draw(&session_id, &player1);
+
+    let game = client.get_game(&session_id);
+    assert_eq!(game.status, GameStatus::Draw);
+    assert!(game.winner.is_none());
+    assert_eq!(hub.get_outcome(&session_id), Some(GameOutcome::Draw));
+    assert_eq!(client.has_game_ended(&session_id), None);
+
+    let result = client.try_offer_draw(&session_id, &player1);
+    assert_eq!(result, Err(Ok(Error::GameAlreadyEnded)));
+}
+
+// ============================================================================
+// Proof Binding Tests
+// ============================================================================
+
+#[test]
+fn test_player_hash_is_field_sized_and_per_player() {
+    let (_env, client, _hub, player1, player2) = setup_test();
+
+    let hash1 = client.get_player_hash(&player1);
+    let hash2 = client.get_player_hash(&player2);
+    assert_ne!(hash1, hash2);
+
+    // The top byte is cleared so the hash fits in a circuit field
+    assert_eq!(hash1.to_array()[0], 0);
+    assert_eq!(hash2.to_array()[0], 0);
+    assert_eq!(client.get_player_hash(&player1), hash1);
+
+    client.start_game(&30u32, &player1, &player2, &100, &100);
+    assert_eq!(client.get_game(&30u32).round, 1);
+}
+
+/// Feedback a player claims on the opponent's guess, for the public input tests
+fn proof_data(env: &Env, player: &Address) -> ProofData {
+    ProofData {
+        player: player.clone(),
+        acertos: 1,
+        permutados: 2,
+        erros: 0,
+        proof: valid_proof(env),
+    }
+}
+
+#[test]
+fn test_public_inputs_layout() {
+    let env = Env::default();
+    let player = Address::generate(&env);
+    let mut player_hash = [0x11u8; 32];
+    player_hash[0] = 0;
+
+    let inputs = build_public_inputs(
+        0x0102_0304,
+        2,
+        &player_hash,
+        123,
+        &[0xab; 32],
+        &proof_data(&env, &player),
+    );
+
+    // Ten 32-byte fields, numbers big-endian in the low bytes
+    let mut expected = [0u8; PUBLIC_INPUTS_LEN];
+    expected[31] = 1;
+    expected[63] = 2;
+    expected[95] = 3;
+    expected[96..128].copy_from_slice(&[0xab; 32]);
+    expected[159] = 1;
+    expected[191] = 2;
+    expected[223] = 0;
+    expected[252..256].copy_from_slice(&[1, 2, 3, 4]);
+    expected[287] = 2;
+    expected[288..320].copy_from_slice(&player_hash);
+    assert_eq!(inputs, expected);
+}
+
+#[test]
+fn test_public_inputs_bind_session_round_and_player() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let hash1 = client.get_player_hash(&player1).to_array();
+    let hash2 = client.get_player_hash(&player2).to_array();
+    let proof = proof_data(&env, &player1);
+    let secret_hash = [7u8; 32];
+
+    let inputs = build_public_inputs(5, 1, &hash1, 123, &secret_hash, &proof);
+    assert_eq!(build_public_inputs(5, 1, &hash1, 123, &secret_hash, &proof), inputs);
+
+    // The same proof can't stand for another session, round or player
+    assert_ne!(build_public_inputs(6, 1, &hash1, 123, &secret_hash, &proof), inputs);
+    assert_ne!(build_public_inputs(5, 2, &hash1, 123, &secret_hash, &proof), inputs);
+    assert_ne!(build_public_inputs(5, 1, &hash2, 123, &secret_hash, &proof), inputs);
+}
+
+// ============================================================================
+// Round History Tests
+// ============================================================================
+
+#[test]
+fn test_round_history_records_resolved_rounds() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let session_id = 40u32;
+
+    client.start_game(&session_id, &player1, &player2, &100, &100);
+    client.register_secret(&session_id, &player1, &BytesN::from_array(&env, &[1u8; 32]));
+    client.register_secret(&session_id, &player2, &BytesN::from_array(&env, &[2u8; 32]));
+    assert!(client.get_round_history(&session_id).is_empty());
+
+    // Round 1: two honest proofs, nobody guesses the secret
+    client.submit_guess(&session_id, &player1, &123);
+    client.submit_guess(&session_id, &player2, &456);
+    client.submit_proof(&session_id, &player1, &0, &1, &2, &valid_proof(&env));
+    client.submit_proof(&session_id, &player2, &1, &0, &2, &valid_proof(&env));
+
+    env.ledger().set_sequence_number(150);
+    client.verify_proof(&session_id, &player1);
+    client.verify_proof(&session_id, &player2);
+
+    // Round 2: player 1 answers with a fraudulent proof
+    client.submit_guess(&session_id, &player1, &789);
+    client.submit_guess(&session_id, &player2, &111);
+    client.submit_proof(&session_id, &player1, &0, &0, &3, &Bytes::new(&env));
+    client.submit_proof(&session_id, &player2, &0, &2, &1, &valid_proof(&env));
+
+    env.ledger().set_sequence_number(160);
+    client.verify_proof(&session_id, &player1);
+    client.verify_proof(&session_id, &player2);
+
+    let history = client.get_round_history(&session_id);
+    assert_eq!(history.len(), 2);
+
+    let record = history.get(0).unwrap();
+    assert_eq!(record.round, 1);
+    assert_eq!((record.player1_guess, record.player2_guess), (123, 456));
+    // Each player's result is the feedback the opponent claimed on their guess
+    assert_eq!(record.player1_result.acertos, 1);
+    assert_eq!(record.player2_result.permutados, 1);
+    assert_eq!((record.p1_is_fraud, record.p2_is_fraud), (false, false));
+    assert_eq!(record.ledger, 150);
+
+    let record = history.get(1).unwrap();
+    assert_eq!(record.round, 2);
+    assert_eq!((record.player1_guess, record.player2_guess), (789, 111));
+    assert_eq!(record.player1_result.permutados, 2);
+    assert_eq!(record.player2_result.erros, 3);
+    assert_eq!((record.p1_is_fraud, record.p2_is_fraud), (true, false));
+    assert_eq!(record.ledger, 160);
+
+    let game = client.get_game(&session_id);
+    assert_eq!(game.winner, Some(player2));
+}
+
+#[test]
+fn test_round_history_of_missing_game() {
+    let (_env, client, _hub, _player1, _player2) = setup_test();
+
+    let result = client.try_get_round_history(&41u32);
+    assert_eq!(result, Err(Ok(Error::GameNotFound)));
+}
+
+// ============================================================================
+// Round Phase Tests
+// ============================================================================
+
+#[test]
+fn test_guesses_lock_once_both_are_in() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let session_id = 50u32;
+
+    client.start_game(&session_id, &player1, &player2, &100, &100);
+    client.register_secret(&session_id, &player1, &BytesN::from_array(&env, &[1u8; 32]));
+    client.register_secret(&session_id, &player2, &BytesN::from_array(&env, &[2u8; 32]));
+    assert_eq!(client.get_game(&session_id).round_phase, RoundPhase::GuessPhase);
+
+    // No proofs before the guesses are in
+    let result = client.try_submit_proof(&session_id, &player2, &0, &1, &2, &Bytes::new(&env));
+    assert_eq!(result, Err(Ok(Error::NotProofPhase)));
+
+    // A guess can be changed until the opponent's is in
+    client.submit_guess(&session_id, &player1, &123);
+    client.submit_guess(&session_id, &player1, &124);
+    client.submit_guess(&session_id, &player2, &456);
+
+    let game = client.get_game(&session_id);
+    assert_eq!(game.round_phase, RoundPhase::ProofPhase);
+    assert_eq!(game.player1_last_guess, Some(124));
+
+    let result = client.try_submit_guess(&session_id, &player1, &789);
+    assert_eq!(result, Err(Ok(Error::NotGuessPhase)));
+    assert_eq!(client.get_game(&session_id).player1_last_guess, Some(124));
+}
+
+#[test]
+fn test_verification_waits_for_both_proofs() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let session_id = 51u32;
+
+    client.start_game(&session_id, &player1, &player2, &100, &100);
+    client.register_secret(&session_id, &player1, &BytesN::from_array(&env, &[1u8; 32]));
+    client.register_secret(&session_id, &player2, &BytesN::from_array(&env, &[2u8; 32]));
+    client.submit_guess(&session_id, &player1, &123);
+    client.submit_guess(&session_id, &player2, &456);
+    client.submit_proof(&session_id, &player1, &0, &1, &2, &Bytes::new(&env));
+
+    let result = client.try_verify_proof(&session_id, &player2);
+    assert_eq!(result, Err(Ok(Error::NotVerificationPhase)));
+
+    client.submit_proof(&session_id, &player2, &0, &1, &2, &Bytes::new(&env));
+    assert_eq!(client.get_game(&session_id).round_phase, RoundPhase::Verification);
+
+    // The proofs are locked in too
+    let result = client.try_submit_proof(&session_id, &player1, &1, &1, &1, &Bytes::new(&env));
+    assert_eq!(result, Err(Ok(Error::NotProofPhase)));
+    let result = client.try_submit_guess(&session_id, &player2, &789);
+    assert_eq!(result, Err(Ok(Error::NotGuessPhase)));
+}
+
+// ============================================================================
+// Round Resolution Tests
+// ============================================================================
+
+/// Start a game and play its first round up to `Verification`
+fn play_to_verification(
+    env: &Env,
+    client: &PassContractClient,
+    session_id: u32,
+    player1: &Address,
+    player2: &Address,
+) {
+    client.start_game(&session_id, player1, player2, &100, &100);
+    client.register_secret(&session_id, player1, &BytesN::from_array(env, &[1u8; 32]));
+    client.register_secret(&session_id, player2, &BytesN::from_array(env, &[2u8; 32]));
+    client.submit_guess(&session_id, player1, &123);
+    client.submit_guess(&session_id, player2, &456);
+    client.submit_proof(&session_id, player1, &0, &1, &2, &valid_proof(env));
+    client.submit_proof(&session_id, player2, &0, &1, &2, &valid_proof(env));
+}
+
+/// Fund the contract with a resolver reward of 50 and return the reward token
+fn fund_resolver_reward(env: &Env, client: &PassContractClient) -> TokenClient<'static> {
+    let token_id = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    StellarAssetClient::new(env, &token_id).mint(&client.address, &500);
+    client.set_resolver_reward(&Some(ResolverReward { token: token_id.clone(), amount: 50 }));
+    TokenClient::new(env, &token_id)
+}
+
+#[test]
+fn test_resolve_round_verifies_both_proofs_and_pays_reward() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let session_id = 60u32;
+
+    let token_id = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    StellarAssetClient::new(&env, &token_id).mint(&client.address, &500);
+    let reward = ResolverReward { token: token_id.clone(), amount: 50 };
+    client.set_resolver_reward(&Some(reward.clone()));
+    assert_eq!(client.get_resolver_reward(), Some(reward));
+
+    play_to_verification(&env, &client, session_id, &player1, &player2);
+
+    // Anyone can resolve the round, in a single call
+    let resolver = Address::generate(&env);
+    let (result_p1, result_p2) = client.resolve_round(&session_id, &resolver);
+    assert_eq!(result_p1.player, player1);
+    assert_eq!(result_p2.player, player2);
+
+    // Both honest proofs verified and nobody guessed right: on to round 2
+    let history = client.get_round_history(&session_id);
+    assert_eq!(history.len(), 1);
+    assert!(!history.get_unchecked(0).p1_is_fraud && !history.get_unchecked(0).p2_is_fraud);
+    assert_eq!(client.get_game(&session_id).round, 2);
+
+    let token = TokenClient::new(&env, &token_id);
+    assert_eq!(token.balance(&resolver), 50);
+    assert_eq!(token.balance(&client.address), 450);
+}
+
+#[test]
+fn test_resolve_round_needs_both_proofs() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let session_id = 61u32;
+    let resolver = Address::generate(&env);
+
+    client.start_game(&session_id, &player1, &player2, &100, &100);
+    let result = client.try_resolve_round(&session_id, &resolver);
+    assert_eq!(result, Err(Ok(Error::NotVerificationPhase)));
+
+    let result = client.try_resolve_round(&62u32, &resolver);
+    assert_eq!(result, Err(Ok(Error::GameNotFound)));
+}
+
+#[test]
+fn test_resolve_round_after_one_verification_without_funds() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let session_id = 63u32;
+
+    // A reward the contract can't pay doesn't block resolution
+    let token_id = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.set_resolver_reward(&Some(ResolverReward { token: token_id.clone(), amount: 50 }));
+
+    play_to_verification(&env, &client, session_id, &player1, &player2);
+
+    // Player 1 verifies player 2's proof; the resolver finishes the round
+    assert_eq!(client.verify_proof(&session_id, &player1), None);
+    let resolver = Address::generate(&env);
+    client.resolve_round(&session_id, &resolver);
+
+    assert_eq!(client.get_round_history(&session_id).len(), 1);
+    assert_eq!(client.get_game(&session_id).round, 2);
+    assert_eq!(TokenClient::new(&env, &token_id).balance(&resolver), 0);
+
+    client.set_resolver_reward(&None);
+    assert_eq!(client.get_resolver_reward(), None);
+}
+
+#[test]
+fn test_resolve_round_reward_not_paid_for_self_play() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let token = fund_resolver_reward(&env, &client);
+    let resolver = Address::generate(&env);
+
+    // One person playing both sides for nothing, with junk proofs
+    let session_id = 64u32;
+    client.start_game(&session_id, &player1, &player2, &0, &0);
+    client.register_secret(&session_id, &player1, &BytesN::from_array(&env, &[1u8; 32]));
+    client.register_secret(&session_id, &player2, &BytesN::from_array(&env, &[2u8; 32]));
+    client.submit_guess(&session_id, &player1, &123);
+    client.submit_guess(&session_id, &player2, &456);
+    client.submit_proof(&session_id, &player1, &0, &1, &2, &Bytes::new(&env));
+    client.submit_proof(&session_id, &player2, &0, &1, &2, &Bytes::new(&env));
+    client.resolve_round(&session_id, &resolver);
+    assert_eq!(client.get_game(&session_id).status, GameStatus::Draw);
+    assert_eq!(token.balance(&resolver), 0);
+
+    // Honest proofs don't earn a reward without stakes either
+    let session_id = 65u32;
+    client.start_game(&session_id, &player1, &player2, &0, &0);
+    client.register_secret(&session_id, &player1, &BytesN::from_array(&env, &[1u8; 32]));
+    client.register_secret(&session_id, &player2, &BytesN::from_array(&env, &[2u8; 32]));
+    client.submit_guess(&session_id, &player1, &123);
+    client.submit_guess(&session_id, &player2, &456);
+    client.submit_proof(&session_id, &player1, &0, &1, &2, &valid_proof(&env));
+    client.submit_proof(&session_id, &player2, &0, &1, &2, &valid_proof(&env));
+    client.resolve_round(&session_id, &resolver);
+    assert_eq!(token.balance(&resolver), 0);
+    assert_eq!(token.balance(&client.address), 500);
+}
+
+#[test]
+fn test_resolve_round_reward_not_paid_when_both_proofs_fail() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let token = fund_resolver_reward(&env, &client);
+    let session_id = 66u32;
+
+    client.start_game(&session_id, &player1, &player2, &100, &100);
+    client.register_secret(&session_id, &player1, &BytesN::from_array(&env, &[1u8; 32]));
+    client.register_secret(&session_id, &player2, &BytesN::from_array(&env, &[2u8; 32]));
+    client.submit_guess(&session_id, &player1, &123);
+    client.submit_guess(&session_id, &player2, &456);
+    client.submit_proof(&session_id, &player1, &0, &1, &2, &Bytes::new(&env));
+    client.submit_proof(&session_id, &player2, &0, &1, &2, &Bytes::new(&env));
+
+    let resolver = Address::generate(&env);
+    client.resolve_round(&session_id, &resolver);
+    assert_eq!(token.balance(&resolver), 0);
+}
+
+// ============================================================================
+// Verify-on-Submit Tests
+// ============================================================================
+
+#[test]
+fn test_verify_on_submit_stores_only_feedback() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let session_id = 70u32;
+
+    client.set_verify_on_submit(&true);
+    client.start_game(&session_id, &player1, &player2, &100, &100);
+    assert!(client.get_game(&session_id).verify_on_submit);
+
+    client.register_secret(&session_id, &player1, &BytesN::from_array(&env, &[1u8; 32]));
+    client.register_secret(&session_id, &player2, &BytesN::from_array(&env, &[2u8; 32]));
+    client.submit_guess(&session_id, &player1, &123);
+    client.submit_guess(&session_id, &player2, &456);
+
+    let proof = valid_proof(&env);
+    client.submit_proof(&session_id, &player1, &0, &1, &2, &proof);
+
+    // Verified on the spot; only the feedback is kept
+    let game = client.get_game(&session_id);
+    assert!(game.p1_proof_verified);
+    let stored = game.player1_proof.get(0).unwrap();
+    assert_eq!((stored.acertos, stored.permutados, stored.erros), (0, 1, 2));
+    assert!(stored.proof.is_empty());
+    assert_eq!(game.round_phase, RoundPhase::ProofPhase);
+
+    let result = client.try_submit_proof(&session_id, &player1, &0, &1, &2, &proof);
+    assert_eq!(result, Err(Ok(Error::ProofAlreadySubmitted)));
+
+    // The second proof resolves the round without a separate verification
+    client.submit_proof(&session_id, &player2, &1, &0, &2, &proof);
+
+    let game = client.get_game(&session_id);
+    assert_eq!(game.round, 2);
+    assert_eq!(game.round_phase, RoundPhase::GuessPhase);
+    assert_eq!(client.get_round_history(&session_id).len(), 1);
+}
+
+#[test]
+fn test_verify_on_submit_ends_game_on_fraud() {
+    let (env, client, hub, player1, player2) = setup_test();
+    let session_id = 72u32;
+
+    client.set_verify_on_submit(&true);
+    client.start_game(&session_id, &player1, &player2, &100, &100);
+    client.register_secret(&session_id, &player1, &BytesN::from_array(&env, &[1u8; 32]));
+    client.register_secret(&session_id, &player2, &BytesN::from_array(&env, &[2u8; 32]));
+    client.submit_guess(&session_id, &player1, &123);
+    client.submit_guess(&session_id, &player2, &456);
+
+    // The fraud is caught on submission; player 2 doesn't need to answer
+    client.submit_proof(&session_id, &player1, &0, &1, &2, &Bytes::new(&env));
+
+    let game = client.get_game(&session_id);
+    assert!(game.p1_is_fraud);
+    assert_eq!(game.status, GameStatus::Winner);
+    assert_eq!(game.winner, Some(player2.clone()));
+    assert_eq!(hub.get_outcome(&session_id), Some(GameOutcome::Player2Won));
+
+    // The round is still recorded; player 2 never had to submit feedback
+    let history = client.get_round_history(&session_id);
+    assert_eq!(history.len(), 1);
+    let record = history.get_unchecked(0);
+    assert_eq!(record.round, 1);
+    assert_eq!(record.player1_guess, 123);
+    assert_eq!(record.player2_guess, 456);
+    assert!(record.p1_is_fraud);
+    assert!(!record.p2_is_fraud);
+    assert_eq!(record.player1_result.player, player1);
+    assert_eq!(record.player1_result.acertos, 0);
+    assert_eq!(record.player2_result.player, player2);
+    assert_eq!(record.player2_result.permutados, 1);
+    assert_eq!(record.player2_result.erros, 2);
+}
+
+#[test]
+fn test_verify_on_submit_is_pinned_at_start() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let session_id = 71u32;
+
+    assert!(!client.get_verify_on_submit());
+    client.start_game(&session_id, &player1, &player2, &100, &100);
+    client.set_verify_on_submit(&true);
+
+    client.register_secret(&session_id, &player1, &BytesN::from_array(&env, &[1u8; 32]));
+    client.register_secret(&session_id, &player2, &BytesN::from_array(&env, &[2u8; 32]));
+    client.submit_guess(&session_id, &player1, &123);
+    client.submit_guess(&session_id, &player2, &456);
+    client.submit_proof(&session_id, &player1, &0, &1, &2, &Bytes::from_array(&env, &[0u8; 64]));
+
+    // The game started before the switch still verifies later
+    let game = client.get_game(&session_id);
+    assert!(!game.verify_on_submit);
+    assert!(!game.p1_proof_verified);
+    assert_eq!(game.player1_proof.get(0).unwrap().proof.len(), 64);
+}
+
+// ============================================================================
+// Verification Key Registry Tests
+// ============================================================================
+
+#[test]
+fn test_games_keep_the_vk_they_started_with() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let old_hash = client.get_active_vk_hash().unwrap();
+    client.start_game(&80u32, &player1, &player2, &100, &100);
+    assert_eq!(client.get_game(&80u32).vk_hash, old_hash);
+
+    // Rotating the circuit only affects games started afterwards
+    let new_vk = Bytes::from_array(&env, &[7u8; 32]);
+    let new_hash = client.register_vk(&new_vk);
+    assert_eq!(new_hash.to_array(), env.crypto().sha256(&new_vk).to_array());
+    assert_eq!(client.get_active_vk_hash(), Some(new_hash.clone()));
+
+    client.start_game(&81u32, &player1, &player2, &100, &100);
+    assert_eq!(client.get_game(&80u32).vk_hash, old_hash);
+    assert_eq!(client.get_game(&81u32).vk_hash, new_hash);
+
+    // Both keys stay registered
+    assert_eq!(client.get_vk(&old_hash), Some(Bytes::new(&env)));
+    assert_eq!(client.get_vk(&new_hash), Some(new_vk));
+}
+
+#[test]
+fn test_start_game_requires_registered_vk() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let hub_addr = env.register(MockGameHub, ());
+    let admin = Address::generate(&env);
+    let contract_id = env.register(PassContract, ());
+    let client = PassContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &hub_addr);
+
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+
+    assert_eq!(client.get_active_vk_hash(), None);
+    let result = client.try_start_game(&82u32, &player1, &player2, &100, &100);
+    assert_eq!(result, Err(Ok(Error::VerificationKeyNotSet)));
+}
@@ -6,32 +6,56 @@
 //! Each player is dealt 2 cards and can choose to "hit" (draw another card) or "stick" (end their turn).
 //! Cards are valued 1-13 where Ace=1, 2-10=face value, Jack/Queen/King=10.
 //!
+//! **Visible Hands:**
+//! Games started with `start_game` deal every card from public game data
+//! (session, players, round, card position), so anyone can work out the cards
+//! in advance. This is deliberate: a bust ends the session through the Game
+//! Hub in the same transaction, so a card has to come out the same in
+//! simulation and submission, which rules out the ledger PRNG. Games where
+//! unpredictable cards matter should use hidden hands.
+//!
+//! **Hidden Hands:**
+//! Games started with `start_hidden_game` keep both hands secret. Each player
+//! picks a 32-byte secret and commits to `keccak256(keccak256(secret))`. Once
+//! both have committed, each reveals their share `keccak256(secret)` with
+//! `reveal_share`. The round's deal salt is the beacon seed over both shares,
+//! so it is only fixed after both commitments and neither player can pick a
+//! secret for a good hand. Each hand is dealt from its player's secret and the
+//! salt, so a player can compute their own cards, and nobody else's, as soon
+//! as both shares are in. `hit` and `stick` only count cards (at most 21 per
+//! hand); once both players have stuck, each reveals their secret with
+//! `reveal_hand`, which the contract checks against their share. Anyone can
+//! then call `reveal_winner`, which deals both hands and settles the game
+//! (busts included). A tie starts a new round, for which both players commit
+//! to fresh secrets with `commit_hand`. Commitments, shares and their
+//! deadlines are kept in a `game_common::beacon::Beacon` per round.
+//!
+//! A contract constructed with a randomness provider also requests a value
+//! from it once both players have committed for a round, and mixes it into
+//! the deal salt, so hidden hands can only be played once the provider has
+//! fulfilled the request. If it still hasn't by the round's deadline, either
+//! player can `cancel_game`, which ends the session as cancelled and refunds
+//! both stakes. A late answer restarts the deadline with the first move, so
+//! neither player is timed out for the provider's delay.
+//!
+//! Playing a hand (and, in a hidden game, revealing it) has an action deadline
+//! (the admin-configured action timeout). Once it passes, a player who has
+//! stuck (and revealed) can `claim_timeout` against an opponent who hasn't.
+//!
+//! Either player can also `resign`, or end the game as a draw with
+//! `offer_draw` and the opponent's `accept_draw`.
+//!
 //! **Game Hub Integration:**
 //! This game is Game Hub-aware and enforces all games to be played through the
 //! Game Hub contract. Games cannot be started or completed without points involvement.
 
+use game_common::beacon::{Beacon, BeaconError, SeedRng};
+pub use game_common::GameOutcome;
 use soroban_sdk::{
-    Address, Bytes, BytesN, Env, IntoVal, contract, contractclient, contracterror,
+    Address, Bytes, BytesN, Env, IntoVal, contract, contracterror,
     contractimpl, contracttype, vec
 };
 
-// Import GameHub contract interface
-// This allows us to call into the GameHub contract
-#[contractclient(name = "GameHubClient")]
-pub trait GameHub {
-    fn start_game(
-        env: Env,
-        game_id: Address,
-        session_id: u32,
-        player1: Address,
-        player2: Address,
-        player1_points: i128,
-        player2_points: i128,
-    );
-
-    fn end_game(env: Env, session_id: u32, player1_won: bool);
-}
-
 // ============================================================================
 // Errors
 // ============================================================================
@@ -51,6 +75,37 @@ pub enum Error {
     SelfPlay = 9,
     RoundOverflow = 10,
     InvalidHandData = 11,
+    SessionExists = 12,
+    NotHiddenGame = 13,
+    HandNotCommitted = 14,
+    AlreadyCommitted = 15,
+    CommitmentMismatch = 16,
+    AlreadyRevealed = 17,
+    HandsNotRevealed = 18,
+    CommitDeadlinePassed = 19,
+    RevealDeadlinePassed = 20,
+    RandomnessNotReady = 21,
+    DeadlineNotReached = 22,
+    OpponentNotStalled = 23,
+    PlayerStalled = 24,
+    NoDrawOffer = 25,
+    TooManyCards = 26,
+    RandomnessReady = 27,
+}
+
+impl From<BeaconError> for Error {
+    fn from(error: BeaconError) -> Self {
+        match error {
+            BeaconError::NotParty => Error::NotPlayer,
+            BeaconError::AlreadyCommitted => Error::AlreadyCommitted,
+            BeaconError::NotAllCommitted => Error::HandNotCommitted,
+            BeaconError::AlreadyRevealed => Error::AlreadyRevealed,
+            BeaconError::CommitmentMismatch => Error::CommitmentMismatch,
+            BeaconError::NotAllRevealed => Error::HandsNotRevealed,
+            BeaconError::CommitDeadlinePassed => Error::CommitDeadlinePassed,
+            BeaconError::RevealDeadlinePassed => Error::RevealDeadlinePassed,
+        }
+    }
 }
 
 // ============================================================================
@@ -80,27 +135,35 @@ pub struct Game {
     pub player1_stuck: bool,
     pub player2_stuck: bool,
     pub winner: Option<Address>,
+    /// Player whose draw offer is waiting for the opponent to accept
+    pub draw_offer: Option<Address>,
+    /// Set when the players agreed to a draw, ending the game without a winner
+    pub drawn: bool,
+    /// Set when a hidden game was called off because the randomness provider
+    /// never answered, refunding both stakes
+    pub cancelled: bool,
     pub round: u32,
+    /// Hidden-hand game: hands stay empty until `reveal_winner` deals them,
+    /// and the round's commitments are stored under `DataKey::Beacon`
+    pub hidden: bool,
+    /// Cards dealt so far in a hidden-hand game
+    pub player1_card_count: u32,
+    pub player2_card_count: u32,
+    /// Secret a player revealed for their hidden hand this round
+    pub player1_secret: Option<BytesN<32>>,
+    pub player2_secret: Option<BytesN<32>>,
+    /// Last ledger of the current phase: playing the hands (in a hidden game,
+    /// revealing the shares first and the secrets last)
+    pub action_deadline: u32,
 }
 
 #[contracttype]
 #[derive(Clone)]
 pub enum DataKey {
     Game(u32),
-    GameHubAddress,
-    Admin,
+    Beacon(u32),
 }
 
-// ============================================================================
-// Storage TTL Management
-// ============================================================================
-// TTL (Time To Live) ensures game data doesn't expire unexpectedly
-// Games are stored in temporary storage with a minimum 30-day retention
-
-/// TTL for game storage (30 days in ledgers, ~5 seconds per ledger)
-/// 30 days = 30 * 24 * 60 * 60 / 5 = 518,400 ledgers
-const GAME_TTL_LEDGERS: u32 = 518_400;
-
 // ============================================================================
 // Helper Functions
 // ============================================================================
@@ -127,11 +190,104 @@ fn calculate_hand_value(hand: &Bytes) -> Result<u32, Error> {
     Ok(total)
 }
 
-/// Deal a card (1-13) using deterministic PRNG
-/// The seed is passed in (as Hash from keccak256)
-fn deal_card(env: &Env, seed: BytesN<32>) -> u8 {
-    env.prng().seed(seed.into());
-    env.prng().gen_range::<u64>(1..=13) as u8
+/// Deal a visible-hand card (1-13): the first `SeedRng` draw from
+/// `keccak256(session_id || player1 || player2 || round || player_index || card_index)`.
+/// Only public data goes in, so simulation and submission deal the same card.
+fn deal_visible_card(env: &Env, session_id: u32, game: &Game, player_index: u8, card_index: u32) -> u8 {
+    let mut seed_bytes = Bytes::new(env);
+    seed_bytes.append(&Bytes::from_array(env, &session_id.to_be_bytes()));
+    seed_bytes.append(&game.player1.to_string().to_bytes());
+    seed_bytes.append(&game.player2.to_string().to_bytes());
+    seed_bytes.append(&Bytes::from_array(env, &game.round.to_be_bytes()));
+    seed_bytes.append(&Bytes::from_array(env, &[player_index]));
+    seed_bytes.append(&Bytes::from_array(env, &card_index.to_be_bytes()));
+
+    SeedRng::new(env, env.crypto().keccak256(&seed_bytes).into()).range(1..=13) as u8
+}
+
+/// Deal both visible hands of a new round, 2 cards each
+fn deal_visible_hands(env: &Env, session_id: u32, game: &mut Game) {
+    let mut player1_hand = Bytes::new(env);
+    let mut player2_hand = Bytes::new(env);
+    for card_index in 0..2 {
+        player1_hand.push_back(deal_visible_card(env, session_id, game, 1, card_index));
+        player2_hand.push_back(deal_visible_card(env, session_id, game, 2, card_index));
+    }
+    game.player1_hand = player1_hand;
+    game.player2_hand = player2_hand;
+}
+
+/// Salt the current round's hidden hands are dealt from: the beacon seed over
+/// both players' shares, mixed with the randomness provider's value for the
+/// round if the contract has one
+fn deal_salt(env: &Env, session_id: u32, game: &Game, beacon: &Beacon) -> Result<BytesN<32>, Error> {
+    game_common::mix_provider_randomness(
+        env,
+        game_common::randomness_request_id(session_id, game.round),
+        beacon.seed(env)?,
+    )
+    .ok_or(Error::RandomnessNotReady)
+}
+
+/// Deal a hidden hand of `card_count` cards from its player's secret and the
+/// round's deal salt. Draws come from `SeedRng` over
+/// `keccak256(salt || secret || session_id || round || player_index)`, so the
+/// player can replay them off-chain, while nobody else knows the secret.
+fn deal_hidden_hand(
+    env: &Env,
+    session_id: u32,
+    round: u32,
+    salt: &BytesN<32>,
+    secret: &BytesN<32>,
+    player_index: u8,
+    card_count: u32,
+) -> Bytes {
+    let mut seed_bytes = Bytes::from(salt.clone());
+    seed_bytes.append(&Bytes::from(secret.clone()));
+    seed_bytes.append(&Bytes::from_array(env, &session_id.to_be_bytes()));
+    seed_bytes.append(&Bytes::from_array(env, &round.to_be_bytes()));
+    seed_bytes.append(&Bytes::from_array(env, &[player_index]));
+
+    let mut rng = SeedRng::new(env, env.crypto().keccak256(&seed_bytes).into());
+    let mut hand = Bytes::new(env);
+    for _ in 0..card_count {
+        hand.push_back(rng.range(1..=13) as u8);
+    }
+    hand
+}
+
+/// Whether a player still owes an action: sticking, or in a hidden game
+/// committing and revealing their share first, and revealing their secret
+/// once both have stuck
+fn is_stalled(game: &Game, beacon: Option<&Beacon>, is_player1: bool) -> bool {
+    let (stuck, player, secret) = if is_player1 {
+        (game.player1_stuck, &game.player1, &game.player1_secret)
+    } else {
+        (game.player2_stuck, &game.player2, &game.player2_secret)
+    };
+    let Some(beacon) = beacon else {
+        return !stuck;
+    };
+    if beacon.awaiting(player) {
+        return true;
+    }
+    // Nothing to play until the opponent's share is in too
+    if !beacon.all_revealed() {
+        return false;
+    }
+    let both_stuck = game.player1_stuck && game.player2_stuck;
+    !stuck || (both_stuck && secret.is_none())
+}
+
+/// Outcome of two revealed hidden hands, or `None` on a tie (including both busting)
+fn hidden_outcome(player1_value: u32, player2_value: u32) -> Option<GameOutcome> {
+    match (player1_value > 21, player2_value > 21) {
+        (true, false) => Some(GameOutcome::Player2Won),
+        (false, true) => Some(GameOutcome::Player1Won),
+        (false, false) if player1_value > player2_value => Some(GameOutcome::Player1Won),
+        (false, false) if player2_value > player1_value => Some(GameOutcome::Player2Won),
+        _ => None,
+    }
 }
 
 // ============================================================================
@@ -148,12 +304,16 @@ impl TwentyOneContract {
     /// # Arguments
     /// * `admin` - Admin address (can upgrade contract)
     /// * `game_hub` - Address of the GameHub contract
-    pub fn __constructor(env: Env, admin: Address, game_hub: Address) {
+    /// * `randomness_provider` - Optional randomness contract mixed into hidden hands
+    pub fn __constructor(
+        env: Env,
+        admin: Address,
+        game_hub: Address,
+        randomness_provider: Option<Address>,
+    ) {
         // Store admin and GameHub address
-        env.storage().instance().set(&DataKey::Admin, &admin);
-        env.storage()
-            .instance()
-            .set(&DataKey::GameHubAddress, &game_hub);
+        game_common::init(&env, &admin, &game_hub);
+        game_common::init_randomness_provider(&env, &randomness_provider);
     }
 
     /// Start a new game between two players with points.
@@ -177,96 +337,211 @@ impl TwentyOneContract {
         player1_points: i128,
         player2_points: i128,
     ) -> Result<(), Error> {
-        // Prevent self-play: Player 1 and Player 2 must be different
-        if player1 == player2 {
-            return Err(Error::SelfPlay);
-        }
+        Self::check_new_game(&env, session_id, &player1, &player2)?;
 
         // Require authentication from both players (they consent to committing points)
         player1.require_auth_for_args(vec![&env, session_id.into_val(&env), player1_points.into_val(&env)]);
         player2.require_auth_for_args(vec![&env, session_id.into_val(&env), player2_points.into_val(&env)]);
 
-        // Get GameHub address
-        let game_hub_addr: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::GameHubAddress)
-            .expect("GameHub address not set");
+        Self::start_game_with_hub(&env, session_id, &player1, &player2, player1_points, player2_points);
 
-        // Create GameHub client
-        let game_hub = GameHubClient::new(&env, &game_hub_addr);
+        // Create game; the initial hands (2 cards each) are dealt below
+        let mut game = Game {
+            player1: player1.clone(),
+            player2: player2.clone(),
+            player1_points,
+            player2_points,
+            player1_hand: Bytes::new(&env),
+            player2_hand: Bytes::new(&env),
+            player1_stuck: false,
+            player2_stuck: false,
+            winner: None,
+            draw_offer: None,
+            drawn: false,
+            cancelled: false,
+            round: 1,
+            hidden: false,
+            player1_card_count: 2,
+            player2_card_count: 2,
+            player1_secret: None,
+            player2_secret: None,
+            action_deadline: game_common::action_deadline(&env),
+        };
+        deal_visible_hands(&env, session_id, &mut game);
 
-        // Call the Game Hub to start the session and lock points
-        // This requires THIS contract's authorization (env.current_contract_address())
-        game_hub.start_game(
-            &env.current_contract_address(),
-            &session_id,
-            &player1,
-            &player2,
-            &player1_points,
-            &player2_points,
-        );
+        Self::store_new_game(&env, session_id, &game);
 
-        // Generate deterministic seed for card dealing
-        // Seed components (all deterministic and identical between sim/submit):
-        // 1. Session ID - unique per game
-        // 2. Player addresses - both players contribute
-        //
-        // Note: We do NOT include ledger sequence or timestamp because those differ
-        // between simulation and submission.
-        let mut seed_bytes = Bytes::new(&env);
-        seed_bytes.append(&Bytes::from_array(&env, &session_id.to_be_bytes()));
-        seed_bytes.append(&player1.to_string().to_bytes());
-        seed_bytes.append(&player2.to_string().to_bytes());
-        let base_seed = env.crypto().keccak256(&seed_bytes);
-
-        // Deal initial hands (2 cards each)
-        // Use different seeds for each card to ensure variety
-        let mut player1_hand = Bytes::new(&env);
-        let mut player2_hand = Bytes::new(&env);
-
-        // Deal 2 cards to player1
-        for i in 0..2 {
-            let mut card_seed_bytes = Bytes::new(&env);
-            card_seed_bytes.append(&Bytes::from(base_seed.clone()));
-            card_seed_bytes.append(&Bytes::from_array(&env, &[i, 1])); // [card_index, player]
-            let card_seed = env.crypto().keccak256(&card_seed_bytes);
-            player1_hand.push_back(deal_card(&env, card_seed.into()));
-        }
-
-        // Deal 2 cards to player2
-        for i in 0..2 {
-            let mut card_seed_bytes = Bytes::new(&env);
-            card_seed_bytes.append(&Bytes::from(base_seed.clone()));
-            card_seed_bytes.append(&Bytes::from_array(&env, &[i, 2])); // [card_index, player]
-            let card_seed = env.crypto().keccak256(&card_seed_bytes);
-            player2_hand.push_back(deal_card(&env, card_seed.into()));
-        }
-
-        // Create game
+        Ok(())
+    }
+
+    /// Start a new game in which both hands stay hidden until `reveal_winner`.
+    /// Works like `start_game`, but each player commits to a secret their hand
+    /// is dealt from. The commitments are part of what each player authorizes.
+    ///
+    /// # Arguments
+    /// * `session_id` - Unique session identifier (u32)
+    /// * `player1` - Address of first player
+    /// * `player2` - Address of second player
+    /// * `player1_points` - Points amount committed by player 1
+    /// * `player2_points` - Points amount committed by player 2
+    /// * `player1_commitment` - `keccak256(keccak256(secret))` of player 1's 32-byte secret
+    /// * `player2_commitment` - `keccak256(keccak256(secret))` of player 2's 32-byte secret
+    #[allow(clippy::too_many_arguments)]
+    pub fn start_hidden_game(
+        env: Env,
+        session_id: u32,
+        player1: Address,
+        player2: Address,
+        player1_points: i128,
+        player2_points: i128,
+        player1_commitment: BytesN<32>,
+        player2_commitment: BytesN<32>,
+    ) -> Result<(), Error> {
+        Self::check_new_game(&env, session_id, &player1, &player2)?;
+
+        // Require authentication from both players, binding their own commitment
+        player1.require_auth_for_args(vec![
+            &env,
+            session_id.into_val(&env),
+            player1_points.into_val(&env),
+            player1_commitment.into_val(&env),
+        ]);
+        player2.require_auth_for_args(vec![
+            &env,
+            session_id.into_val(&env),
+            player2_points.into_val(&env),
+            player2_commitment.into_val(&env),
+        ]);
+
+        Self::start_game_with_hub(&env, session_id, &player1, &player2, player1_points, player2_points);
+
+        // Both players are committed from the start, so the share reveals open now
+        let mut beacon = Self::new_beacon(&env, &player1, &player2);
+        beacon.commit(&env, &player1, player1_commitment)?;
+        beacon.commit(&env, &player2, player2_commitment)?;
+        Self::store_beacon(&env, session_id, &beacon);
+        game_common::request_randomness(&env, game_common::randomness_request_id(session_id, 1));
+
+        // Hands are dealt from the secrets when they are revealed
         let game = Game {
-            player1: player1.clone(),
-            player2: player2.clone(),
+            player1,
+            player2,
             player1_points,
             player2_points,
-            player1_hand,
-            player2_hand,
+            player1_hand: Bytes::new(&env),
+            player2_hand: Bytes::new(&env),
             player1_stuck: false,
             player2_stuck: false,
             winner: None,
+            draw_offer: None,
+            drawn: false,
+            cancelled: false,
             round: 1,
+            hidden: true,
+            player1_card_count: 2,
+            player2_card_count: 2,
+            player1_secret: None,
+            player2_secret: None,
+            action_deadline: game_common::action_deadline(&env),
         };
 
-        // Store game in temporary storage with 30-day TTL
-        let game_key = DataKey::Game(session_id);
-        env.storage().temporary().set(&game_key, &game);
+        Self::store_new_game(&env, session_id, &game);
 
-        // Set TTL to ensure game is retained for at least 30 days
-        env.storage()
+        Ok(())
+    }
+
+    /// Commit to a fresh secret for a new round of a hidden-hand game.
+    /// Needed after a tie, since the previous secrets have been revealed.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the player committing
+    /// * `commitment` - `keccak256(keccak256(secret))` of a new 32-byte secret
+    pub fn commit_hand(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        commitment: BytesN<32>,
+    ) -> Result<(), Error> {
+        player.require_auth();
+
+        // Get game from temporary storage
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
             .temporary()
-            .extend_ttl(&game_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
 
-        // Event emitted by GameHub contract (GameStarted)
+        if !game.hidden {
+            return Err(Error::NotHiddenGame);
+        }
+
+        // Check game is still active (not won, drawn or cancelled yet)
+        if game.winner.is_some() || game.drawn || game.cancelled {
+            return Err(Error::GameAlreadyEnded);
+        }
+
+        let mut beacon = Self::load_beacon(&env, session_id)?;
+        beacon.commit(&env, &player, commitment)?;
+        Self::store_beacon(&env, session_id, &beacon);
+
+        // The last commitment opens the share reveals
+        if beacon.all_committed() {
+            game.action_deadline = game_common::action_deadline(&env);
+            env.storage().temporary().set(&key, &game);
+            game_common::request_randomness(
+                &env,
+                game_common::randomness_request_id(session_id, game.round),
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Reveal a player's share of the round's deal salt, `keccak256(secret)`.
+    /// Only allowed once both players have committed, and must match the
+    /// player's commitment. Once both shares are in, each player can compute
+    /// their own hand and play it.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the player revealing
+    /// * `share` - `keccak256(secret)`, whose hash was committed
+    pub fn reveal_share(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        share: BytesN<32>,
+    ) -> Result<(), Error> {
+        player.require_auth();
+
+        // Get game from temporary storage
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        if !game.hidden {
+            return Err(Error::NotHiddenGame);
+        }
+
+        // Check game is still active (not won, drawn or cancelled yet)
+        if game.winner.is_some() || game.drawn || game.cancelled {
+            return Err(Error::GameAlreadyEnded);
+        }
+
+        let mut beacon = Self::load_beacon(&env, session_id)?;
+        beacon.reveal(&env, &player, Bytes::from(share))?;
+        Self::store_beacon(&env, session_id, &beacon);
+
+        // The last share fixes the deal salt, and play starts
+        if beacon.all_revealed() {
+            game.action_deadline = game_common::action_deadline(&env);
+            env.storage().temporary().set(&key, &game);
+        }
 
         Ok(())
     }
@@ -288,8 +563,8 @@ impl TwentyOneContract {
             .get(&key)
             .ok_or(Error::GameNotFound)?;
 
-        // Check game is still active (no winner yet)
-        if game.winner.is_some() {
+        // Check game is still active (not won, drawn or cancelled yet)
+        if game.winner.is_some() || game.drawn || game.cancelled {
             return Err(Error::GameAlreadyEnded);
         }
 
@@ -309,22 +584,32 @@ impl TwentyOneContract {
             return Err(Error::AlreadyStuck);
         }
 
-        // Generate seed for new card based on current hand size
-        let mut seed_bytes = Bytes::new(&env);
-        seed_bytes.append(&Bytes::from_array(&env, &session_id.to_be_bytes()));
-        seed_bytes.append(&player.to_string().to_bytes());
+        // Hidden hands are only counted here; busts are settled at reveal
+        if game.hidden {
+            // A card can only be drawn once its player can know it
+            Self::check_hidden_play(&env, session_id, &mut game, &player)?;
+
+            // 21 aces already make 21, so any further card is a certain bust
+            let card_count = if is_player1 {
+                &mut game.player1_card_count
+            } else {
+                &mut game.player2_card_count
+            };
+            if *card_count >= 21 {
+                return Err(Error::TooManyCards);
+            }
+            *card_count += 1;
+            env.storage().temporary().set(&key, &game);
+            return Ok(());
+        }
 
-        let card_count = if is_player1 {
-            game.player1_hand.len()
+        // The new card's position is the current hand size
+        let (player_index, card_count) = if is_player1 {
+            (1, game.player1_hand.len())
         } else {
-            game.player2_hand.len()
+            (2, game.player2_hand.len())
         };
-
-        seed_bytes.append(&Bytes::from_array(&env, &(card_count as u32).to_be_bytes()));
-        seed_bytes.append(&Bytes::from_array(&env, &game.round.to_be_bytes()));
-
-        let card_seed = env.crypto().keccak256(&seed_bytes);
-        let new_card = deal_card(&env, card_seed.into());
+        let new_card = deal_visible_card(&env, session_id, &game, player_index, card_count);
 
         // Add card to player's hand
         if is_player1 {
@@ -335,7 +620,7 @@ impl TwentyOneContract {
             if hand_value > 21 {
                 // Player 1 busted, player 2 wins
                 // Call GameHub FIRST (before setting winner)
-                Self::end_game_with_hub(&env, session_id, false)?;
+                Self::end_game_with_hub(&env, session_id, GameOutcome::Player2Won)?;
 
                 // Only set winner AFTER GameHub succeeds
                 game.winner = Some(game.player2.clone());
@@ -352,7 +637,7 @@ impl TwentyOneContract {
             if hand_value > 21 {
                 // Player 2 busted, player 1 wins
                 // Call GameHub FIRST (before setting winner)
-                Self::end_game_with_hub(&env, session_id, true)?;
+                Self::end_game_with_hub(&env, session_id, GameOutcome::Player1Won)?;
 
                 // Only set winner AFTER GameHub succeeds
                 game.winner = Some(game.player1.clone());
@@ -386,11 +671,16 @@ impl TwentyOneContract {
             .get(&key)
             .ok_or(Error::GameNotFound)?;
 
-        // Check game is still active (no winner yet)
-        if game.winner.is_some() {
+        // Check game is still active (not won, drawn or cancelled yet)
+        if game.winner.is_some() || game.drawn || game.cancelled {
             return Err(Error::GameAlreadyEnded);
         }
 
+        // A hidden hand can't be played before its player can know it
+        if game.hidden {
+            Self::check_hidden_play(&env, session_id, &mut game, &player)?;
+        }
+
         // Mark player as stuck
         if player == game.player1 {
             if game.player1_stuck {
@@ -406,23 +696,96 @@ impl TwentyOneContract {
             return Err(Error::NotPlayer);
         }
 
+        // Once both have stuck, hidden hands get a fresh deadline to be revealed
+        if game.player1_stuck && game.player2_stuck {
+            game.action_deadline = game_common::action_deadline(&env);
+        }
+
         // Store updated game
         env.storage().temporary().set(&key, &game);
 
         Ok(())
     }
 
+    /// Reveal a hidden hand's secret once both players have stuck.
+    /// The secret must hash to the player's share. The hands are dealt
+    /// by `reveal_winner` once both secrets are revealed.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the player revealing
+    /// * `secret` - The secret whose hash is the player's share
+    pub fn reveal_hand(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        secret: BytesN<32>,
+    ) -> Result<(), Error> {
+        player.require_auth();
+
+        // Get game from temporary storage
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        if !game.hidden {
+            return Err(Error::NotHiddenGame);
+        }
+
+        // Check game is still active (not won, drawn or cancelled yet)
+        if game.winner.is_some() || game.drawn || game.cancelled {
+            return Err(Error::GameAlreadyEnded);
+        }
+
+        // Hands stay hidden until neither player can draw any more
+        if !game.player1_stuck || !game.player2_stuck {
+            return Err(Error::BothPlayersNotStuck);
+        }
+        if env.ledger().sequence() > game.action_deadline {
+            return Err(Error::RevealDeadlinePassed);
+        }
+
+        let revealed = if player == game.player1 {
+            &mut game.player1_secret
+        } else if player == game.player2 {
+            &mut game.player2_secret
+        } else {
+            return Err(Error::NotPlayer);
+        };
+        if revealed.is_some() {
+            return Err(Error::AlreadyRevealed);
+        }
+
+        // Check the secret against the player's share
+        let share = Self::load_beacon(&env, session_id)?
+            .revealed(&player)
+            .ok_or(Error::HandsNotRevealed)?;
+        if Bytes::from(env.crypto().keccak256(&Bytes::from(secret.clone()))) != share {
+            return Err(Error::CommitmentMismatch);
+        }
+        *revealed = Some(secret);
+
+        env.storage().temporary().set(&key, &game);
+
+        Ok(())
+    }
+
     /// Reveal the winner of the game and submit outcome to GameHub.
-    /// Can only be called after both players have stuck.
+    /// Can only be called after both players have stuck (and, for a hidden-hand
+    /// game, revealed their secrets, from which both hands are dealt here).
     /// This calculates hand values, determines the winner (closest to 21),
-    /// and handles draws by dealing new hands.
+    /// and handles draws by starting a new round.
     ///
     /// # Arguments
     /// * `session_id` - The session ID of the game
     ///
     /// # Returns
-    /// * `Address` - Address of the winning player
-    pub fn reveal_winner(env: Env, session_id: u32) -> Result<Address, Error> {
+    /// * `Option<Address>` - Address of the winning player, or `None` when the
+    ///   hands tied and a new round started
+    pub fn reveal_winner(env: Env, session_id: u32) -> Result<Option<Address>, Error> {
         // Get game from temporary storage
         let key = DataKey::Game(session_id);
         let mut game: Game = env
@@ -433,7 +796,10 @@ impl TwentyOneContract {
 
         // Check if game already ended (has a winner)
         if let Some(winner) = &game.winner {
-            return Ok(winner.clone());
+            return Ok(Some(winner.clone()));
+        }
+        if game.drawn || game.cancelled {
+            return Err(Error::GameAlreadyEnded);
         }
 
         // Check both players have stuck
@@ -441,6 +807,69 @@ impl TwentyOneContract {
             return Err(Error::BothPlayersNotStuck);
         }
 
+        // Hidden hands are dealt from both revealed secrets, then settled
+        // from the cards, busts included
+        if game.hidden {
+            let (Some(player1_secret), Some(player2_secret)) =
+                (&game.player1_secret, &game.player2_secret)
+            else {
+                return Err(Error::HandsNotRevealed);
+            };
+            let salt = deal_salt(&env, session_id, &game, &Self::load_beacon(&env, session_id)?)?;
+            let player1_hand = deal_hidden_hand(
+                &env,
+                session_id,
+                game.round,
+                &salt,
+                player1_secret,
+                1,
+                game.player1_card_count,
+            );
+            let player2_hand = deal_hidden_hand(
+                &env,
+                session_id,
+                game.round,
+                &salt,
+                player2_secret,
+                2,
+                game.player2_card_count,
+            );
+            let player1_value = calculate_hand_value(&player1_hand)?;
+            let player2_value = calculate_hand_value(&player2_hand)?;
+
+            // On a tie, start a new round with fresh secrets
+            let Some(outcome) = hidden_outcome(player1_value, player2_value) else {
+                game.round = game.round.checked_add(1).ok_or(Error::RoundOverflow)?;
+                game.player1_stuck = false;
+                game.player2_stuck = false;
+                game.player1_card_count = 2;
+                game.player2_card_count = 2;
+                game.player1_secret = None;
+                game.player2_secret = None;
+                game.action_deadline = game_common::action_deadline(&env);
+                let beacon = Self::new_beacon(&env, &game.player1, &game.player2);
+                Self::store_beacon(&env, session_id, &beacon);
+                env.storage().temporary().set(&key, &game);
+
+                return Ok(None);
+            };
+            let winner = if outcome == GameOutcome::Player1Won {
+                game.player1.clone()
+            } else {
+                game.player2.clone()
+            };
+
+            // Call GameHub FIRST (before setting winner)
+            Self::end_game_with_hub(&env, session_id, outcome)?;
+
+            game.player1_hand = player1_hand;
+            game.player2_hand = player2_hand;
+            game.winner = Some(winner.clone());
+            env.storage().temporary().set(&key, &game);
+
+            return Ok(Some(winner));
+        }
+
         // Calculate hand values
         let player1_value = calculate_hand_value(&game.player1_hand)?;
         let player2_value = calculate_hand_value(&game.player2_hand)?;
@@ -461,53 +890,262 @@ impl TwentyOneContract {
             game.player1_stuck = false;
             game.player2_stuck = false;
 
-            // Clear hands
-            game.player1_hand = Bytes::new(&env);
-            game.player2_hand = Bytes::new(&env);
-
-            // Deal new hands (2 cards each)
-            let mut seed_bytes = Bytes::new(&env);
-            seed_bytes.append(&Bytes::from_array(&env, &session_id.to_be_bytes()));
-            seed_bytes.append(&game.player1.to_string().to_bytes());
-            seed_bytes.append(&game.player2.to_string().to_bytes());
-            seed_bytes.append(&Bytes::from_array(&env, &game.round.to_be_bytes()));
-            let base_seed = env.crypto().keccak256(&seed_bytes);
-
-            // Deal 2 cards to player1
-            for i in 0..2 {
-                let mut card_seed_bytes = Bytes::new(&env);
-                card_seed_bytes.append(&Bytes::from(base_seed.clone()));
-                card_seed_bytes.append(&Bytes::from_array(&env, &[i, 1])); // [card_index, player]
-                let card_seed = env.crypto().keccak256(&card_seed_bytes);
-                game.player1_hand.push_back(deal_card(&env, card_seed.into()));
-            }
+            // Deal new hands (2 cards each) for the new round
+            deal_visible_hands(&env, session_id, &mut game);
 
-            // Deal 2 cards to player2
-            for i in 0..2 {
-                let mut card_seed_bytes = Bytes::new(&env);
-                card_seed_bytes.append(&Bytes::from(base_seed.clone()));
-                card_seed_bytes.append(&Bytes::from_array(&env, &[i, 2])); // [card_index, player]
-                let card_seed = env.crypto().keccak256(&card_seed_bytes);
-                game.player2_hand.push_back(deal_card(&env, card_seed.into()));
-            }
-
-            // Store updated game and return error to indicate draw
+            // Store updated game; returning an error would roll the new round back
             env.storage().temporary().set(&key, &game);
 
-            return Err(Error::Draw);
+            return Ok(None);
         };
 
         // Call GameHub FIRST (before setting winner)
-        let player1_won = winner == game.player1;
-        Self::end_game_with_hub(&env, session_id, player1_won)?;
+        let outcome = if winner == game.player1 {
+            GameOutcome::Player1Won
+        } else {
+            GameOutcome::Player2Won
+        };
+        Self::end_game_with_hub(&env, session_id, outcome)?;
 
         // Only update game with winner AFTER GameHub succeeds
         game.winner = Some(winner.clone());
         env.storage().temporary().set(&key, &game);
 
+        Ok(Some(winner))
+    }
+
+    /// Claim the win after the action deadline has passed.
+    /// The claimant must have done their part of the current phase (in a
+    /// hidden game committing, revealing their share, sticking, then revealing
+    /// their secret) while the opponent hasn't.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the player claiming the win
+    ///
+    /// # Returns
+    /// * `Address` - Address of the winning player
+    pub fn claim_timeout(env: Env, session_id: u32, player: Address) -> Result<Address, Error> {
+        player.require_auth();
+
+        // Get game from temporary storage
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        // Check game is still active (not won, drawn or cancelled yet)
+        if game.winner.is_some() || game.drawn || game.cancelled {
+            return Err(Error::GameAlreadyEnded);
+        }
+
+        let is_player1 = if player == game.player1 {
+            true
+        } else if player == game.player2 {
+            false
+        } else {
+            return Err(Error::NotPlayer);
+        };
+
+        if env.ledger().sequence() <= game.action_deadline {
+            return Err(Error::DeadlineNotReached);
+        }
+        let beacon = if game.hidden {
+            Some(Self::load_beacon(&env, session_id)?)
+        } else {
+            None
+        };
+        if is_stalled(&game, beacon.as_ref(), is_player1) {
+            return Err(Error::PlayerStalled);
+        }
+        if !is_stalled(&game, beacon.as_ref(), !is_player1) {
+            return Err(Error::OpponentNotStalled);
+        }
+
+        // Call GameHub FIRST (before setting winner)
+        let outcome = if is_player1 {
+            GameOutcome::Player1Won
+        } else {
+            GameOutcome::Player2Won
+        };
+        Self::end_game_with_hub(&env, session_id, outcome)?;
+
+        game.winner = Some(player.clone());
+        env.storage().temporary().set(&key, &game);
+
+        Ok(player)
+    }
+
+    /// Call off a hidden game whose randomness provider hasn't answered for the
+    /// current round by its deadline. The session ends as cancelled, refunding
+    /// both stakes.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the player cancelling
+    pub fn cancel_game(env: Env, session_id: u32, player: Address) -> Result<(), Error> {
+        player.require_auth();
+
+        // Get game from temporary storage
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        if !game.hidden {
+            return Err(Error::NotHiddenGame);
+        }
+
+        // Check game is still active (not won, drawn or cancelled yet)
+        if game.winner.is_some() || game.drawn || game.cancelled {
+            return Err(Error::GameAlreadyEnded);
+        }
+
+        if player != game.player1 && player != game.player2 {
+            return Err(Error::NotPlayer);
+        }
+
+        if env.ledger().sequence() <= game.action_deadline {
+            return Err(Error::DeadlineNotReached);
+        }
+
+        // Only the provider can be holding the round up; a missing share or
+        // commitment is a stalled player, settled by `claim_timeout`
+        match deal_salt(&env, session_id, &game, &Self::load_beacon(&env, session_id)?) {
+            Ok(_) => return Err(Error::RandomnessReady),
+            Err(Error::RandomnessNotReady) => {}
+            Err(error) => return Err(error),
+        }
+
+        // Call GameHub FIRST (before marking the cancellation)
+        Self::end_game_with_hub(&env, session_id, GameOutcome::Cancelled)?;
+
+        game.cancelled = true;
+        env.storage().temporary().set(&key, &game);
+
+        Ok(())
+    }
+
+    /// Resign the game, handing the win to the opponent.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the resigning player
+    ///
+    /// # Returns
+    /// * `Address` - Address of the winning player
+    pub fn resign(env: Env, session_id: u32, player: Address) -> Result<Address, Error> {
+        player.require_auth();
+
+        // Get game from temporary storage
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        // Check game is still active (not won, drawn or cancelled yet)
+        if game.winner.is_some() || game.drawn || game.cancelled {
+            return Err(Error::GameAlreadyEnded);
+        }
+
+        let (winner, outcome) = if player == game.player1 {
+            (game.player2.clone(), GameOutcome::Player2Won)
+        } else if player == game.player2 {
+            (game.player1.clone(), GameOutcome::Player1Won)
+        } else {
+            return Err(Error::NotPlayer);
+        };
+
+        // Call GameHub FIRST (before setting winner)
+        Self::end_game_with_hub(&env, session_id, outcome)?;
+
+        game.winner = Some(winner.clone());
+        env.storage().temporary().set(&key, &game);
+
         Ok(winner)
     }
 
+    /// Offer the opponent a draw. The offer stands until the opponent accepts
+    /// it or the game ends; offering again replaces the opponent's own offer.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the player offering the draw
+    pub fn offer_draw(env: Env, session_id: u32, player: Address) -> Result<(), Error> {
+        player.require_auth();
+
+        // Get game from temporary storage
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        // Check game is still active (not won, drawn or cancelled yet)
+        if game.winner.is_some() || game.drawn || game.cancelled {
+            return Err(Error::GameAlreadyEnded);
+        }
+
+        if player != game.player1 && player != game.player2 {
+            return Err(Error::NotPlayer);
+        }
+
+        game.draw_offer = Some(player);
+        env.storage().temporary().set(&key, &game);
+
+        Ok(())
+    }
+
+    /// Accept the opponent's draw offer, ending the game as a draw.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the player accepting the draw
+    pub fn accept_draw(env: Env, session_id: u32, player: Address) -> Result<(), Error> {
+        player.require_auth();
+
+        // Get game from temporary storage
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        // Check game is still active (not won, drawn or cancelled yet)
+        if game.winner.is_some() || game.drawn || game.cancelled {
+            return Err(Error::GameAlreadyEnded);
+        }
+
+        let opponent = if player == game.player1 {
+            game.player2.clone()
+        } else if player == game.player2 {
+            game.player1.clone()
+        } else {
+            return Err(Error::NotPlayer);
+        };
+
+        // Only the opponent's offer can be accepted
+        if game.draw_offer != Some(opponent) {
+            return Err(Error::NoDrawOffer);
+        }
+
+        // Call GameHub FIRST (before marking the draw)
+        Self::end_game_with_hub(&env, session_id, GameOutcome::Draw)?;
+
+        game.drawn = true;
+        env.storage().temporary().set(&key, &game);
+
+        Ok(())
+    }
+
     /// Get game information.
     ///
     /// # Arguments
@@ -539,6 +1177,14 @@ impl TwentyOneContract {
             .get(&key)
             .ok_or(Error::GameNotFound)?;
 
+        // A hidden hand has no value on-chain until it is revealed
+        if game.hidden
+            && ((player == game.player1 && game.player1_hand.is_empty())
+                || (player == game.player2 && game.player2_hand.is_empty()))
+        {
+            return Err(Error::HandsNotRevealed);
+        }
+
         if player == game.player1 {
             calculate_hand_value(&game.player1_hand)
         } else if player == game.player2 {
@@ -548,26 +1194,146 @@ impl TwentyOneContract {
         }
     }
 
+    /// Get the commitments and shares of a hidden-hand game's current round.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    ///
+    /// # Returns
+    /// * `Beacon` - The round's commitments, shares and deadlines
+    pub fn get_beacon(env: Env, session_id: u32) -> Result<Beacon, Error> {
+        Self::load_beacon(&env, session_id)
+    }
+
+    /// Get the salt the current round's hidden hands are dealt from. Each
+    /// player deals their own hand from it and their secret off-chain, the
+    /// same way `reveal_winner` does.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    ///
+    /// # Returns
+    /// * `BytesN<32>` - The round's deal salt, once both shares are revealed
+    pub fn get_deal_salt(env: Env, session_id: u32) -> Result<BytesN<32>, Error> {
+        let game: Game = env
+            .storage()
+            .temporary()
+            .get(&DataKey::Game(session_id))
+            .ok_or(Error::GameNotFound)?;
+        deal_salt(&env, session_id, &game, &Self::load_beacon(&env, session_id)?)
+    }
+
     // ========================================================================
     // Internal Helper Functions
     // ========================================================================
 
-    /// Helper to end game with the Game Hub
-    fn end_game_with_hub(env: &Env, session_id: u32, player1_won: bool) -> Result<(), Error> {
-        // Get GameHub address
-        let game_hub_addr: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::GameHubAddress)
-            .expect("GameHub address not set");
+    /// Open a beacon for a new round of a hidden-hand game
+    fn new_beacon(env: &Env, player1: &Address, player2: &Address) -> Beacon {
+        Beacon::new(
+            env,
+            vec![env, player1.clone(), player2.clone()],
+            game_common::get_action_timeout(env),
+            game_common::get_action_timeout(env),
+        )
+    }
+
+    /// Helper to read the current round's beacon of a hidden-hand game
+    fn load_beacon(env: &Env, session_id: u32) -> Result<Beacon, Error> {
+        env.storage()
+            .temporary()
+            .get(&DataKey::Beacon(session_id))
+            .ok_or(Error::NotHiddenGame)
+    }
+
+    /// Helper to store a hidden-hand game's beacon alongside the game
+    fn store_beacon(env: &Env, session_id: u32, beacon: &Beacon) {
+        let key = DataKey::Beacon(session_id);
+        env.storage().temporary().set(&key, beacon);
+        game_common::extend_game_ttl(env, &key);
+    }
 
+    /// Check a hidden hand can be played: its player committed, and can know
+    /// their cards because both shares and the provider's value are in. A
+    /// provider that answered after the deadline left the round untouched,
+    /// so its first move restarts the deadline.
+    fn check_hidden_play(env: &Env, session_id: u32, game: &mut Game, player: &Address) -> Result<(), Error> {
+        let beacon = Self::load_beacon(env, session_id)?;
+        if !beacon.is_committed(player) {
+            return Err(Error::HandNotCommitted);
+        }
+        deal_salt(env, session_id, game, &beacon)?;
+
+        let untouched = !game.player1_stuck
+            && !game.player2_stuck
+            && game.player1_card_count == 2
+            && game.player2_card_count == 2;
+        if untouched && env.ledger().sequence() > game.action_deadline {
+            game.action_deadline = game_common::action_deadline(env);
+        }
+
+        Ok(())
+    }
+
+    /// Reject self-play and session IDs that already hold a live game
+    fn check_new_game(env: &Env, session_id: u32, player1: &Address, player2: &Address) -> Result<(), Error> {
+        // Prevent self-play: Player 1 and Player 2 must be different
+        if player1 == player2 {
+            return Err(Error::SelfPlay);
+        }
+
+        // Refuse to overwrite a live game stored under the same session ID
+        if env.storage().temporary().has(&DataKey::Game(session_id)) {
+            return Err(Error::SessionExists);
+        }
+
+        Ok(())
+    }
+
+    /// Helper to start the session with the Game Hub, locking both players' points
+    fn start_game_with_hub(
+        env: &Env,
+        session_id: u32,
+        player1: &Address,
+        player2: &Address,
+        player1_points: i128,
+        player2_points: i128,
+    ) {
         // Create GameHub client
-        let game_hub = GameHubClient::new(env, &game_hub_addr);
+        let game_hub = game_common::hub_client(env);
+
+        // Call the Game Hub to start the session and lock points
+        // This requires THIS contract's authorization (env.current_contract_address())
+        game_hub.start_game(
+            &env.current_contract_address(),
+            &session_id,
+            player1,
+            player2,
+            &player1_points,
+            &player2_points,
+        );
+    }
+
+    /// Helper to store a newly started game
+    fn store_new_game(env: &Env, session_id: u32, game: &Game) {
+        // Store game in temporary storage with 30-day TTL
+        let game_key = DataKey::Game(session_id);
+        env.storage().temporary().set(&game_key, game);
+
+        // Set TTL to ensure game is retained for at least 30 days
+        game_common::extend_game_ttl(env, &game_key);
+
+        // Event emitted by GameHub contract (GameStarted)
+    }
+
+    /// Helper to end game with the Game Hub
+    fn end_game_with_hub(env: &Env, session_id: u32, outcome: GameOutcome) -> Result<(), Error> {
+        // Create GameHub client
+        let game_hub = game_common::hub_client(env);
 
         // Call the Game Hub to end the session
         // This unlocks points and updates standings
         // Event emitted by the Game Hub contract (GameEnded)
-        game_hub.end_game(&session_id, &player1_won);
+        game_hub.end_game(&session_id, &outcome);
 
         Ok(())
     }
@@ -581,10 +1347,7 @@ impl TwentyOneContract {
     /// # Returns
     /// * `Address` - The admin address
     pub fn get_admin(env: Env) -> Address {
-        env.storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set")
+        game_common::get_admin(&env)
     }
 
     /// Set a new admin address
@@ -592,14 +1355,7 @@ impl TwentyOneContract {
     /// # Arguments
     /// * `new_admin` - The new admin address
     pub fn set_admin(env: Env, new_admin: Address) {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
-
-        env.storage().instance().set(&DataKey::Admin, &new_admin);
+        game_common::set_admin(&env, &new_admin);
     }
 
     /// Get the current GameHub contract address
@@ -607,10 +1363,7 @@ impl TwentyOneContract {
     /// # Returns
     /// * `Address` - The GameHub contract address
     pub fn get_hub(env: Env) -> Address {
-        env.storage()
-            .instance()
-            .get(&DataKey::GameHubAddress)
-            .expect("GameHub address not set")
+        game_common::get_hub(&env)
     }
 
     /// Set a new GameHub contract address
@@ -618,16 +1371,31 @@ impl TwentyOneContract {
     /// # Arguments
     /// * `new_hub` - The new GameHub contract address
     pub fn set_hub(env: Env, new_hub: Address) {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
+        game_common::set_hub(&env, &new_hub);
+    }
 
-        env.storage()
-            .instance()
-            .set(&DataKey::GameHubAddress, &new_hub);
+    /// Get the randomness provider mixed into hidden hands, if any
+    ///
+    /// # Returns
+    /// * `Option<Address>` - The randomness provider contract address
+    pub fn get_randomness_provider(env: Env) -> Option<Address> {
+        game_common::get_randomness_provider(&env)
+    }
+
+    /// Get the number of ledgers players have to play, and then to reveal
+    ///
+    /// # Returns
+    /// * `u32` - The action timeout in ledgers
+    pub fn get_action_timeout(env: Env) -> u32 {
+        game_common::get_action_timeout(&env)
+    }
+
+    /// Set the action timeout for new phases (games in progress keep their deadline)
+    ///
+    /// # Arguments
+    /// * `ledgers` - The action timeout in ledgers
+    pub fn set_action_timeout(env: Env, ledgers: u32) {
+        game_common::set_action_timeout(&env, ledgers);
     }
 
     /// Update the contract WASM hash (upgrade contract)
@@ -635,14 +1403,7 @@ impl TwentyOneContract {
     /// # Arguments
     /// * `new_wasm_hash` - The hash of the new WASM binary
     pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
-
-        env.deployer().update_current_contract_wasm(new_wasm_hash);
+        game_common::upgrade(&env, new_wasm_hash);
     }
 }
 
@@ -7,7 +7,8 @@
 // For full integration tests with the real GameHub contract, see:
 // contracts/game_hub/src/tests/twenty_one_integration.rs
 
-use crate::{Error, TwentyOneContract, TwentyOneContractClient};
+use crate::{Error, GameOutcome, TwentyOneContract, TwentyOneContractClient};
+use mock_randomness::{MockRandomness, MockRandomnessClient};
 use soroban_sdk::testutils::{Address as _, Ledger as _};
 use soroban_sdk::{contract, contractimpl, Address, Bytes, BytesN, Env};
 
@@ -32,8 +33,13 @@ impl MockGameHub {
         // Mock implementation - does nothing
     }
 
-    pub fn end_game(_env: Env, _session_id: u32, _player1_won: bool) {
-        // Mock implementation - does nothing
+    pub fn end_game(env: Env, session_id: u32, outcome: GameOutcome) {
+        // Record the outcome so tests can check what the game reported
+        env.storage().temporary().set(&session_id, &outcome);
+    }
+
+    pub fn get_outcome(env: Env, session_id: u32) -> Option<GameOutcome> {
+        env.storage().temporary().get(&session_id)
     }
 
     pub fn add_game(_env: Env, _game_address: Address) {
@@ -75,7 +81,7 @@ fn setup_test() -> (
     let admin = Address::generate(&env);
 
     // Deploy twenty-one with admin and GameHub address
-    let contract_id = env.register(TwentyOneContract, (&admin, &hub_addr));
+    let contract_id = env.register(TwentyOneContract, (&admin, &hub_addr, None::<Address>));
     let client = TwentyOneContractClient::new(&env, &contract_id);
 
     // Register twenty-one as a whitelisted game (mock does nothing)
@@ -182,7 +188,7 @@ fn test_complete_game_simple() {
     client.stick(&session_id, &player2);
 
     // Reveal winner
-    let winner = client.reveal_winner(&session_id);
+    let winner = client.reveal_winner(&session_id).unwrap();
     assert!(winner == player1 || winner == player2);
 
     // Verify game is ended
@@ -307,14 +313,21 @@ fn test_multiple_hits_allowed() {
 fn test_closer_to_21_wins() {
     let (_env, client, _hub, player1, player2) = setup_test();
 
-    let session_id = 7u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    // Find a session whose hands don't tie (ties start a new round)
+    let mut settled = None;
+    for session_id in 7u32..30 {
+        client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
 
-    // Both players stick
-    client.stick(&session_id, &player1);
-    client.stick(&session_id, &player2);
+        // Both players stick
+        client.stick(&session_id, &player1);
+        client.stick(&session_id, &player2);
 
-    let winner = client.reveal_winner(&session_id);
+        if let Some(winner) = client.reveal_winner(&session_id) {
+            settled = Some((session_id, winner));
+            break;
+        }
+    }
+    let (session_id, winner) = settled.expect("Some hands should not tie");
 
     // Get final hand values
     let game = client.get_game(&session_id);
@@ -324,10 +337,9 @@ fn test_closer_to_21_wins() {
     // Winner should be closer to 21
     if player1_value > player2_value {
         assert_eq!(winner, player1);
-    } else if player2_value > player1_value {
+    } else {
         assert_eq!(winner, player2);
     }
-    // If equal, test will continue (draw handling)
 }
 
 #[test]
@@ -428,9 +440,8 @@ fn test_draw_starts_new_round() {
 
     // Only test draw behavior if hands are equal
     if player1_value == player2_value {
-        // This should return Draw error
-        let result = client.try_reveal_winner(&session_id);
-        assert_twenty_one_error(&result, Error::Draw);
+        // No winner: a new round starts instead
+        assert_eq!(client.reveal_winner(&session_id), None);
 
         // Verify new round was created
         let game_after = client.get_game(&session_id);
@@ -544,9 +555,9 @@ fn test_cannot_hit_after_game_ended() {
     client.stick(&session_id, &player1);
     client.stick(&session_id, &player2);
 
-    // Reveal winner (may panic if draw, but that's okay)
+    // Reveal winner (a tie starts a new round instead)
     let result = client.try_reveal_winner(&session_id);
-    if result.is_ok() {
+    if let Ok(Ok(Some(_))) = result {
         // Game ended successfully
         // Try to hit after game ended
         let hit_result = client.try_hit(&session_id, &player1);
@@ -592,12 +603,10 @@ fn test_reveal_winner_idempotent() {
 
     // First reveal
     let result1 = client.try_reveal_winner(&session_id);
-    if result1.is_ok() {
-        let winner1 = result1.unwrap().unwrap();
-
+    if let Ok(Ok(Some(winner1))) = result1 {
         // Second reveal should return same winner (idempotent)
         let winner2 = client.reveal_winner(&session_id);
-        assert_eq!(winner1, winner2);
+        assert_eq!(winner2, Some(winner1));
     }
 }
 
@@ -741,7 +750,7 @@ fn test_get_admin() {
     let admin = Address::generate(&env);
     let hub_addr = env.register(MockGameHub, ());
 
-    let contract_id = env.register(TwentyOneContract, (&admin, &hub_addr));
+    let contract_id = env.register(TwentyOneContract, (&admin, &hub_addr, None::<Address>));
     let client = TwentyOneContractClient::new(&env, &contract_id);
 
     let retrieved_admin = client.get_admin();
@@ -756,7 +765,7 @@ fn test_get_hub() {
     let admin = Address::generate(&env);
     let hub_addr = env.register(MockGameHub, ());
 
-    let contract_id = env.register(TwentyOneContract, (&admin, &hub_addr));
+    let contract_id = env.register(TwentyOneContract, (&admin, &hub_addr, None::<Address>));
     let client = TwentyOneContractClient::new(&env, &contract_id);
 
     let retrieved_hub = client.get_hub();
@@ -772,7 +781,7 @@ fn test_set_admin() {
     let new_admin = Address::generate(&env);
     let hub_addr = env.register(MockGameHub, ());
 
-    let contract_id = env.register(TwentyOneContract, (&admin, &hub_addr));
+    let contract_id = env.register(TwentyOneContract, (&admin, &hub_addr, None::<Address>));
     let client = TwentyOneContractClient::new(&env, &contract_id);
 
     // Set new admin
@@ -791,7 +800,7 @@ fn test_set_hub() {
     let hub_addr = env.register(MockGameHub, ());
     let new_hub_addr = Address::generate(&env);
 
-    let contract_id = env.register(TwentyOneContract, (&admin, &hub_addr));
+    let contract_id = env.register(TwentyOneContract, (&admin, &hub_addr, None::<Address>));
     let client = TwentyOneContractClient::new(&env, &contract_id);
 
     // Set new game_hub address
@@ -809,7 +818,7 @@ fn test_upgrade_function_exists() {
     let admin = Address::generate(&env);
     let hub_addr = env.register(MockGameHub, ());
 
-    let contract_id = env.register(TwentyOneContract, (&admin, &hub_addr));
+    let contract_id = env.register(TwentyOneContract, (&admin, &hub_addr, None::<Address>));
     let client = TwentyOneContractClient::new(&env, &contract_id);
 
     // Verify the upgrade function exists and can be called
@@ -846,6 +855,21 @@ fn test_deterministic_card_dealing() {
     assert_eq!(game2.player1_hand.len(), 2);
 }
 
+#[test]
+fn test_visible_cards_follow_from_public_data() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 28u32;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+
+    // Visible cards must match between simulation and submission, so anyone
+    // can work out the next one from the game state
+    let game = client.get_game(&session_id);
+    let next_card = crate::deal_visible_card(&env, session_id, &game, 1, 2);
+    client.hit(&session_id, &player1);
+    assert_eq!(client.get_game(&session_id).player1_hand.get(2), Some(next_card));
+}
+
 #[test]
 fn test_round_counter() {
     let (_env, client, _hub, player1, player2) = setup_test();
@@ -870,3 +894,639 @@ fn test_cannot_play_against_self() {
     let result = client.try_start_game(&session_id, &player1, &player1, &100_0000000, &100_0000000);
     assert_twenty_one_error(&result, Error::SelfPlay);
 }
+
+#[test]
+fn test_cannot_start_existing_session() {
+    let (_env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 30u32;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    client.stick(&session_id, &player1);
+
+    // Starting again over the live session must not redeal the hands
+    let result = client.try_start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    assert_twenty_one_error(&result, Error::SessionExists);
+    assert!(client.get_game(&session_id).player1_stuck);
+}
+
+// ============================================================================
+// Hidden Hand Tests
+// ============================================================================
+
+/// Secret a test player commits to for their hidden hand
+fn secret(env: &Env, seed: u8) -> BytesN<32> {
+    BytesN::from_array(env, &[seed; 32])
+}
+
+/// Build the share `keccak256(secret)` a player reveals once both have committed
+fn share(env: &Env, secret: &BytesN<32>) -> BytesN<32> {
+    env.crypto().keccak256(&Bytes::from(secret.clone())).into()
+}
+
+/// Build the commitment `keccak256(keccak256(secret))` a frontend would submit
+fn commitment(env: &Env, secret: &BytesN<32>) -> BytesN<32> {
+    env.crypto().keccak256(&Bytes::from(share(env, secret))).into()
+}
+
+/// Start a hidden-hand game where player 1 uses `secret(seed)` and player 2
+/// `secret(seed + 1)`, and reveal both shares so the hands can be played
+fn start_hidden(
+    env: &Env,
+    client: &TwentyOneContractClient,
+    session_id: u32,
+    player1: &Address,
+    player2: &Address,
+    seed: u8,
+) {
+    client.start_hidden_game(
+        &session_id,
+        player1,
+        player2,
+        &100_0000000,
+        &100_0000000,
+        &commitment(env, &secret(env, seed)),
+        &commitment(env, &secret(env, seed + 1)),
+    );
+    client.reveal_share(&session_id, player1, &share(env, &secret(env, seed)));
+    client.reveal_share(&session_id, player2, &share(env, &secret(env, seed + 1)));
+}
+
+#[test]
+fn test_hidden_game_hides_hands() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 40u32;
+    start_hidden(&env, &client, session_id, &player1, &player2, 1);
+
+    // No cards are stored on-chain, only how many were drawn
+    client.hit(&session_id, &player1);
+    let game = client.get_game(&session_id);
+    assert!(game.hidden);
+    assert!(game.player1_hand.is_empty());
+    assert!(game.player2_hand.is_empty());
+    assert_eq!(game.player1_card_count, 3);
+    assert_eq!(game.player2_card_count, 2);
+
+    let result = client.try_get_hand_value(&session_id, &player1);
+    assert_twenty_one_error(&result, Error::HandsNotRevealed);
+}
+
+#[test]
+fn test_hidden_game_reveal_and_settle() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 41u32;
+    start_hidden(&env, &client, session_id, &player1, &player2, 1);
+    client.hit(&session_id, &player2);
+    client.stick(&session_id, &player1);
+    client.stick(&session_id, &player2);
+
+    client.reveal_hand(&session_id, &player1, &secret(&env, 1));
+    let result = client.try_reveal_winner(&session_id);
+    assert_twenty_one_error(&result, Error::HandsNotRevealed);
+    client.reveal_hand(&session_id, &player2, &secret(&env, 2));
+
+    // Revealing doesn't deal the hands, reveal_winner does
+    assert!(client.get_game(&session_id).player1_hand.is_empty());
+    let winner = client.reveal_winner(&session_id);
+
+    let game = client.get_game(&session_id);
+    if game.round == 1 {
+        assert_eq!(game.player1_hand.len(), 2);
+        assert_eq!(game.player2_hand.len(), 3);
+
+        let player1_value = calculate_hand_value_helper(&game.player1_hand);
+        let player2_value = calculate_hand_value_helper(&game.player2_hand);
+        let expected_winner = if player2_value > 21 || player1_value > player2_value {
+            player1.clone()
+        } else {
+            player2.clone()
+        };
+        assert_eq!(winner, Some(expected_winner));
+    }
+    // If the hands tied, a new round was started (covered below)
+}
+
+#[test]
+fn test_hidden_hand_is_reproducible_from_secrets() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    let (env2, client2, _hub2, player1_2, player2_2) = setup_test();
+
+    // Same secrets and session deal the same hidden hands, whoever the players are
+    start_hidden(&env, &client, 42, &player1, &player2, 1);
+    client.stick(&42, &player1);
+    client.stick(&42, &player2);
+    client.reveal_hand(&42, &player1, &secret(&env, 1));
+    client.reveal_hand(&42, &player2, &secret(&env, 2));
+    client.reveal_winner(&42);
+
+    start_hidden(&env2, &client2, 42, &player1_2, &player2_2, 1);
+    client2.stick(&42, &player1_2);
+    client2.stick(&42, &player2_2);
+    client2.reveal_hand(&42, &player1_2, &secret(&env2, 1));
+    client2.reveal_hand(&42, &player2_2, &secret(&env2, 2));
+    client2.reveal_winner(&42);
+
+    let game1 = client.get_game(&42);
+    let game2 = client2.get_game(&42);
+    assert_eq!(game1.round, 1);
+    assert_eq!(game1.player1_hand.len(), 2);
+    assert_eq!(game1.player2_hand.len(), 2);
+    assert_eq!(game1.player1_hand, game2.player1_hand);
+    assert_eq!(game1.player2_hand, game2.player2_hand);
+}
+
+#[test]
+fn test_player_can_compute_own_hidden_hand() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 49u32;
+    start_hidden(&env, &client, session_id, &player1, &player2, 1);
+    client.hit(&session_id, &player1);
+
+    // Player 1 deals their own cards off-chain from the salt and their secret
+    let salt = client.get_deal_salt(&session_id);
+    let own_hand = crate::deal_hidden_hand(&env, session_id, 1, &salt, &secret(&env, 1), 1, 3);
+
+    client.stick(&session_id, &player1);
+    client.stick(&session_id, &player2);
+    client.reveal_hand(&session_id, &player1, &secret(&env, 1));
+    client.reveal_hand(&session_id, &player2, &secret(&env, 2));
+    client.reveal_winner(&session_id);
+
+    let game = client.get_game(&session_id);
+    if game.round == 1 {
+        assert_eq!(game.player1_hand, own_hand);
+    }
+}
+
+#[test]
+fn test_hidden_hand_cannot_be_predicted_from_own_secret() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    // Player 1 keeps the same secret while player 2's varies
+    let player1_secret = secret(&env, 1);
+    let mut first_hand: Option<Bytes> = None;
+    let mut hand_changed = false;
+    for opponent_seed in 2u8..7 {
+        let session_id = 200 + opponent_seed as u32;
+        let opponent_secret = secret(&env, opponent_seed);
+        client.start_hidden_game(
+            &session_id,
+            &player1,
+            &player2,
+            &100_0000000,
+            &100_0000000,
+            &commitment(&env, &player1_secret),
+            &commitment(&env, &opponent_secret),
+        );
+        client.reveal_share(&session_id, &player1, &share(&env, &player1_secret));
+        client.reveal_share(&session_id, &player2, &share(&env, &opponent_secret));
+        client.hit(&session_id, &player1);
+        client.stick(&session_id, &player1);
+        client.stick(&session_id, &player2);
+
+        client.reveal_hand(&session_id, &player1, &player1_secret);
+        client.reveal_hand(&session_id, &player2, &opponent_secret);
+        client.reveal_winner(&session_id);
+        let game = client.get_game(&session_id);
+        if game.round == 1 {
+            assert_eq!(game.player1_hand.len(), 3);
+            match &first_hand {
+                None => first_hand = Some(game.player1_hand),
+                Some(hand) => hand_changed |= *hand != game.player1_hand,
+            }
+        }
+    }
+
+    // The same secret deals player 1 different cards against different opponents
+    assert!(hand_changed);
+}
+
+#[test]
+fn test_hidden_hands_wait_for_both_shares() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 50u32;
+    client.start_hidden_game(
+        &session_id,
+        &player1,
+        &player2,
+        &100_0000000,
+        &100_0000000,
+        &commitment(&env, &secret(&env, 1)),
+        &commitment(&env, &secret(&env, 2)),
+    );
+
+    // The share must open the commitment; the secret itself doesn't
+    let result = client.try_reveal_share(&session_id, &player1, &secret(&env, 1));
+    assert_twenty_one_error(&result, Error::CommitmentMismatch);
+    client.reveal_share(&session_id, &player1, &share(&env, &secret(&env, 1)));
+    let result = client.try_reveal_share(&session_id, &player1, &share(&env, &secret(&env, 1)));
+    assert_twenty_one_error(&result, Error::AlreadyRevealed);
+
+    // Nobody can play, or know a card, until the opponent's share is in
+    let result = client.try_get_deal_salt(&session_id);
+    assert_twenty_one_error(&result, Error::HandsNotRevealed);
+    let result = client.try_hit(&session_id, &player1);
+    assert_twenty_one_error(&result, Error::HandsNotRevealed);
+    let result = client.try_stick(&session_id, &player1);
+    assert_twenty_one_error(&result, Error::HandsNotRevealed);
+
+    client.reveal_share(&session_id, &player2, &share(&env, &secret(&env, 2)));
+    client.get_deal_salt(&session_id);
+    client.hit(&session_id, &player1);
+}
+
+#[test]
+fn test_reveal_hand_must_match_commitment() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 43u32;
+    start_hidden(&env, &client, session_id, &player1, &player2, 1);
+    client.stick(&session_id, &player1);
+    client.stick(&session_id, &player2);
+
+    let result = client.try_reveal_hand(&session_id, &player1, &secret(&env, 9));
+    assert_twenty_one_error(&result, Error::CommitmentMismatch);
+
+    // Player 2's secret doesn't open player 1's commitment either
+    let result = client.try_reveal_hand(&session_id, &player1, &secret(&env, 2));
+    assert_twenty_one_error(&result, Error::CommitmentMismatch);
+
+    client.reveal_hand(&session_id, &player1, &secret(&env, 1));
+    let result = client.try_reveal_hand(&session_id, &player1, &secret(&env, 1));
+    assert_twenty_one_error(&result, Error::AlreadyRevealed);
+}
+
+#[test]
+fn test_reveal_hand_requires_both_stuck() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 44u32;
+    start_hidden(&env, &client, session_id, &player1, &player2, 1);
+    client.stick(&session_id, &player1);
+
+    // Revealing early would show the opponent what they need to beat
+    let result = client.try_reveal_hand(&session_id, &player1, &secret(&env, 1));
+    assert_twenty_one_error(&result, Error::BothPlayersNotStuck);
+}
+
+#[test]
+fn test_hidden_bust_loses_at_reveal() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 45u32;
+    start_hidden(&env, &client, session_id, &player1, &player2, 1);
+
+    // 21 cards are the most a hand can hold; 21 aces would make exactly 21
+    for _ in 0..19 {
+        client.hit(&session_id, &player1);
+    }
+    let result = client.try_hit(&session_id, &player1);
+    assert_twenty_one_error(&result, Error::TooManyCards);
+    assert_eq!(client.get_game(&session_id).player1_card_count, 21);
+    assert!(client.get_game(&session_id).winner.is_none());
+    client.stick(&session_id, &player1);
+    client.stick(&session_id, &player2);
+    client.reveal_hand(&session_id, &player1, &secret(&env, 1));
+    client.reveal_hand(&session_id, &player2, &secret(&env, 2));
+
+    assert_eq!(client.reveal_winner(&session_id), Some(player2));
+    assert_eq!(client.get_game(&session_id).player1_hand.len(), 21);
+}
+
+#[test]
+fn test_hidden_tie_starts_new_round() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    // Find a session whose hidden hands tie
+    let mut tied_session = None;
+    for seed in (1u8..200).step_by(2) {
+        let session_id = 100 + seed as u32;
+        start_hidden(&env, &client, session_id, &player1, &player2, seed);
+        client.stick(&session_id, &player1);
+        client.stick(&session_id, &player2);
+        client.reveal_hand(&session_id, &player1, &secret(&env, seed));
+        client.reveal_hand(&session_id, &player2, &secret(&env, seed + 1));
+        if client.reveal_winner(&session_id).is_none() {
+            tied_session = Some(session_id);
+            break;
+        }
+    }
+    let session_id = tied_session.expect("Some hidden hands should tie");
+
+    // Revealed secrets are public, so the new round needs fresh ones
+    let game = client.get_game(&session_id);
+    assert_eq!(game.round, 2);
+    assert!(game.winner.is_none());
+    assert!(!game.player1_stuck);
+    assert!(game.player1_hand.is_empty());
+    assert!(game.player1_secret.is_none());
+    assert!(game.player2_secret.is_none());
+    let beacon = client.get_beacon(&session_id);
+    assert!(!beacon.is_committed(&player1));
+    assert!(!beacon.is_committed(&player2));
+    assert_eq!(game.player1_card_count, 2);
+
+    let result = client.try_hit(&session_id, &player1);
+    assert_twenty_one_error(&result, Error::HandNotCommitted);
+
+    client.commit_hand(&session_id, &player1, &commitment(&env, &secret(&env, 250)));
+    let result = client.try_commit_hand(&session_id, &player1, &commitment(&env, &secret(&env, 251)));
+    assert_twenty_one_error(&result, Error::AlreadyCommitted);
+    client.commit_hand(&session_id, &player2, &commitment(&env, &secret(&env, 252)));
+    client.reveal_share(&session_id, &player1, &share(&env, &secret(&env, 250)));
+    client.reveal_share(&session_id, &player2, &share(&env, &secret(&env, 252)));
+    client.stick(&session_id, &player1);
+    client.stick(&session_id, &player2);
+    client.reveal_hand(&session_id, &player1, &secret(&env, 250));
+    client.reveal_hand(&session_id, &player2, &secret(&env, 252));
+}
+
+#[test]
+fn test_hidden_only_calls_reject_visible_game() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 46u32;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+
+    let result = client.try_commit_hand(&session_id, &player1, &commitment(&env, &secret(&env, 1)));
+    assert_twenty_one_error(&result, Error::NotHiddenGame);
+    let result = client.try_reveal_share(&session_id, &player1, &share(&env, &secret(&env, 1)));
+    assert_twenty_one_error(&result, Error::NotHiddenGame);
+    let result = client.try_get_beacon(&session_id);
+    assert_twenty_one_error(&result, Error::NotHiddenGame);
+
+    client.stick(&session_id, &player1);
+    client.stick(&session_id, &player2);
+    let result = client.try_reveal_hand(&session_id, &player1, &secret(&env, 1));
+    assert_twenty_one_error(&result, Error::NotHiddenGame);
+}
+
+#[test]
+fn test_hidden_reveal_deadline() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 47u32;
+    start_hidden(&env, &client, session_id, &player1, &player2, 1);
+    client.stick(&session_id, &player1);
+    client.stick(&session_id, &player2);
+
+    // Secrets are due by the deadline both players sticking started
+    let reveal_deadline = client.get_game(&session_id).action_deadline;
+    env.ledger().set_sequence_number(reveal_deadline + 1);
+    let result = client.try_reveal_hand(&session_id, &player1, &secret(&env, 1));
+    assert_twenty_one_error(&result, Error::RevealDeadlinePassed);
+}
+
+#[test]
+fn test_hidden_hands_wait_for_randomness_provider() {
+    let (env, _client, hub, player1, player2) = setup_test();
+    let provider_addr = env.register(MockRandomness, ());
+    let provider = MockRandomnessClient::new(&env, &provider_addr);
+    let admin = Address::generate(&env);
+    let contract_id = env.register(TwentyOneContract, (&admin, &hub.address, Some(provider_addr.clone())));
+    let client = TwentyOneContractClient::new(&env, &contract_id);
+    assert_eq!(client.get_randomness_provider(), Some(provider_addr));
+
+    let session_id = 48u32;
+    start_hidden(&env, &client, session_id, &player1, &player2, 1);
+    let request_id = ((session_id as u64) << 32) | 1;
+    assert!(provider.is_pending(&contract_id, &request_id));
+
+    // Hands can't be played until the provider has answered for the round
+    let result = client.try_hit(&session_id, &player1);
+    assert_twenty_one_error(&result, Error::RandomnessNotReady);
+    let result = client.try_get_deal_salt(&session_id);
+    assert_twenty_one_error(&result, Error::RandomnessNotReady);
+
+    provider.fulfill(&contract_id, &request_id, &BytesN::from_array(&env, &[7u8; 32]));
+    client.stick(&session_id, &player1);
+    client.stick(&session_id, &player2);
+    client.reveal_hand(&session_id, &player1, &secret(&env, 1));
+    client.reveal_hand(&session_id, &player2, &secret(&env, 2));
+    client.reveal_winner(&session_id);
+    let game = client.get_game(&session_id);
+    if game.round == 1 {
+        assert_eq!(game.player1_hand.len(), 2);
+        assert_eq!(game.player2_hand.len(), 2);
+    }
+}
+
+#[test]
+fn test_cancel_game_when_provider_never_answers() {
+    let (env, plain_client, hub, player1, player2) = setup_test();
+    let provider_addr = env.register(MockRandomness, ());
+    let admin = Address::generate(&env);
+    let contract_id = env.register(TwentyOneContract, (&admin, &hub.address, Some(provider_addr)));
+    let client = TwentyOneContractClient::new(&env, &contract_id);
+
+    let session_id = 51u32;
+    start_hidden(&env, &client, session_id, &player1, &player2, 1);
+    let result = client.try_cancel_game(&session_id, &player1);
+    assert_twenty_one_error(&result, Error::DeadlineNotReached);
+
+    // Neither player could move, so neither can claim the win
+    let deadline = client.get_game(&session_id).action_deadline;
+    env.ledger().set_sequence_number(deadline + 1);
+    let result = client.try_claim_timeout(&session_id, &player1);
+    assert_twenty_one_error(&result, Error::PlayerStalled);
+
+    client.cancel_game(&session_id, &player2);
+    assert!(client.get_game(&session_id).cancelled);
+    assert_eq!(hub.get_outcome(&session_id), Some(GameOutcome::Cancelled));
+    let result = client.try_hit(&session_id, &player1);
+    assert_twenty_one_error(&result, Error::GameAlreadyEnded);
+    let result = client.try_cancel_game(&session_id, &player1);
+    assert_twenty_one_error(&result, Error::GameAlreadyEnded);
+
+    // Only hidden games wait on the provider
+    plain_client.start_game(&52, &player1, &player2, &100_0000000, &100_0000000);
+    let result = plain_client.try_cancel_game(&52, &player1);
+    assert_twenty_one_error(&result, Error::NotHiddenGame);
+}
+
+#[test]
+fn test_late_provider_answer_restarts_deadline() {
+    let (env, _client, hub, player1, player2) = setup_test();
+    let provider_addr = env.register(MockRandomness, ());
+    let provider = MockRandomnessClient::new(&env, &provider_addr);
+    let admin = Address::generate(&env);
+    let contract_id = env.register(TwentyOneContract, (&admin, &hub.address, Some(provider_addr)));
+    let client = TwentyOneContractClient::new(&env, &contract_id);
+    client.set_action_timeout(&10);
+
+    let session_id = 53u32;
+    start_hidden(&env, &client, session_id, &player1, &player2, 1);
+    let request_id = ((session_id as u64) << 32) | 1;
+    env.ledger().set_sequence_number(120);
+    provider.fulfill(&contract_id, &request_id, &BytesN::from_array(&env, &[7u8; 32]));
+
+    // Once the provider has answered, the game is played out instead
+    let result = client.try_cancel_game(&session_id, &player1);
+    assert_twenty_one_error(&result, Error::RandomnessReady);
+
+    // The first move restarts the deadline, so player 2 isn't timed out at once
+    client.stick(&session_id, &player1);
+    assert_eq!(client.get_game(&session_id).action_deadline, 130);
+    let result = client.try_claim_timeout(&session_id, &player1);
+    assert_twenty_one_error(&result, Error::DeadlineNotReached);
+    client.hit(&session_id, &player2);
+    assert_eq!(client.get_game(&session_id).action_deadline, 130);
+}
+
+// ============================================================================
+// Timeout Tests
+// ============================================================================
+
+#[test]
+fn test_claim_timeout_when_opponent_never_sticks() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 60u32;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    client.stick(&session_id, &player1);
+
+    // Player 2 still has time to play
+    let result = client.try_claim_timeout(&session_id, &player1);
+    assert_twenty_one_error(&result, Error::DeadlineNotReached);
+
+    let deadline = client.get_game(&session_id).action_deadline;
+    env.ledger().set_sequence_number(deadline + 1);
+
+    let result = client.try_claim_timeout(&session_id, &player2);
+    assert_twenty_one_error(&result, Error::PlayerStalled);
+
+    assert_eq!(client.claim_timeout(&session_id, &player1), player1);
+    assert_eq!(client.get_game(&session_id).winner, Some(player1.clone()));
+
+    let result = client.try_claim_timeout(&session_id, &player1);
+    assert_twenty_one_error(&result, Error::GameAlreadyEnded);
+}
+
+#[test]
+fn test_claim_timeout_when_opponent_never_reveals_hidden_hand() {
+    let (env, client, _hub, player1, player2) = setup_test();
+    client.set_action_timeout(&10);
+    assert_eq!(client.get_action_timeout(), 10);
+
+    let session_id = 61u32;
+    start_hidden(&env, &client, session_id, &player1, &player2, 1);
+    assert_eq!(client.get_game(&session_id).action_deadline, 110);
+
+    // Sticking starts a fresh deadline for the reveals
+    env.ledger().set_sequence_number(105);
+    client.stick(&session_id, &player1);
+    client.stick(&session_id, &player2);
+    assert_eq!(client.get_game(&session_id).action_deadline, 115);
+
+    client.reveal_hand(&session_id, &player1, &secret(&env, 1));
+    env.ledger().set_sequence_number(116);
+
+    let result = client.try_claim_timeout(&session_id, &player2);
+    assert_twenty_one_error(&result, Error::PlayerStalled);
+    assert_eq!(client.claim_timeout(&session_id, &player1), player1);
+}
+
+#[test]
+fn test_claim_timeout_when_opponent_never_reveals_share() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 63u32;
+    client.start_hidden_game(
+        &session_id,
+        &player1,
+        &player2,
+        &100_0000000,
+        &100_0000000,
+        &commitment(&env, &secret(&env, 1)),
+        &commitment(&env, &secret(&env, 2)),
+    );
+    client.reveal_share(&session_id, &player1, &share(&env, &secret(&env, 1)));
+
+    // Withholding a share keeps both hands unplayable, so it forfeits the game
+    let deadline = client.get_game(&session_id).action_deadline;
+    env.ledger().set_sequence_number(deadline + 1);
+    let result = client.try_claim_timeout(&session_id, &player2);
+    assert_twenty_one_error(&result, Error::PlayerStalled);
+    assert_eq!(client.claim_timeout(&session_id, &player1), player1);
+}
+
+#[test]
+fn test_claim_timeout_needs_stalled_opponent() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 62u32;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    client.stick(&session_id, &player1);
+    client.stick(&session_id, &player2);
+
+    // Both players stuck: the winner is revealed normally
+    let deadline = client.get_game(&session_id).action_deadline;
+    env.ledger().set_sequence_number(deadline + 1);
+    let result = client.try_claim_timeout(&session_id, &player1);
+    assert_twenty_one_error(&result, Error::OpponentNotStalled);
+}
+
+// ============================================================================
+// Resign and Draw Tests
+// ============================================================================
+
+#[test]
+fn test_resign_hands_win_to_opponent() {
+    let (_env, client, hub, player1, player2) = setup_test();
+
+    let session_id = 70u32;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+
+    assert_eq!(client.resign(&session_id, &player2), player1);
+    assert_eq!(client.get_game(&session_id).winner, Some(player1.clone()));
+    assert_eq!(hub.get_outcome(&session_id), Some(GameOutcome::Player1Won));
+
+    let result = client.try_resign(&session_id, &player1);
+    assert_twenty_one_error(&result, Error::GameAlreadyEnded);
+}
+
+#[test]
+fn test_agreed_draw_ends_game() {
+    let (_env, client, hub, player1, player2) = setup_test();
+
+    let session_id = 71u32;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+
+    // Nothing to accept yet, and a player can't accept their own offer
+    let result = client.try_accept_draw(&session_id, &player2);
+    assert_twenty_one_error(&result, Error::NoDrawOffer);
+    client.offer_draw(&session_id, &player1);
+    let result = client.try_accept_draw(&session_id, &player1);
+    assert_twenty_one_error(&result, Error::NoDrawOffer);
+
+    client.accept_draw(&session_id, &player2);
+    let game = client.get_game(&session_id);
+    assert!(game.drawn);
+    assert!(game.winner.is_none());
+    assert_eq!(hub.get_outcome(&session_id), Some(GameOutcome::Draw));
+
+    // The game is over for every other action
+    let result = client.try_hit(&session_id, &player1);
+    assert_twenty_one_error(&result, Error::GameAlreadyEnded);
+    let result = client.try_reveal_winner(&session_id);
+    assert_twenty_one_error(&result, Error::GameAlreadyEnded);
+    let result = client.try_resign(&session_id, &player1);
+    assert_twenty_one_error(&result, Error::GameAlreadyEnded);
+}
+
+#[test]
+fn test_non_player_cannot_resign_or_offer_draw() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 72u32;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+
+    let outsider = Address::generate(&env);
+    let result = client.try_resign(&session_id, &outsider);
+    assert_twenty_one_error(&result, Error::NotPlayer);
+    let result = client.try_offer_draw(&session_id, &outsider);
+    assert_twenty_one_error(&result, Error::NotPlayer);
+}
@@ -1065,6 +1065,7 @@ export function PassGame({
             SALT,
             opponentGuess,
             proofStats,
+            { sessionId, round: gameState?.round ?? 1, playerAddress: userAddress },
           );
 
           console.log('[DEBUG] Número de public inputs:', publicInputs.length);
@@ -1086,9 +1087,9 @@ export function PassGame({
             console.log(`[SubmitProof] Public Input ${i}:`, hex);
           });
 
-          // Validar que temos 7 public inputs
-          if (publicInputs.length !== 7) {
-            console.warn(`⚠️ Expected 7 public inputs, got ${publicInputs.length}`);
+          // Validar que temos 10 public inputs
+          if (publicInputs.length !== 10) {
+            console.warn(`⚠️ Expected 10 public inputs, got ${publicInputs.length}`);
           }
           // TODO: No futuro, enviar proof e publicInputs para o contrato
           // await passService.submitProof(..., proof, publicInputs, ...)
@@ -1144,7 +1145,7 @@ export function PassGame({
         const errorMsg = err instanceof Error ? err.message : 'Failed to submit proof';
 
         // Provide specific error feedback
-        if (errorMsg.includes('BothPlayersNotGuessed') || errorMsg.includes('InvalidStatus')) {
+        if (errorMsg.includes('NotProofPhase') || errorMsg.includes('BothPlayersNotGuessed') || errorMsg.includes('InvalidStatus')) {
           setError('Both players must make their guesses before sending proof');
         } else if (errorMsg.includes('Transaction failed')) {
           setError('Transaction failed. Check if both have made their guesses.');
@@ -40,10 +40,18 @@ export const networks = {
 
 
 export interface Game {
+  /**
+   * Player whose draw offer is waiting for the opponent to accept
+   */
+  draw_offer: Option<string>;
   p1_is_fraud: boolean;
   p1_proof_verified: boolean;
   p2_is_fraud: boolean;
   p2_proof_verified: boolean;
+  /**
+   * Last ledger on which the current phase can be completed
+   */
+  phase_deadline: u32;
   player1: string;
   player1_last_guess: Option<u32>;
   player1_points: i128;
@@ -56,7 +64,24 @@ export interface Game {
   player2_proof: Array<ProofData>;
   player2_result: Array<GameResult>;
   player2_secret_hash: Option<Buffer>;
+  /**
+   * Current round, starting at 1; bound into every proof's public inputs
+   */
+  round: u32;
+  /**
+   * Phase of the current round while the game is `Playing`
+   */
+  round_phase: RoundPhase;
   status: GameStatus;
+  /**
+   * Whether `submit_proof` verifies proofs on the spot, pinned at `start_game`
+   */
+  verify_on_submit: boolean;
+  /**
+   * Hash of the verification key the game's proofs are checked against,
+   * pinned at `start_game`
+   */
+  vk_hash: Buffer;
   winner: Option<string>;
 }
 
@@ -67,10 +92,20 @@ export const Errors = {
   5: {message:"GameAlreadyEnded"},
   6: {message:"InvalidStatus"},
   7: {message:"SecretAlreadyRegistered"},
-  8: {message:"BothPlayersNotGuessed"}
+  8: {message:"BothPlayersNotGuessed"},
+  9: {message:"SessionExists"},
+  10: {message:"DeadlineNotReached"},
+  11: {message:"OpponentNotStalled"},
+  12: {message:"PlayerStalled"},
+  13: {message:"NoDrawOffer"},
+  14: {message:"NotGuessPhase"},
+  15: {message:"NotProofPhase"},
+  16: {message:"NotVerificationPhase"},
+  17: {message:"ProofAlreadySubmitted"},
+  18: {message:"VerificationKeyNotSet"}
 }
 
-export type DataKey = {tag: "Game", values: readonly [u32]} | {tag: "GameHubAddress", values: void} | {tag: "Admin", values: void} | {tag: "VerificationKey", values: void};
+export type DataKey = {tag: "Game", values: readonly [u32]} | {tag: "VerificationKey", values: void} | {tag: "Round", values: readonly [u32, u32]} | {tag: "ResolverReward", values: void} | {tag: "VerifyOnSubmit", values: void} | {tag: "Vk", values: readonly [Buffer]} | {tag: "ActiveVk", values: void};
 
 
 export interface ProofData {
@@ -91,37 +126,49 @@ export interface GameResult {
 
 export type GameStatus = {tag: "WaitingForPlayers", values: void} | {tag: "Setup", values: void} | {tag: "Playing", values: void} | {tag: "Draw", values: void} | {tag: "Winner", values: void} | {tag: "Finished", values: void};
 
-export interface Client {
-  /**
-   * Construct and simulate a get_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   */
-  get_hub: (options?: MethodOptions) => Promise<AssembledTransaction<string>>
 
+/**
+ * Record of one verified round, kept under its own `DataKey::Round` entry
+ */
+export interface RoundRecord {
   /**
-   * Construct and simulate a set_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Ledger sequence the round was resolved on
    */
-  set_hub: ({new_hub}: {new_hub: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
-
+  ledger: u32;
   /**
-   * Construct and simulate a upgrade transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Whether each player's proof failed verification
    */
-  upgrade: ({new_wasm_hash}: {new_wasm_hash: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
-
+  p1_is_fraud: boolean;
+  p2_is_fraud: boolean;
+  player1_guess: u32;
   /**
-   * Construct and simulate a get_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Feedback player 2 claimed on player 1's guess
    */
-  get_game: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Game>>>
-
+  player1_result: GameResult;
+  player2_guess: u32;
   /**
-   * Construct and simulate a get_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Feedback player 1 claimed on player 2's guess
    */
-  get_admin: (options?: MethodOptions) => Promise<AssembledTransaction<string>>
+  player2_result: GameResult;
+  round: u32;
+}
 
-  /**
-   * Construct and simulate a set_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   */
-  set_admin: ({new_admin}: {new_admin: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
 
+/**
+ * Reward paid to whoever resolves a round with `resolve_round`, out of the
+ * contract's own balance of `token`
+ */
+export interface ResolverReward {
+  amount: i128;
+  token: string;
+}
+
+/**
+ * Phases of a round, in order
+ */
+export type RoundPhase = {tag: "GuessPhase", values: void} | {tag: "ProofPhase", values: void} | {tag: "Verification", values: void};
+
+export interface Client {
   /**
    * Construct and simulate a initialize transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
@@ -132,6 +179,11 @@ export interface Client {
    */
   start_game: ({session_id, player1, player2, player1_points, player2_points}: {session_id: u32, player1: string, player2: string, player1_points: i128, player2_points: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
+  /**
+   * Construct and simulate a register_secret transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  register_secret: ({session_id, player, secret_hash}: {session_id: u32, player: string, secret_hash: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
   /**
    * Construct and simulate a submit_guess transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
@@ -147,6 +199,66 @@ export interface Client {
    */
   verify_proof: ({session_id, caller}: {session_id: u32, caller: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Option<readonly [GameResult, GameResult]>>>>
 
+  /**
+   * Construct and simulate a resolve_round transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Verify both stored proofs of the current round and resolve it in a
+   * single call. Anyone can call it once both proofs are in; `verify_proof`
+   * remains for splitting the verification cost across two transactions.
+   * If a resolver reward is configured and the contract holds enough of
+   * the reward token, the submitter is paid it.
+   * 
+   * # Arguments
+   * * `session_id` - The session ID of the game
+   * * `submitter` - Address submitting the transaction, paid any reward
+   */
+  resolve_round: ({session_id, submitter}: {session_id: u32, submitter: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<readonly [GameResult, GameResult]>>>
+
+  /**
+   * Construct and simulate a claim_timeout transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Claim the win after the current phase's deadline has passed.
+   * The claimant must have completed every action the phase asks of them,
+   * while the opponent still owes one. If both players stalled, neither can
+   * claim and the session is left for the Game Hub to expire.
+   * Once both proofs of a round are in nobody is stalled, so the call
+   * verifies them and resolves the round instead, like `resolve_round`.
+   * 
+   * # Arguments
+   * * `session_id` - The session ID of the game
+   * * `player` - The responsive player claiming the win
+   */
+  claim_timeout: ({session_id, player}: {session_id: u32, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a resign transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Resign the game, handing the win to the opponent.
+   * 
+   * # Arguments
+   * * `session_id` - The session ID of the game
+   * * `player` - The resigning player
+   */
+  resign: ({session_id, player}: {session_id: u32, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a offer_draw transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Offer the opponent a draw. The offer stands until the opponent accepts
+   * it or the game ends; offering again replaces the opponent's own offer.
+   * 
+   * # Arguments
+   * * `session_id` - The session ID of the game
+   * * `player` - The player offering the draw
+   */
+  offer_draw: ({session_id, player}: {session_id: u32, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a accept_draw transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Accept the opponent's draw offer, ending the game as a draw.
+   * 
+   * # Arguments
+   * * `session_id` - The session ID of the game
+   * * `player` - The player accepting the draw
+   */
+  accept_draw: ({session_id, player}: {session_id: u32, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
   /**
    * Construct and simulate a has_game_ended transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
@@ -158,24 +270,101 @@ export interface Client {
   get_game_status: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<GameStatus>>>
 
   /**
-   * Construct and simulate a register_secret transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a get_player_result transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  register_secret: ({session_id, player, secret_hash}: {session_id: u32, player: string, secret_hash: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+  get_player_result: ({session_id, player}: {session_id: u32, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<GameResult>>>
 
   /**
-   * Construct and simulate a get_player_result transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a get_round_history transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Every resolved round of a game, oldest first
    */
-  get_player_result: ({session_id, player}: {session_id: u32, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<GameResult>>>
+  get_round_history: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Array<RoundRecord>>>>
 
   /**
-   * Construct and simulate a get_verification_key transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a get_player_hash transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * The address hash a player binds into their proofs as the `player`
+   * public input
    */
-  get_verification_key: (options?: MethodOptions) => Promise<AssembledTransaction<Option<Buffer>>>
+  get_player_hash: ({player}: {player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Buffer>>
 
   /**
-   * Construct and simulate a set_verification_key transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a get_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  set_verification_key: ({vk}: {vk: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  get_game: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Game>>>
+
+  /**
+   * Construct and simulate a get_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_admin: (options?: MethodOptions) => Promise<AssembledTransaction<string>>
+
+  /**
+   * Construct and simulate a set_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  set_admin: ({new_admin}: {new_admin: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a get_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_hub: (options?: MethodOptions) => Promise<AssembledTransaction<string>>
+
+  /**
+   * Construct and simulate a set_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  set_hub: ({new_hub}: {new_hub: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a register_vk transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Register a verification key and make it the one new games are pinned
+   * to. Keys live in persistent storage under their `sha256` hash, so games
+   * already in progress keep verifying against the key they started with.
+   * 
+   * # Returns
+   * The hash the key is registered under
+   */
+  register_vk: ({vk}: {vk: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Buffer>>
+
+  /**
+   * Construct and simulate a get_vk transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * A registered verification key by hash
+   */
+  get_vk: ({vk_hash}: {vk_hash: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Option<Buffer>>>
+
+  /**
+   * Construct and simulate a get_active_vk_hash transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Hash of the verification key new games are pinned to
+   */
+  get_active_vk_hash: (options?: MethodOptions) => Promise<AssembledTransaction<Option<Buffer>>>
+
+  /**
+   * Construct and simulate a set_resolver_reward transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set the reward paid to `resolve_round` submitters, or `None` to pay
+   * nothing. The contract must be funded with the reward token; rounds
+   * still resolve, unrewarded, once it runs out.
+   */
+  set_resolver_reward: ({reward}: {reward: Option<ResolverReward>}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a get_resolver_reward transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_resolver_reward: (options?: MethodOptions) => Promise<AssembledTransaction<Option<ResolverReward>>>
+
+  /**
+   * Construct and simulate a set_verify_on_submit transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Choose whether games started from now on verify each proof as it is
+   * submitted, storing only the verified feedback instead of the proof.
+   * Games already started keep the mode they were started with.
+   */
+  set_verify_on_submit: ({enabled}: {enabled: boolean}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a get_verify_on_submit transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_verify_on_submit: (options?: MethodOptions) => Promise<AssembledTransaction<boolean>>
+
+  /**
+   * Construct and simulate a upgrade transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  upgrade: ({new_wasm_hash}: {new_wasm_hash: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
 
 }
 export class Client extends ContractClient {
@@ -195,49 +384,76 @@ export class Client extends ContractClient {
   }
   constructor(public readonly options: ContractClientOptions) {
     super(
-      new ContractSpec([ "AAAAAQAAAAAAAAAAAAAABEdhbWUAAAASAAAAAAAAAAtwMV9pc19mcmF1ZAAAAAABAAAAAAAAABFwMV9wcm9vZl92ZXJpZmllZAAAAAAAAAEAAAAAAAAAC3AyX2lzX2ZyYXVkAAAAAAEAAAAAAAAAEXAyX3Byb29mX3ZlcmlmaWVkAAAAAAAAAQAAAAAAAAAHcGxheWVyMQAAAAATAAAAAAAAABJwbGF5ZXIxX2xhc3RfZ3Vlc3MAAAAAA+gAAAAEAAAAAAAAAA5wbGF5ZXIxX3BvaW50cwAAAAAACwAAAAAAAAANcGxheWVyMV9wcm9vZgAAAAAAA+oAAAfQAAAACVByb29mRGF0YQAAAAAAAAAAAAAOcGxheWVyMV9yZXN1bHQAAAAAA+oAAAfQAAAACkdhbWVSZXN1bHQAAAAAAAAAAAATcGxheWVyMV9zZWNyZXRfaGFzaAAAAAPoAAAD7gAAACAAAAAAAAAAB3BsYXllcjIAAAAAEwAAAAAAAAAScGxheWVyMl9sYXN0X2d1ZXNzAAAAAAPoAAAABAAAAAAAAAAOcGxheWVyMl9wb2ludHMAAAAAAAsAAAAAAAAADXBsYXllcjJfcHJvb2YAAAAAAAPqAAAH0AAAAAlQcm9vZkRhdGEAAAAAAAAAAAAADnBsYXllcjJfcmVzdWx0AAAAAAPqAAAH0AAAAApHYW1lUmVzdWx0AAAAAAAAAAAAE3BsYXllcjJfc2VjcmV0X2hhc2gAAAAD6AAAA+4AAAAgAAAAAAAAAAZzdGF0dXMAAAAAB9AAAAAKR2FtZVN0YXR1cwAAAAAAAAAAAAZ3aW5uZXIAAAAAA+gAAAAT",
-        "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAABwAAAAAAAAAMR2FtZU5vdEZvdW5kAAAAAQAAAAAAAAAJTm90UGxheWVyAAAAAAAAAgAAAAAAAAAOQWxyZWFkeUd1ZXNzZWQAAAAAAAMAAAAAAAAAEEdhbWVBbHJlYWR5RW5kZWQAAAAFAAAAAAAAAA1JbnZhbGlkU3RhdHVzAAAAAAAABgAAAAAAAAAXU2VjcmV0QWxyZWFkeVJlZ2lzdGVyZWQAAAAABwAAAAAAAAAVQm90aFBsYXllcnNOb3RHdWVzc2VkAAAAAAAACA==",
-        "AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAABAAAAAEAAAAAAAAABEdhbWUAAAABAAAABAAAAAAAAAAAAAAADkdhbWVIdWJBZGRyZXNzAAAAAAAAAAAAAAAAAAVBZG1pbgAAAAAAAAAAAAAAAAAAD1ZlcmlmaWNhdGlvbktleQA=",
+      new ContractSpec([ "AAAAAQAAAAAAAAAAAAAABEdhbWUAAAAYAAAAPVBsYXllciB3aG9zZSBkcmF3IG9mZmVyIGlzIHdhaXRpbmcgZm9yIHRoZSBvcHBvbmVudCB0byBhY2NlcHQAAAAAAAAKZHJhd19vZmZlcgAAAAAD6AAAABMAAAAAAAAAC3AxX2lzX2ZyYXVkAAAAAAEAAAAAAAAAEXAxX3Byb29mX3ZlcmlmaWVkAAAAAAAAAQAAAAAAAAALcDJfaXNfZnJhdWQAAAAAAQAAAAAAAAARcDJfcHJvb2ZfdmVyaWZpZWQAAAAAAAABAAAAN0xhc3QgbGVkZ2VyIG9uIHdoaWNoIHRoZSBjdXJyZW50IHBoYXNlIGNhbiBiZSBjb21wbGV0ZWQAAAAADnBoYXNlX2RlYWRsaW5lAAAAAAAEAAAAAAAAAAdwbGF5ZXIxAAAAABMAAAAAAAAAEnBsYXllcjFfbGFzdF9ndWVzcwAAAAAD6AAAAAQAAAAAAAAADnBsYXllcjFfcG9pbnRzAAAAAAALAAAAAAAAAA1wbGF5ZXIxX3Byb29mAAAAAAAD6gAAB9AAAAAJUHJvb2ZEYXRhAAAAAAAAAAAAAA5wbGF5ZXIxX3Jlc3VsdAAAAAAD6gAAB9AAAAAKR2FtZVJlc3VsdAAAAAAAAAAAABNwbGF5ZXIxX3NlY3JldF9oYXNoAAAAA+gAAAPuAAAAIAAAAAAAAAAHcGxheWVyMgAAAAATAAAAAAAAABJwbGF5ZXIyX2xhc3RfZ3Vlc3MAAAAAA+gAAAAEAAAAAAAAAA5wbGF5ZXIyX3BvaW50cwAAAAAACwAAAAAAAAANcGxheWVyMl9wcm9vZgAAAAAAA+oAAAfQAAAACVByb29mRGF0YQAAAAAAAAAAAAAOcGxheWVyMl9yZXN1bHQAAAAAA+oAAAfQAAAACkdhbWVSZXN1bHQAAAAAAAAAAAATcGxheWVyMl9zZWNyZXRfaGFzaAAAAAPoAAAD7gAAACAAAABEQ3VycmVudCByb3VuZCwgc3RhcnRpbmcgYXQgMTsgYm91bmQgaW50byBldmVyeSBwcm9vZidzIHB1YmxpYyBpbnB1dHMAAAAFcm91bmQAAAAAAAAEAAAANlBoYXNlIG9mIHRoZSBjdXJyZW50IHJvdW5kIHdoaWxlIHRoZSBnYW1lIGlzIGBQbGF5aW5nYAAAAAAAC3JvdW5kX3BoYXNlAAAAB9AAAAAKUm91bmRQaGFzZQAAAAAAAAAAAAZzdGF0dXMAAAAAB9AAAAAKR2FtZVN0YXR1cwAAAAAASldoZXRoZXIgYHN1Ym1pdF9wcm9vZmAgdmVyaWZpZXMgcHJvb2ZzIG9uIHRoZSBzcG90LCBwaW5uZWQgYXQgYHN0YXJ0X2dhbWVgAAAAAAAQdmVyaWZ5X29uX3N1Ym1pdAAAAAEAAABaSGFzaCBvZiB0aGUgdmVyaWZpY2F0aW9uIGtleSB0aGUgZ2FtZSdzIHByb29mcyBhcmUgY2hlY2tlZCBhZ2FpbnN0LApwaW5uZWQgYXQgYHN0YXJ0X2dhbWVgAAAAAAAHdmtfaGFzaAAAAAPuAAAAIAAAAAAAAAAGd2lubmVyAAAAAAPoAAAAEw==",
+        "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAAEQAAAAAAAAAMR2FtZU5vdEZvdW5kAAAAAQAAAAAAAAAJTm90UGxheWVyAAAAAAAAAgAAAAAAAAAOQWxyZWFkeUd1ZXNzZWQAAAAAAAMAAAAAAAAAEEdhbWVBbHJlYWR5RW5kZWQAAAAFAAAAAAAAAA1JbnZhbGlkU3RhdHVzAAAAAAAABgAAAAAAAAAXU2VjcmV0QWxyZWFkeVJlZ2lzdGVyZWQAAAAABwAAAAAAAAAVQm90aFBsYXllcnNOb3RHdWVzc2VkAAAAAAAACAAAAAAAAAANU2Vzc2lvbkV4aXN0cwAAAAAAAAkAAAAAAAAAEkRlYWRsaW5lTm90UmVhY2hlZAAAAAAACgAAAAAAAAAST3Bwb25lbnROb3RTdGFsbGVkAAAAAAALAAAAAAAAAA1QbGF5ZXJTdGFsbGVkAAAAAAAADAAAAAAAAAALTm9EcmF3T2ZmZXIAAAAADQAAAAAAAAANTm90R3Vlc3NQaGFzZQAAAAAAAA4AAAAAAAAADU5vdFByb29mUGhhc2UAAAAAAAAPAAAAAAAAABROb3RWZXJpZmljYXRpb25QaGFzZQAAABAAAAAAAAAAFVByb29mQWxyZWFkeVN1Ym1pdHRlZAAAAAAAABEAAAAAAAAAFVZlcmlmaWNhdGlvbktleU5vdFNldAAAAAAAABI=",
+        "AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAABwAAAAEAAAAAAAAABEdhbWUAAAABAAAABAAAAAAAAAA0TGVnYWN5IGluc3RhbmNlLXN0b3JhZ2UgVks7IGNsZWFyZWQgYnkgYHJlZ2lzdGVyX3ZrYAAAAA9WZXJpZmljYXRpb25LZXkAAAAAAQAAAAAAAAAFUm91bmQAAAAAAAACAAAABAAAAAQAAAAAAAAAAAAAAA5SZXNvbHZlclJld2FyZAAAAAAAAAAAAAAAAAAOVmVyaWZ5T25TdWJtaXQAAAAAAAEAAABBUmVnaXN0ZXJlZCB2ZXJpZmljYXRpb24ga2V5LCBpbiBwZXJzaXN0ZW50IHN0b3JhZ2UgdW5kZXIgaXRzIGhhc2gAAAAAAAACVmsAAAAAAAEAAAPuAAAAIAAAAAAAAAA0SGFzaCBvZiB0aGUgdmVyaWZpY2F0aW9uIGtleSBuZXcgZ2FtZXMgYXJlIHBpbm5lZCB0bwAAAAhBY3RpdmVWaw==",
         "AAAAAQAAAAAAAAAAAAAACVByb29mRGF0YQAAAAAAAAUAAAAAAAAAB2FjZXJ0b3MAAAAABAAAAAAAAAAFZXJyb3MAAAAAAAAEAAAAAAAAAApwZXJtdXRhZG9zAAAAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAABXByb29mAAAAAAAADg==",
         "AAAAAQAAAAAAAAAAAAAACkdhbWVSZXN1bHQAAAAAAAQAAAAAAAAAB2FjZXJ0b3MAAAAABAAAAAAAAAAFZXJyb3MAAAAAAAAEAAAAAAAAAApwZXJtdXRhZG9zAAAAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABM=",
         "AAAAAgAAAAAAAAAAAAAACkdhbWVTdGF0dXMAAAAAAAYAAAAAAAAAAAAAABFXYWl0aW5nRm9yUGxheWVycwAAAAAAAAAAAAAAAAAABVNldHVwAAAAAAAAAAAAAAAAAAAHUGxheWluZwAAAAAAAAAAAAAAAAREcmF3AAAAAAAAAAAAAAAGV2lubmVyAAAAAAAAAAAAAAAAAAhGaW5pc2hlZA==",
-        "AAAAAAAAAAAAAAAHZ2V0X2h1YgAAAAAAAAAAAQAAABM=",
-        "AAAAAAAAAAAAAAAHc2V0X2h1YgAAAAABAAAAAAAAAAduZXdfaHViAAAAABMAAAAA",
-        "AAAAAAAAAAAAAAAHdXBncmFkZQAAAAABAAAAAAAAAA1uZXdfd2FzbV9oYXNoAAAAAAAD7gAAACAAAAAA",
-        "AAAAAAAAAAAAAAAIZ2V0X2dhbWUAAAABAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAQAAA+kAAAfQAAAABEdhbWUAAAAD",
-        "AAAAAAAAAAAAAAAJZ2V0X2FkbWluAAAAAAAAAAAAAAEAAAAT",
-        "AAAAAAAAAAAAAAAJc2V0X2FkbWluAAAAAAAAAQAAAAAAAAAJbmV3X2FkbWluAAAAAAAAEwAAAAA=",
+        "AAAAAQAAAEdSZWNvcmQgb2Ygb25lIHZlcmlmaWVkIHJvdW5kLCBrZXB0IHVuZGVyIGl0cyBvd24gYERhdGFLZXk6OlJvdW5kYCBlbnRyeQAAAAAAAAAAC1JvdW5kUmVjb3JkAAAAAAgAAAApTGVkZ2VyIHNlcXVlbmNlIHRoZSByb3VuZCB3YXMgcmVzb2x2ZWQgb24AAAAAAAAGbGVkZ2VyAAAAAAAEAAAAL1doZXRoZXIgZWFjaCBwbGF5ZXIncyBwcm9vZiBmYWlsZWQgdmVyaWZpY2F0aW9uAAAAAAtwMV9pc19mcmF1ZAAAAAABAAAAAAAAAAtwMl9pc19mcmF1ZAAAAAABAAAAAAAAAA1wbGF5ZXIxX2d1ZXNzAAAAAAAABAAAAC1GZWVkYmFjayBwbGF5ZXIgMiBjbGFpbWVkIG9uIHBsYXllciAxJ3MgZ3Vlc3MAAAAAAAAOcGxheWVyMV9yZXN1bHQAAAAAB9AAAAAKR2FtZVJlc3VsdAAAAAAAAAAAAA1wbGF5ZXIyX2d1ZXNzAAAAAAAABAAAAC1GZWVkYmFjayBwbGF5ZXIgMSBjbGFpbWVkIG9uIHBsYXllciAyJ3MgZ3Vlc3MAAAAAAAAOcGxheWVyMl9yZXN1bHQAAAAAB9AAAAAKR2FtZVJlc3VsdAAAAAAAAAAAAAVyb3VuZAAAAAAAAAQ=",
+        "AAAAAQAAAGpSZXdhcmQgcGFpZCB0byB3aG9ldmVyIHJlc29sdmVzIGEgcm91bmQgd2l0aCBgcmVzb2x2ZV9yb3VuZGAsIG91dCBvZiB0aGUKY29udHJhY3QncyBvd24gYmFsYW5jZSBvZiBgdG9rZW5gAAAAAAAAAAAADlJlc29sdmVyUmV3YXJkAAAAAAACAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAAAAAABXRva2VuAAAAAAAAEw==",
+        "AAAAAgAAABtQaGFzZXMgb2YgYSByb3VuZCwgaW4gb3JkZXIAAAAAAAAAAApSb3VuZFBoYXNlAAAAAAADAAAAAAAAAExQbGF5ZXJzIHN1Ym1pdCB0aGVpciBndWVzc2VzOyBlYWNoIGNhbiBzdGlsbCBjaGFuZ2UgdGhlaXJzIHVudGlsIGJvdGggYXJlIGluAAAACkd1ZXNzUGhhc2UAAAAAAAAAAABIR3Vlc3NlcyBhcmUgbG9ja2VkOyBwbGF5ZXJzIHN1Ym1pdCBwcm9vZnMgYW5zd2VyaW5nIHRoZSBvcHBvbmVudCdzIGd1ZXNzAAAAClByb29mUGhhc2UAAAAAAAAAAAAqQm90aCBwcm9vZnMgYXJlIGluIGFuZCB3YWl0IHRvIGJlIHZlcmlmaWVkAAAAAAAMVmVyaWZpY2F0aW9u",
         "AAAAAAAAAAAAAAAKaW5pdGlhbGl6ZQAAAAAAAgAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAAAAAAhnYW1lX2h1YgAAABMAAAAA",
         "AAAAAAAAAAAAAAAKc3RhcnRfZ2FtZQAAAAAABQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAHcGxheWVyMQAAAAATAAAAAAAAAAdwbGF5ZXIyAAAAABMAAAAAAAAADnBsYXllcjFfcG9pbnRzAAAAAAALAAAAAAAAAA5wbGF5ZXIyX3BvaW50cwAAAAAACwAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAAAAAAAPcmVnaXN0ZXJfc2VjcmV0AAAAAAMAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAAAAAALc2VjcmV0X2hhc2gAAAAD7gAAACAAAAABAAAD6QAAAAIAAAAD",
         "AAAAAAAAAAAAAAAMc3VibWl0X2d1ZXNzAAAAAwAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAAVndWVzcwAAAAAAAAQAAAABAAAD6QAAAAIAAAAD",
         "AAAAAAAAAAAAAAAMc3VibWl0X3Byb29mAAAABgAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAAdhY2VydG9zAAAAAAQAAAAAAAAACnBlcm11dGFkb3MAAAAAAAQAAAAAAAAABWVycm9zAAAAAAAABAAAAAAAAAAFcHJvb2YAAAAAAAAOAAAAAQAAA+kAAAACAAAAAw==",
         "AAAAAAAAAAAAAAAMdmVyaWZ5X3Byb29mAAAAAgAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGY2FsbGVyAAAAAAATAAAAAQAAA+kAAAPoAAAD7QAAAAIAAAfQAAAACkdhbWVSZXN1bHQAAAAAB9AAAAAKR2FtZVJlc3VsdAAAAAAAAw==",
+        "AAAAAAAAAbxWZXJpZnkgYm90aCBzdG9yZWQgcHJvb2ZzIG9mIHRoZSBjdXJyZW50IHJvdW5kIGFuZCByZXNvbHZlIGl0IGluIGEKc2luZ2xlIGNhbGwuIEFueW9uZSBjYW4gY2FsbCBpdCBvbmNlIGJvdGggcHJvb2ZzIGFyZSBpbjsgYHZlcmlmeV9wcm9vZmAKcmVtYWlucyBmb3Igc3BsaXR0aW5nIHRoZSB2ZXJpZmljYXRpb24gY29zdCBhY3Jvc3MgdHdvIHRyYW5zYWN0aW9ucy4KSWYgYSByZXNvbHZlciByZXdhcmQgaXMgY29uZmlndXJlZCBhbmQgdGhlIGNvbnRyYWN0IGhvbGRzIGVub3VnaCBvZgp0aGUgcmV3YXJkIHRva2VuLCB0aGUgc3VibWl0dGVyIGlzIHBhaWQgaXQuCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIFRoZSBzZXNzaW9uIElEIG9mIHRoZSBnYW1lCiogYHN1Ym1pdHRlcmAgLSBBZGRyZXNzIHN1Ym1pdHRpbmcgdGhlIHRyYW5zYWN0aW9uLCBwYWlkIGFueSByZXdhcmQAAAANcmVzb2x2ZV9yb3VuZAAAAAAAAAIAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAACXN1Ym1pdHRlcgAAAAAAABMAAAABAAAD6QAAA+0AAAACAAAH0AAAAApHYW1lUmVzdWx0AAAAAAfQAAAACkdhbWVSZXN1bHQAAAAAAAM=",
+        "AAAAAAAAAfdDbGFpbSB0aGUgd2luIGFmdGVyIHRoZSBjdXJyZW50IHBoYXNlJ3MgZGVhZGxpbmUgaGFzIHBhc3NlZC4KVGhlIGNsYWltYW50IG11c3QgaGF2ZSBjb21wbGV0ZWQgZXZlcnkgYWN0aW9uIHRoZSBwaGFzZSBhc2tzIG9mIHRoZW0sCndoaWxlIHRoZSBvcHBvbmVudCBzdGlsbCBvd2VzIG9uZS4gSWYgYm90aCBwbGF5ZXJzIHN0YWxsZWQsIG5laXRoZXIgY2FuCmNsYWltIGFuZCB0aGUgc2Vzc2lvbiBpcyBsZWZ0IGZvciB0aGUgR2FtZSBIdWIgdG8gZXhwaXJlLgpPbmNlIGJvdGggcHJvb2ZzIG9mIGEgcm91bmQgYXJlIGluIG5vYm9keSBpcyBzdGFsbGVkLCBzbyB0aGUgY2FsbAp2ZXJpZmllcyB0aGVtIGFuZCByZXNvbHZlcyB0aGUgcm91bmQgaW5zdGVhZCwgbGlrZSBgcmVzb2x2ZV9yb3VuZGAuCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIFRoZSBzZXNzaW9uIElEIG9mIHRoZSBnYW1lCiogYHBsYXllcmAgLSBUaGUgcmVzcG9uc2l2ZSBwbGF5ZXIgY2xhaW1pbmcgdGhlIHdpbgAAAAANY2xhaW1fdGltZW91dAAAAAAAAAIAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAIxSZXNpZ24gdGhlIGdhbWUsIGhhbmRpbmcgdGhlIHdpbiB0byB0aGUgb3Bwb25lbnQuCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIFRoZSBzZXNzaW9uIElEIG9mIHRoZSBnYW1lCiogYHBsYXllcmAgLSBUaGUgcmVzaWduaW5nIHBsYXllcgAAAAZyZXNpZ24AAAAAAAIAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAPBPZmZlciB0aGUgb3Bwb25lbnQgYSBkcmF3LiBUaGUgb2ZmZXIgc3RhbmRzIHVudGlsIHRoZSBvcHBvbmVudCBhY2NlcHRzCml0IG9yIHRoZSBnYW1lIGVuZHM7IG9mZmVyaW5nIGFnYWluIHJlcGxhY2VzIHRoZSBvcHBvbmVudCdzIG93biBvZmZlci4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVGhlIHNlc3Npb24gSUQgb2YgdGhlIGdhbWUKKiBgcGxheWVyYCAtIFRoZSBwbGF5ZXIgb2ZmZXJpbmcgdGhlIGRyYXcAAAAKb2ZmZXJfZHJhdwAAAAAAAgAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAKBBY2NlcHQgdGhlIG9wcG9uZW50J3MgZHJhdyBvZmZlciwgZW5kaW5nIHRoZSBnYW1lIGFzIGEgZHJhdy4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVGhlIHNlc3Npb24gSUQgb2YgdGhlIGdhbWUKKiBgcGxheWVyYCAtIFRoZSBwbGF5ZXIgYWNjZXB0aW5nIHRoZSBkcmF3AAAAC2FjY2VwdF9kcmF3AAAAAAIAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAPpAAAAAgAAAAM=",
         "AAAAAAAAAAAAAAAOaGFzX2dhbWVfZW5kZWQAAAAAAAEAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAABAAAD6QAAA+gAAAATAAAAAw==",
         "AAAAAAAAAAAAAAAPZ2V0X2dhbWVfc3RhdHVzAAAAAAEAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAABAAAD6QAAB9AAAAAKR2FtZVN0YXR1cwAAAAAAAw==",
-        "AAAAAAAAAAAAAAAPcmVnaXN0ZXJfc2VjcmV0AAAAAAMAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAAAAAALc2VjcmV0X2hhc2gAAAAD7gAAACAAAAABAAAD6QAAAAIAAAAD",
         "AAAAAAAAAAAAAAARZ2V0X3BsYXllcl9yZXN1bHQAAAAAAAACAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAABAAAD6QAAB9AAAAAKR2FtZVJlc3VsdAAAAAAAAw==",
-        "AAAAAAAAAAAAAAAUZ2V0X3ZlcmlmaWNhdGlvbl9rZXkAAAAAAAAAAQAAA+gAAAAO",
-        "AAAAAAAAAAAAAAAUc2V0X3ZlcmlmaWNhdGlvbl9rZXkAAAABAAAAAAAAAAJ2awAAAAAADgAAAAA=" ]),
+        "AAAAAAAAACxFdmVyeSByZXNvbHZlZCByb3VuZCBvZiBhIGdhbWUsIG9sZGVzdCBmaXJzdAAAABFnZXRfcm91bmRfaGlzdG9yeQAAAAAAAAEAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAABAAAD6QAAA+oAAAfQAAAAC1JvdW5kUmVjb3JkAAAAAAM=",
+        "AAAAAAAAAE5UaGUgYWRkcmVzcyBoYXNoIGEgcGxheWVyIGJpbmRzIGludG8gdGhlaXIgcHJvb2ZzIGFzIHRoZSBgcGxheWVyYApwdWJsaWMgaW5wdXQAAAAAAA9nZXRfcGxheWVyX2hhc2gAAAAAAQAAAAAAAAAGcGxheWVyAAAAAAATAAAAAQAAA+4AAAAg",
+        "AAAAAAAAAAAAAAAIZ2V0X2dhbWUAAAABAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAQAAA+kAAAfQAAAABEdhbWUAAAAD",
+        "AAAAAAAAAAAAAAAJZ2V0X2FkbWluAAAAAAAAAAAAAAEAAAAT",
+        "AAAAAAAAAAAAAAAJc2V0X2FkbWluAAAAAAAAAQAAAAAAAAAJbmV3X2FkbWluAAAAAAAAEwAAAAA=",
+        "AAAAAAAAAAAAAAAHZ2V0X2h1YgAAAAAAAAAAAQAAABM=",
+        "AAAAAAAAAAAAAAAHc2V0X2h1YgAAAAABAAAAAAAAAAduZXdfaHViAAAAABMAAAAA",
+        "AAAAAAAAAQJSZWdpc3RlciBhIHZlcmlmaWNhdGlvbiBrZXkgYW5kIG1ha2UgaXQgdGhlIG9uZSBuZXcgZ2FtZXMgYXJlIHBpbm5lZAp0by4gS2V5cyBsaXZlIGluIHBlcnNpc3RlbnQgc3RvcmFnZSB1bmRlciB0aGVpciBgc2hhMjU2YCBoYXNoLCBzbyBnYW1lcwphbHJlYWR5IGluIHByb2dyZXNzIGtlZXAgdmVyaWZ5aW5nIGFnYWluc3QgdGhlIGtleSB0aGV5IHN0YXJ0ZWQgd2l0aC4KCiMgUmV0dXJucwpUaGUgaGFzaCB0aGUga2V5IGlzIHJlZ2lzdGVyZWQgdW5kZXIAAAAAAAtyZWdpc3Rlcl92awAAAAABAAAAAAAAAAJ2awAAAAAADgAAAAEAAAPuAAAAIA==",
+        "AAAAAAAAACVBIHJlZ2lzdGVyZWQgdmVyaWZpY2F0aW9uIGtleSBieSBoYXNoAAAAAAAABmdldF92awAAAAAAAQAAAAAAAAAHdmtfaGFzaAAAAAPuAAAAIAAAAAEAAAPoAAAADg==",
+        "AAAAAAAAADRIYXNoIG9mIHRoZSB2ZXJpZmljYXRpb24ga2V5IG5ldyBnYW1lcyBhcmUgcGlubmVkIHRvAAAAEmdldF9hY3RpdmVfdmtfaGFzaAAAAAAAAAAAAAEAAAPoAAAD7gAAACA=",
+        "AAAAAAAAALNTZXQgdGhlIHJld2FyZCBwYWlkIHRvIGByZXNvbHZlX3JvdW5kYCBzdWJtaXR0ZXJzLCBvciBgTm9uZWAgdG8gcGF5Cm5vdGhpbmcuIFRoZSBjb250cmFjdCBtdXN0IGJlIGZ1bmRlZCB3aXRoIHRoZSByZXdhcmQgdG9rZW47IHJvdW5kcwpzdGlsbCByZXNvbHZlLCB1bnJld2FyZGVkLCBvbmNlIGl0IHJ1bnMgb3V0LgAAAAATc2V0X3Jlc29sdmVyX3Jld2FyZAAAAAABAAAAAAAAAAZyZXdhcmQAAAAAA+gAAAfQAAAADlJlc29sdmVyUmV3YXJkAAAAAAAA",
+        "AAAAAAAAAAAAAAATZ2V0X3Jlc29sdmVyX3Jld2FyZAAAAAAAAAAAAQAAA+gAAAfQAAAADlJlc29sdmVyUmV3YXJkAAA=",
+        "AAAAAAAAAMNDaG9vc2Ugd2hldGhlciBnYW1lcyBzdGFydGVkIGZyb20gbm93IG9uIHZlcmlmeSBlYWNoIHByb29mIGFzIGl0IGlzCnN1Ym1pdHRlZCwgc3RvcmluZyBvbmx5IHRoZSB2ZXJpZmllZCBmZWVkYmFjayBpbnN0ZWFkIG9mIHRoZSBwcm9vZi4KR2FtZXMgYWxyZWFkeSBzdGFydGVkIGtlZXAgdGhlIG1vZGUgdGhleSB3ZXJlIHN0YXJ0ZWQgd2l0aC4AAAAAFHNldF92ZXJpZnlfb25fc3VibWl0AAAAAQAAAAAAAAAHZW5hYmxlZAAAAAABAAAAAA==",
+        "AAAAAAAAAAAAAAAUZ2V0X3ZlcmlmeV9vbl9zdWJtaXQAAAAAAAAAAQAAAAE=",
+        "AAAAAAAAAAAAAAAHdXBncmFkZQAAAAABAAAAAAAAAA1uZXdfd2FzbV9oYXNoAAAAAAAD7gAAACAAAAAA" ]),
       options
     )
   }
   public readonly fromJSON = {
-    get_hub: this.txFromJSON<string>,
-        set_hub: this.txFromJSON<null>,
-        upgrade: this.txFromJSON<null>,
-        get_game: this.txFromJSON<Result<Game>>,
-        get_admin: this.txFromJSON<string>,
-        set_admin: this.txFromJSON<null>,
-        initialize: this.txFromJSON<null>,
+    initialize: this.txFromJSON<null>,
         start_game: this.txFromJSON<Result<void>>,
+        register_secret: this.txFromJSON<Result<void>>,
         submit_guess: this.txFromJSON<Result<void>>,
         submit_proof: this.txFromJSON<Result<void>>,
         verify_proof: this.txFromJSON<Result<Option<readonly [GameResult, GameResult]>>>,
+        resolve_round: this.txFromJSON<Result<readonly [GameResult, GameResult]>>,
+        claim_timeout: this.txFromJSON<Result<void>>,
+        resign: this.txFromJSON<Result<void>>,
+        offer_draw: this.txFromJSON<Result<void>>,
+        accept_draw: this.txFromJSON<Result<void>>,
         has_game_ended: this.txFromJSON<Result<Option<string>>>,
         get_game_status: this.txFromJSON<Result<GameStatus>>,
-        register_secret: this.txFromJSON<Result<void>>,
         get_player_result: this.txFromJSON<Result<GameResult>>,
-        get_verification_key: this.txFromJSON<Option<Buffer>>,
-        set_verification_key: this.txFromJSON<null>
+        get_round_history: this.txFromJSON<Result<Array<RoundRecord>>>,
+        get_player_hash: this.txFromJSON<Buffer>,
+        get_game: this.txFromJSON<Result<Game>>,
+        get_admin: this.txFromJSON<string>,
+        set_admin: this.txFromJSON<null>,
+        get_hub: this.txFromJSON<string>,
+        set_hub: this.txFromJSON<null>,
+        register_vk: this.txFromJSON<Buffer>,
+        get_vk: this.txFromJSON<Option<Buffer>>,
+        get_active_vk_hash: this.txFromJSON<Option<Buffer>>,
+        set_resolver_reward: this.txFromJSON<null>,
+        get_resolver_reward: this.txFromJSON<Option<ResolverReward>>,
+        set_verify_on_submit: this.txFromJSON<null>,
+        get_verify_on_submit: this.txFromJSON<boolean>,
+        upgrade: this.txFromJSON<null>
   }
 }
\ No newline at end of file
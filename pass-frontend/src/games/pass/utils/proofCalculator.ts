@@ -1,6 +1,7 @@
 import { Noir } from '@noir-lang/noir_js';
 import { UltraHonkBackend } from '@aztec/bb.js';
 import { Barretenberg, Fr } from '@aztec/bb.js';
+import { hash } from '@stellar/stellar-sdk';
 import initNoirC from '@noir-lang/noirc_abi';
 import initACVM from '@noir-lang/acvm_js';
 import acvm from '@noir-lang/acvm_js/web/acvm_js_bg.wasm?url';
@@ -15,6 +16,30 @@ async function initWasm() {
     wasmInitialized = true;
 }
 
+/**
+ * Sessão, rodada e jogador aos quais a prova fica vinculada (public inputs)
+ */
+export interface ProofBinding {
+    sessionId: number;
+    round: number;
+    playerAddress: string;
+}
+
+/**
+ * Public inputs do circuito que vinculam a prova à sessão, rodada e jogador
+ */
+const BINDING_PARAMS = ['session_id', 'round', 'player'];
+
+/**
+ * Hash do endereço do jogador como o contrato calcula (get_player_hash):
+ * sha256 do endereço, com o primeiro byte zerado para caber num Field
+ */
+export function playerHash(playerAddress: string): string {
+    const digest = Buffer.from(hash(Buffer.from(playerAddress)));
+    digest[0] = 0;
+    return `0x${digest.toString('hex')}`;
+}
+
 export interface ProofStats {
     acertos: number;      // Dígitos corretos na posição correta
     permutados: number;   // Dígitos corretos em posição errada
@@ -120,6 +145,7 @@ export async function proveTurn(
     salt: string,
     guess: number,
     stats: ProofStats,
+    binding: ProofBinding,
 ): Promise<{ proof: Uint8Array; publicInputs: string[] }> {
     // Importar dinamicamente para evitar erros de SSR se necessário, 
     // mas aqui estamos no client-side.
@@ -136,6 +162,17 @@ export async function proveTurn(
     }
 
     const circuitData = (circuit as any).default ?? circuit;
+
+    // Um circuito compilado antes da vinculação a sessão/rodada/jogador gera
+    // provas que o contrato rejeita como fraude: melhor falhar aqui
+    const circuitParams: string[] = (circuitData.abi?.parameters ?? []).map((p: { name: string }) => p.name);
+    const missingParams = BINDING_PARAMS.filter((name) => !circuitParams.includes(name));
+    if (missingParams.length > 0) {
+        throw new Error(
+            `Circuit artifact is out of date (missing ${missingParams.join(', ')}). ` +
+            "Recompile contracts/pass with 'nargo compile' and copy 'target/pass_circuit.json' to 'pass-frontend/src/games/pass/circuit.json'."
+        );
+    }
     const backend = new UltraHonkBackend(circuitData.bytecode);
     const noir = new Noir(circuitData);
 
@@ -149,11 +186,8 @@ export async function proveTurn(
     // We pass 0 as placeholder if the circuit computes it, but usually we pass private inputs
     // and the circuit constraints them against public inputs or returns them.
     // In our case, the circuit has:
-    // fn main(secret, salt, guess, hash, acertos, permutados, erros)
-    // All inputs are provided by Prover?
-    // Wait, the circuit signature in main.nr:
-    // fn main(secret, salt, guess, hash, acertos, permutados, erros) -> pub (...)
-    // So we provide ALL inputs.
+    // fn main(secret, salt, guess, hash, acertos, permutados, erros, session_id, round, player)
+    // and the prover provides ALL inputs.
 
     // Normalize salt directly to field-compatible string (hex)
     const saltBn = normalizeSalt(salt);
@@ -177,7 +211,10 @@ export async function proveTurn(
         hash: hashField,
         acertos: stats.acertos,
         permutados: stats.permutados,
-        erros: stats.erros
+        erros: stats.erros,
+        session_id: binding.sessionId,
+        round: binding.round,
+        player: playerHash(binding.playerAddress)
     };
 
 
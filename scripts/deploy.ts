@@ -8,7 +8,7 @@
  */
 
 import { $ } from "bun";
-import { existsSync } from "node:fs";
+import { copyFileSync, existsSync } from "node:fs";
 import { unlink } from "node:fs/promises";
 import { tmpdir } from "node:os";
 import { join } from "node:path";
@@ -157,6 +157,16 @@ for (const contract of contracts) {
       process.exit(1);
     }
     console.log("  ✅ VK encontrada em contracts/pass/target/vk/vk");
+
+    // O frontend precisa provar com o mesmo circuito compilado de onde saiu a VK
+    const circuitPath = join(process.cwd(), "contracts/pass/target/pass_circuit.json");
+    if (!existsSync(circuitPath)) {
+      console.error("❌ Circuito compilado não encontrado em contracts/pass/target/pass_circuit.json");
+      console.error("  Ele é gerado pelo mesmo 'nargo compile' que a VK (veja o comando acima)");
+      process.exit(1);
+    }
+    copyFileSync(circuitPath, join(process.cwd(), "pass-frontend/src/games/pass/circuit.json"));
+    console.log("  ✅ Circuito copiado para pass-frontend/src/games/pass/circuit.json");
   }
 
   if (!await Bun.file(contract.wasmPath).exists()) missingWasm.push(contract.wasmPath);
@@ -377,7 +387,7 @@ for (const contract of contracts) {
               --id ${contractId} \
               --source-account ${adminSecret} \
               --network ${NETWORK} \
-              -- set_verification_key \
+              -- register_vk \
               --vk ${vkArg}`;
               
             console.log("  ✅ VK configurada com sucesso!");
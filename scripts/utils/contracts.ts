@@ -53,6 +53,12 @@ export async function getWorkspaceContracts(): Promise<ContractInfo[]> {
       throw new Error(`Missing [package].name in ${manifestPath}`);
     }
 
+    // Shared library crates (e.g. game-common) don't build to a contract WASM
+    const crateTypes = (manifestToml?.lib?.["crate-type"] ?? []) as string[];
+    if (!crateTypes.includes("cdylib")) {
+      continue;
+    }
+
     const wasmName = toWasmName(packageName);
     const envKey = toEnvKey(packageName);
 
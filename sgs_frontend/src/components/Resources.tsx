@@ -324,7 +324,16 @@ pub trait GameHub {
         player2_points: i128,
     );
 
-    fn end_game(env: Env, session_id: u32, player1_won: bool);
+    fn end_game(env: Env, session_id: u32, outcome: GameOutcome);
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum GameOutcome {
+    Player1Won,
+    Player2Won,
+    Draw,
+    Cancelled,
 }`}</code>
             </pre>
           </div>
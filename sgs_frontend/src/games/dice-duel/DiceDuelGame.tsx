@@ -5,6 +5,7 @@ import { getLocationSearch } from '@/utils/location';
 import { useWallet } from '@/hooks/useWallet';
 import { DICE_DUEL_CONTRACT } from '@/utils/constants';
 import { getFundedSimulationSourceAddress } from '@/utils/simulationUtils';
+import { hasBeaconEntry } from '@/utils/commitReveal';
 import { devWalletService, DevWalletService } from '@/services/devWalletService';
 import type { Game } from './bindings';
 
@@ -25,6 +26,10 @@ const createRandomSessionId = (): number => {
 // Create service instance with the contract ID
 const diceDuelService = new DiceDuelService(DICE_DUEL_CONTRACT);
 
+// Rolls are committed first and revealed once both players have rolled
+const bothRevealed = (game: Game) =>
+  hasBeaconEntry(game.beacon.reveals, game.player1) && hasBeaconEntry(game.beacon.reveals, game.player2);
+
 const DICE_PIPS: Record<number, Array<[number, number]>> = {
   1: [[50, 50]],
   2: [[25, 25], [75, 75]],
@@ -195,7 +200,7 @@ export function DiceDuelGame({
       // Determine game phase based on state
       if (game && game.winner !== null && game.winner !== undefined) {
         setGamePhase('complete');
-      } else if (game && game.player1_rolled && game.player2_rolled) {
+      } else if (game && bothRevealed(game)) {
         setGamePhase('reveal');
       } else {
         setGamePhase('roll');
@@ -765,10 +770,10 @@ export function DiceDuelGame({
           setGamePhase('complete');
           const isWinner = game.winner === userAddress;
           setSuccess(isWinner ? '🎉 You won this game!' : 'Game complete. Winner revealed.');
-        } else if (game.player1_rolled && game.player2_rolled) {
-          // Both players rolled, waiting for reveal
+        } else if (bothRevealed(game)) {
+          // Both players rolled and revealed, waiting for the winner reveal
           setGamePhase('reveal');
-          setSuccess('Game loaded! Both players have rolled. You can reveal the winner.');
+          setSuccess('Game loaded! Both players have revealed their rolls. You can reveal the winner.');
         } else {
           // Still in rolling phase
           setGamePhase('roll');
@@ -861,6 +866,27 @@ export function DiceDuelGame({
     });
   };
 
+  const handleRevealRoll = async () => {
+    await runAction(async () => {
+      try {
+        setLoading(true);
+        setError(null);
+        setSuccess(null);
+
+        const signer = getContractSigner();
+        await diceDuelService.revealRoll(sessionId, userAddress, signer);
+
+        setSuccess('Roll revealed!');
+        await loadGameState();
+      } catch (err) {
+        console.error('Reveal roll error:', err);
+        setError(err instanceof Error ? err.message : 'Failed to reveal roll');
+      } finally {
+        setLoading(false);
+      }
+    });
+  };
+
   const waitForWinner = async () => {
     let updatedGame = await diceDuelService.getGame(sessionId);
     let attempts = 0;
@@ -908,7 +934,10 @@ export function DiceDuelGame({
 
   const isPlayer1 = gameState && gameState.player1 === userAddress;
   const isPlayer2 = gameState && gameState.player2 === userAddress;
-  const hasRolled = isPlayer1 ? !!gameState?.player1_rolled : isPlayer2 ? !!gameState?.player2_rolled : false;
+  const player1Rolled = !!gameState && hasBeaconEntry(gameState.beacon.commitments, gameState.player1);
+  const player2Rolled = !!gameState && hasBeaconEntry(gameState.beacon.commitments, gameState.player2);
+  const hasRolled = isPlayer1 ? player1Rolled : isPlayer2 ? player2Rolled : false;
+  const hasRevealedRoll = !!gameState && hasBeaconEntry(gameState.beacon.reveals, userAddress);
 
   const player1Dice = [gameState?.player1_die1 ?? null, gameState?.player1_die2 ?? null];
   const player2Dice = [gameState?.player2_die1 ?? null, gameState?.player2_die2 ?? null];
@@ -1362,10 +1391,10 @@ export function DiceDuelGame({
                 Points: {(Number(gameState.player1_points) / 10000000).toFixed(2)}
               </div>
               <div className="mt-4 flex items-center gap-3 dice-tray">
-                <DiceFace value={gameState.player1_die1 ?? null} tone="gold" rolling={player1Rolling} rolled={gameState.player1_rolled} />
-                <DiceFace value={gameState.player1_die2 ?? null} tone="gold" rolling={player1Rolling} rolled={gameState.player1_rolled} />
+                <DiceFace value={gameState.player1_die1 ?? null} tone="gold" rolling={player1Rolling} rolled={player1Rolled} />
+                <DiceFace value={gameState.player1_die2 ?? null} tone="gold" rolling={player1Rolling} rolled={player1Rolled} />
                 <div className="text-xs font-bold text-gray-600">
-                  {gameState.player1_rolled ? 'Rolled' : 'Waiting'}
+                  {hasBeaconEntry(gameState.beacon.reveals, gameState.player1) ? 'Revealed' : player1Rolled ? 'Rolled' : 'Waiting'}
                 </div>
               </div>
             </div>
@@ -1379,10 +1408,10 @@ export function DiceDuelGame({
                 Points: {(Number(gameState.player2_points) / 10000000).toFixed(2)}
               </div>
               <div className="mt-4 flex items-center gap-3 dice-tray">
-                <DiceFace value={gameState.player2_die1 ?? null} tone="red" rolling={player2Rolling} rolled={gameState.player2_rolled} />
-                <DiceFace value={gameState.player2_die2 ?? null} tone="red" rolling={player2Rolling} rolled={gameState.player2_rolled} />
+                <DiceFace value={gameState.player2_die1 ?? null} tone="red" rolling={player2Rolling} rolled={player2Rolled} />
+                <DiceFace value={gameState.player2_die2 ?? null} tone="red" rolling={player2Rolling} rolled={player2Rolled} />
                 <div className="text-xs font-bold text-gray-600">
-                  {gameState.player2_rolled ? 'Rolled' : 'Waiting'}
+                  {hasBeaconEntry(gameState.beacon.reveals, gameState.player2) ? 'Revealed' : player2Rolled ? 'Rolled' : 'Waiting'}
                 </div>
               </div>
             </div>
@@ -1403,13 +1432,36 @@ export function DiceDuelGame({
             </div>
           )}
 
-          {hasRolled && (
+          {hasRolled && !(player1Rolled && player2Rolled) && (
             <div className="p-4 bg-gradient-to-r from-amber-50 to-rose-50 border-2 border-amber-200 rounded-xl">
               <p className="text-sm font-semibold text-amber-800">
                 ✓ You've rolled. Waiting for the other player...
               </p>
             </div>
           )}
+
+          {hasRolled && player1Rolled && player2Rolled && !hasRevealedRoll && (
+            <div className="space-y-4">
+              <p className="text-sm font-semibold text-gray-700">
+                Both players have rolled. Reveal your roll so the dice can be cast.
+              </p>
+              <button
+                onClick={handleRevealRoll}
+                disabled={isBusy}
+                className="w-full py-4 rounded-xl font-bold text-white text-lg bg-gradient-to-r from-rose-500 via-red-500 to-amber-500 hover:from-rose-600 hover:via-red-600 hover:to-amber-600 disabled:from-gray-200 disabled:to-gray-300 disabled:text-gray-500 transition-all shadow-xl hover:shadow-2xl transform hover:scale-105 disabled:transform-none"
+              >
+                {loading ? 'Revealing...' : 'Reveal Roll'}
+              </button>
+            </div>
+          )}
+
+          {hasRevealedRoll && (
+            <div className="p-4 bg-gradient-to-r from-amber-50 to-rose-50 border-2 border-amber-200 rounded-xl">
+              <p className="text-sm font-semibold text-amber-800">
+                ✓ You've revealed your roll. Waiting for the other player to reveal...
+              </p>
+            </div>
+          )}
         </div>
       )}
 
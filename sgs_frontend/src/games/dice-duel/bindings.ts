@@ -40,16 +40,30 @@ export const networks = {
 
 
 export interface Game {
+  /**
+   * Last ledger of the current roll or reveal phase
+   */
+  action_deadline: u32;
+  /**
+   * Roll commitments and their reveals
+   */
+  beacon: Beacon;
+  /**
+   * Player whose draw offer is waiting for the opponent to accept
+   */
+  draw_offer: Option<string>;
+  /**
+   * Set when the players agreed to a draw, ending the game without a winner
+   */
+  drawn: boolean;
   player1: string;
   player1_die1: Option<u32>;
   player1_die2: Option<u32>;
   player1_points: i128;
-  player1_rolled: boolean;
   player2: string;
   player2_die1: Option<u32>;
   player2_die2: Option<u32>;
   player2_points: i128;
-  player2_rolled: boolean;
   winner: Option<string>;
 }
 
@@ -58,49 +72,149 @@ export const Errors = {
   2: {message:"NotPlayer"},
   3: {message:"AlreadyRolled"},
   4: {message:"BothPlayersNotRolled"},
-  5: {message:"GameAlreadyEnded"}
+  5: {message:"GameAlreadyEnded"},
+  6: {message:"SessionExists"},
+  7: {message:"AlreadyRevealed"},
+  8: {message:"CommitmentMismatch"},
+  9: {message:"BothPlayersNotRevealed"},
+  10: {message:"CommitDeadlinePassed"},
+  11: {message:"RevealDeadlinePassed"},
+  12: {message:"RandomnessNotReady"},
+  13: {message:"DeadlineNotReached"},
+  14: {message:"OpponentNotStalled"},
+  15: {message:"PlayerStalled"},
+  16: {message:"NoDrawOffer"}
 }
 
-export type DataKey = {tag: "Game", values: readonly [u32]} | {tag: "GameHubAddress", values: void} | {tag: "Admin", values: void};
+export type DataKey = {tag: "Game", values: readonly [u32]};
+
+
+/**
+ * Commitment/reveal state of one beacon round
+ */
+export interface Beacon {
+  /**
+   * Last ledger on which a commitment is accepted
+   */
+  commit_deadline: u32;
+  commitments: Map<string, Buffer>;
+  parties: Array<string>;
+  /**
+   * Last ledger on which a reveal is accepted, set by the last commitment
+   */
+  reveal_deadline: Option<u32>;
+  /**
+   * Ledgers parties have to reveal once the last commitment lands
+   */
+  reveal_window: u32;
+  reveals: Map<string, Buffer>;
+}
 
 export interface Client {
+  /**
+   * Construct and simulate a start_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Start a new game between two players with points.
+   * This creates a session in the Game Hub and locks points before starting the game.
+   * 
+   * **CRITICAL:** This method requires authorization from THIS contract (not players).
+   * The Game Hub will call `game_id.require_auth()` which checks this contract's address.
+   * 
+   * # Arguments
+   * * `session_id` - Unique session identifier (u32)
+   * * `player1` - Address of first player
+   * * `player2` - Address of second player
+   * * `player1_points` - Points amount committed by player 1
+   * * `player2_points` - Points amount committed by player 2
+   */
+  start_game: ({session_id, player1, player2, player1_points, player2_points}: {session_id: u32, player1: string, player2: string, player1_points: i128, player2_points: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
   /**
    * Construct and simulate a roll transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    * Commit a roll for the current game.
-   * Both players must roll before the winner can be revealed.
+   * Both players must roll and reveal before the winner can be revealed.
    * 
    * # Arguments
    * * `session_id` - The session ID of the game
    * * `player` - Address of the player rolling the dice
+   * * `commitment` - `keccak256(value)` of a secret random 32-byte value
    */
-  roll: ({session_id, player}: {session_id: u32, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+  roll: ({session_id, player, commitment}: {session_id: u32, player: string, commitment: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
   /**
-   * Construct and simulate a get_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get the current GameHub contract address
+   * Construct and simulate a reveal_roll transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Reveal the secret value committed in `roll`.
+   * Only allowed once both players have rolled, so neither value can be
+   * chosen after seeing the other.
+   * 
+   * # Arguments
+   * * `session_id` - The session ID of the game
+   * * `player` - Address of the player revealing
+   * * `value` - The value whose hash was committed
+   */
+  reveal_roll: ({session_id, player, value}: {session_id: u32, player: string, value: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a reveal_winner transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Reveal the winner of the game and submit outcome to GameHub.
+   * Can only be called after both players have rolled and revealed.
+   * This generates dice rolls for both players, determines the winner, and ends the session.
+   * 
+   * # Arguments
+   * * `session_id` - The session ID of the game
    * 
    * # Returns
-   * * `Address` - The GameHub contract address
+   * * `Address` - Address of the winning player
    */
-  get_hub: (options?: MethodOptions) => Promise<AssembledTransaction<string>>
+  reveal_winner: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<string>>>
 
   /**
-   * Construct and simulate a set_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Set a new GameHub contract address
+   * Construct and simulate a claim_timeout transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Claim the win after the action deadline has passed.
+   * The claimant must have done their part of the current phase (rolled,
+   * or revealed once both rolled) while the opponent hasn't.
    * 
    * # Arguments
-   * * `new_hub` - The new GameHub contract address
+   * * `session_id` - The session ID of the game
+   * * `player` - Address of the player claiming the win
+   * 
+   * # Returns
+   * * `Address` - Address of the winning player
    */
-  set_hub: ({new_hub}: {new_hub: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  claim_timeout: ({session_id, player}: {session_id: u32, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<string>>>
 
   /**
-   * Construct and simulate a upgrade transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Update the contract WASM hash (upgrade contract)
+   * Construct and simulate a resign transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Resign the game, handing the win to the opponent.
    * 
    * # Arguments
-   * * `new_wasm_hash` - The hash of the new WASM binary
+   * * `session_id` - The session ID of the game
+   * * `player` - Address of the resigning player
+   * 
+   * # Returns
+   * * `Address` - Address of the winning player
    */
-  upgrade: ({new_wasm_hash}: {new_wasm_hash: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  resign: ({session_id, player}: {session_id: u32, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<string>>>
+
+  /**
+   * Construct and simulate a offer_draw transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Offer the opponent a draw. The offer stands until the opponent accepts
+   * it or the game ends; offering again replaces the opponent's own offer.
+   * 
+   * # Arguments
+   * * `session_id` - The session ID of the game
+   * * `player` - Address of the player offering the draw
+   */
+  offer_draw: ({session_id, player}: {session_id: u32, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a accept_draw transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Accept the opponent's draw offer, ending the game as a draw.
+   * 
+   * # Arguments
+   * * `session_id` - The session ID of the game
+   * * `player` - Address of the player accepting the draw
+   */
+  accept_draw: ({session_id, player}: {session_id: u32, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
   /**
    * Construct and simulate a get_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
@@ -133,41 +247,64 @@ export interface Client {
   set_admin: ({new_admin}: {new_admin: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
 
   /**
-   * Construct and simulate a start_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Start a new game between two players with points.
-   * This creates a session in the Game Hub and locks points before starting the game.
+   * Construct and simulate a get_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the current GameHub contract address
    * 
-   * **CRITICAL:** This method requires authorization from THIS contract (not players).
-   * The Game Hub will call `game_id.require_auth()` which checks this contract's address.
+   * # Returns
+   * * `Address` - The GameHub contract address
+   */
+  get_hub: (options?: MethodOptions) => Promise<AssembledTransaction<string>>
+
+  /**
+   * Construct and simulate a set_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set a new GameHub contract address
    * 
    * # Arguments
-   * * `session_id` - Unique session identifier (u32)
-   * * `player1` - Address of first player
-   * * `player2` - Address of second player
-   * * `player1_points` - Points amount committed by player 1
-   * * `player2_points` - Points amount committed by player 2
+   * * `new_hub` - The new GameHub contract address
    */
-  start_game: ({session_id, player1, player2, player1_points, player2_points}: {session_id: u32, player1: string, player2: string, player1_points: i128, player2_points: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+  set_hub: ({new_hub}: {new_hub: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
 
   /**
-   * Construct and simulate a reveal_winner transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Reveal the winner of the game and submit outcome to GameHub.
-   * Can only be called after both players have rolled.
-   * This generates dice rolls for both players, determines the winner, and ends the session.
+   * Construct and simulate a get_randomness_provider transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the randomness provider mixed into every roll, if any
    * 
-   * # Arguments
-   * * `session_id` - The session ID of the game
+   * # Returns
+   * * `Option<Address>` - The randomness provider contract address
+   */
+  get_randomness_provider: (options?: MethodOptions) => Promise<AssembledTransaction<Option<string>>>
+
+  /**
+   * Construct and simulate a get_action_timeout transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the number of ledgers players have to roll, and then to reveal
    * 
    * # Returns
-   * * `Address` - Address of the winning player
+   * * `u32` - The action timeout in ledgers
    */
-  reveal_winner: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<string>>>
+  get_action_timeout: (options?: MethodOptions) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a set_action_timeout transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set the action timeout for new phases (games in progress keep their deadline)
+   * 
+   * # Arguments
+   * * `ledgers` - The action timeout in ledgers
+   */
+  set_action_timeout: ({ledgers}: {ledgers: u32}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a upgrade transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Update the contract WASM hash (upgrade contract)
+   * 
+   * # Arguments
+   * * `new_wasm_hash` - The hash of the new WASM binary
+   */
+  upgrade: ({new_wasm_hash}: {new_wasm_hash: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
 
 }
 export class Client extends ContractClient {
   static async deploy<T = Client>(
         /** Constructor/Initialization Args for the contract's `__constructor` method */
-        {admin, game_hub}: {admin: string, game_hub: string},
+        {admin, game_hub, randomness_provider}: {admin: string, game_hub: string, randomness_provider: Option<string>},
     /** Options for initializing a Client as well as for calling a method, with extras specific to deploying. */
     options: MethodOptions &
       Omit<ContractClientOptions, "contractId"> & {
@@ -179,35 +316,52 @@ export class Client extends ContractClient {
         format?: "hex" | "base64";
       }
   ): Promise<AssembledTransaction<T>> {
-    return ContractClient.deploy({admin, game_hub}, options)
+    return ContractClient.deploy({admin, game_hub, randomness_provider}, options)
   }
   constructor(public readonly options: ContractClientOptions) {
     super(
-      new ContractSpec([ "AAAAAQAAAAAAAAAAAAAABEdhbWUAAAALAAAAAAAAAAdwbGF5ZXIxAAAAABMAAAAAAAAADHBsYXllcjFfZGllMQAAA+gAAAAEAAAAAAAAAAxwbGF5ZXIxX2RpZTIAAAPoAAAABAAAAAAAAAAOcGxheWVyMV9wb2ludHMAAAAAAAsAAAAAAAAADnBsYXllcjFfcm9sbGVkAAAAAAABAAAAAAAAAAdwbGF5ZXIyAAAAABMAAAAAAAAADHBsYXllcjJfZGllMQAAA+gAAAAEAAAAAAAAAAxwbGF5ZXIyX2RpZTIAAAPoAAAABAAAAAAAAAAOcGxheWVyMl9wb2ludHMAAAAAAAsAAAAAAAAADnBsYXllcjJfcm9sbGVkAAAAAAABAAAAAAAAAAZ3aW5uZXIAAAAAA+gAAAAT",
-        "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAABQAAAAAAAAAMR2FtZU5vdEZvdW5kAAAAAQAAAAAAAAAJTm90UGxheWVyAAAAAAAAAgAAAAAAAAANQWxyZWFkeVJvbGxlZAAAAAAAAAMAAAAAAAAAFEJvdGhQbGF5ZXJzTm90Um9sbGVkAAAABAAAAAAAAAAQR2FtZUFscmVhZHlFbmRlZAAAAAU=",
-        "AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAAAwAAAAEAAAAAAAAABEdhbWUAAAABAAAABAAAAAAAAAAAAAAADkdhbWVIdWJBZGRyZXNzAAAAAAAAAAAAAAAAAAVBZG1pbgAAAA==",
-        "AAAAAAAAAMpDb21taXQgYSByb2xsIGZvciB0aGUgY3VycmVudCBnYW1lLgpCb3RoIHBsYXllcnMgbXVzdCByb2xsIGJlZm9yZSB0aGUgd2lubmVyIGNhbiBiZSByZXZlYWxlZC4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVGhlIHNlc3Npb24gSUQgb2YgdGhlIGdhbWUKKiBgcGxheWVyYCAtIEFkZHJlc3Mgb2YgdGhlIHBsYXllciByb2xsaW5nIHRoZSBkaWNlAAAAAAAEcm9sbAAAAAIAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAPpAAAD7QAAAAAAAAAD",
-        "AAAAAAAAAF5HZXQgdGhlIGN1cnJlbnQgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzCgojIFJldHVybnMKKiBgQWRkcmVzc2AgLSBUaGUgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzAAAAAAAHZ2V0X2h1YgAAAAAAAAAAAQAAABM=",
-        "AAAAAAAAAF5TZXQgYSBuZXcgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzCgojIEFyZ3VtZW50cwoqIGBuZXdfaHViYCAtIFRoZSBuZXcgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzAAAAAAAHc2V0X2h1YgAAAAABAAAAAAAAAAduZXdfaHViAAAAABMAAAAA",
-        "AAAAAAAAAHFVcGRhdGUgdGhlIGNvbnRyYWN0IFdBU00gaGFzaCAodXBncmFkZSBjb250cmFjdCkKCiMgQXJndW1lbnRzCiogYG5ld193YXNtX2hhc2hgIC0gVGhlIGhhc2ggb2YgdGhlIG5ldyBXQVNNIGJpbmFyeQAAAAAAAAd1cGdyYWRlAAAAAAEAAAAAAAAADW5ld193YXNtX2hhc2gAAAAAAAPuAAAAIAAAAAA=",
+      new ContractSpec([ "AAAAAQAAAAAAAAAAAAAABEdhbWUAAAANAAAAL0xhc3QgbGVkZ2VyIG9mIHRoZSBjdXJyZW50IHJvbGwgb3IgcmV2ZWFsIHBoYXNlAAAAAA9hY3Rpb25fZGVhZGxpbmUAAAAABAAAACJSb2xsIGNvbW1pdG1lbnRzIGFuZCB0aGVpciByZXZlYWxzAAAAAAAGYmVhY29uAAAAAAfQAAAABkJlYWNvbgAAAAAAPVBsYXllciB3aG9zZSBkcmF3IG9mZmVyIGlzIHdhaXRpbmcgZm9yIHRoZSBvcHBvbmVudCB0byBhY2NlcHQAAAAAAAAKZHJhd19vZmZlcgAAAAAD6AAAABMAAABHU2V0IHdoZW4gdGhlIHBsYXllcnMgYWdyZWVkIHRvIGEgZHJhdywgZW5kaW5nIHRoZSBnYW1lIHdpdGhvdXQgYSB3aW5uZXIAAAAABWRyYXduAAAAAAAAAQAAAAAAAAAHcGxheWVyMQAAAAATAAAAAAAAAAxwbGF5ZXIxX2RpZTEAAAPoAAAABAAAAAAAAAAMcGxheWVyMV9kaWUyAAAD6AAAAAQAAAAAAAAADnBsYXllcjFfcG9pbnRzAAAAAAALAAAAAAAAAAdwbGF5ZXIyAAAAABMAAAAAAAAADHBsYXllcjJfZGllMQAAA+gAAAAEAAAAAAAAAAxwbGF5ZXIyX2RpZTIAAAPoAAAABAAAAAAAAAAOcGxheWVyMl9wb2ludHMAAAAAAAsAAAAAAAAABndpbm5lcgAAAAAD6AAAABM=",
+        "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAAEAAAAAAAAAAMR2FtZU5vdEZvdW5kAAAAAQAAAAAAAAAJTm90UGxheWVyAAAAAAAAAgAAAAAAAAANQWxyZWFkeVJvbGxlZAAAAAAAAAMAAAAAAAAAFEJvdGhQbGF5ZXJzTm90Um9sbGVkAAAABAAAAAAAAAAQR2FtZUFscmVhZHlFbmRlZAAAAAUAAAAAAAAADVNlc3Npb25FeGlzdHMAAAAAAAAGAAAAAAAAAA9BbHJlYWR5UmV2ZWFsZWQAAAAABwAAAAAAAAASQ29tbWl0bWVudE1pc21hdGNoAAAAAAAIAAAAAAAAABZCb3RoUGxheWVyc05vdFJldmVhbGVkAAAAAAAJAAAAAAAAABRDb21taXREZWFkbGluZVBhc3NlZAAAAAoAAAAAAAAAFFJldmVhbERlYWRsaW5lUGFzc2VkAAAACwAAAAAAAAASUmFuZG9tbmVzc05vdFJlYWR5AAAAAAAMAAAAAAAAABJEZWFkbGluZU5vdFJlYWNoZWQAAAAAAA0AAAAAAAAAEk9wcG9uZW50Tm90U3RhbGxlZAAAAAAADgAAAAAAAAANUGxheWVyU3RhbGxlZAAAAAAAAA8AAAAAAAAAC05vRHJhd09mZmVyAAAAABA=",
+        "AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAAAQAAAAEAAAAAAAAABEdhbWUAAAABAAAABA==",
+        "AAAAAQAAACtDb21taXRtZW50L3JldmVhbCBzdGF0ZSBvZiBvbmUgYmVhY29uIHJvdW5kAAAAAAAAAAAGQmVhY29uAAAAAAAGAAAALUxhc3QgbGVkZ2VyIG9uIHdoaWNoIGEgY29tbWl0bWVudCBpcyBhY2NlcHRlZAAAAAAAAA9jb21taXRfZGVhZGxpbmUAAAAABAAAAAAAAAALY29tbWl0bWVudHMAAAAD7AAAABMAAAPuAAAAIAAAAAAAAAAHcGFydGllcwAAAAPqAAAAEwAAAEVMYXN0IGxlZGdlciBvbiB3aGljaCBhIHJldmVhbCBpcyBhY2NlcHRlZCwgc2V0IGJ5IHRoZSBsYXN0IGNvbW1pdG1lbnQAAAAAAAAPcmV2ZWFsX2RlYWRsaW5lAAAAA+gAAAAEAAAAPUxlZGdlcnMgcGFydGllcyBoYXZlIHRvIHJldmVhbCBvbmNlIHRoZSBsYXN0IGNvbW1pdG1lbnQgbGFuZHMAAAAAAAANcmV2ZWFsX3dpbmRvdwAAAAAAAAQAAAAAAAAAB3JldmVhbHMAAAAD7AAAABMAAAAO",
+        "AAAAAAAAAPBJbml0aWFsaXplIHRoZSBjb250cmFjdCB3aXRoIEdhbWVIdWIgYWRkcmVzcyBhbmQgYWRtaW4KCiMgQXJndW1lbnRzCiogYGFkbWluYCAtIEFkbWluIGFkZHJlc3MgKGNhbiB1cGdyYWRlIGNvbnRyYWN0KQoqIGBnYW1lX2h1YmAgLSBBZGRyZXNzIG9mIHRoZSBHYW1lSHViIGNvbnRyYWN0CiogYHJhbmRvbW5lc3NfcHJvdmlkZXJgIC0gT3B0aW9uYWwgcmFuZG9tbmVzcyBjb250cmFjdCBtaXhlZCBpbnRvIGV2ZXJ5IHJvbGwAAAANX19jb25zdHJ1Y3RvcgAAAAAAAAMAAAAAAAAABWFkbWluAAAAAAAAEwAAAAAAAAAIZ2FtZV9odWIAAAATAAAAAAAAABNyYW5kb21uZXNzX3Byb3ZpZGVyAAAAA+gAAAATAAAAAA==",
+        "AAAAAAAAAipTdGFydCBhIG5ldyBnYW1lIGJldHdlZW4gdHdvIHBsYXllcnMgd2l0aCBwb2ludHMuClRoaXMgY3JlYXRlcyBhIHNlc3Npb24gaW4gdGhlIEdhbWUgSHViIGFuZCBsb2NrcyBwb2ludHMgYmVmb3JlIHN0YXJ0aW5nIHRoZSBnYW1lLgoKKipDUklUSUNBTDoqKiBUaGlzIG1ldGhvZCByZXF1aXJlcyBhdXRob3JpemF0aW9uIGZyb20gVEhJUyBjb250cmFjdCAobm90IHBsYXllcnMpLgpUaGUgR2FtZSBIdWIgd2lsbCBjYWxsIGBnYW1lX2lkLnJlcXVpcmVfYXV0aCgpYCB3aGljaCBjaGVja3MgdGhpcyBjb250cmFjdCdzIGFkZHJlc3MuCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIFVuaXF1ZSBzZXNzaW9uIGlkZW50aWZpZXIgKHUzMikKKiBgcGxheWVyMWAgLSBBZGRyZXNzIG9mIGZpcnN0IHBsYXllcgoqIGBwbGF5ZXIyYCAtIEFkZHJlc3Mgb2Ygc2Vjb25kIHBsYXllcgoqIGBwbGF5ZXIxX3BvaW50c2AgLSBQb2ludHMgYW1vdW50IGNvbW1pdHRlZCBieSBwbGF5ZXIgMQoqIGBwbGF5ZXIyX3BvaW50c2AgLSBQb2ludHMgYW1vdW50IGNvbW1pdHRlZCBieSBwbGF5ZXIgMgAAAAAACnN0YXJ0X2dhbWUAAAAAAAUAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAAB3BsYXllcjEAAAAAEwAAAAAAAAAHcGxheWVyMgAAAAATAAAAAAAAAA5wbGF5ZXIxX3BvaW50cwAAAAAACwAAAAAAAAAOcGxheWVyMl9wb2ludHMAAAAAAAsAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAARpDb21taXQgYSByb2xsIGZvciB0aGUgY3VycmVudCBnYW1lLgpCb3RoIHBsYXllcnMgbXVzdCByb2xsIGFuZCByZXZlYWwgYmVmb3JlIHRoZSB3aW5uZXIgY2FuIGJlIHJldmVhbGVkLgoKIyBBcmd1bWVudHMKKiBgc2Vzc2lvbl9pZGAgLSBUaGUgc2Vzc2lvbiBJRCBvZiB0aGUgZ2FtZQoqIGBwbGF5ZXJgIC0gQWRkcmVzcyBvZiB0aGUgcGxheWVyIHJvbGxpbmcgdGhlIGRpY2UKKiBgY29tbWl0bWVudGAgLSBga2VjY2FrMjU2KHZhbHVlKWAgb2YgYSBzZWNyZXQgcmFuZG9tIDMyLWJ5dGUgdmFsdWUAAAAAAARyb2xsAAAAAwAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAApjb21taXRtZW50AAAAAAPuAAAAIAAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAASRSZXZlYWwgdGhlIHNlY3JldCB2YWx1ZSBjb21taXR0ZWQgaW4gYHJvbGxgLgpPbmx5IGFsbG93ZWQgb25jZSBib3RoIHBsYXllcnMgaGF2ZSByb2xsZWQsIHNvIG5laXRoZXIgdmFsdWUgY2FuIGJlCmNob3NlbiBhZnRlciBzZWVpbmcgdGhlIG90aGVyLgoKIyBBcmd1bWVudHMKKiBgc2Vzc2lvbl9pZGAgLSBUaGUgc2Vzc2lvbiBJRCBvZiB0aGUgZ2FtZQoqIGBwbGF5ZXJgIC0gQWRkcmVzcyBvZiB0aGUgcGxheWVyIHJldmVhbGluZwoqIGB2YWx1ZWAgLSBUaGUgdmFsdWUgd2hvc2UgaGFzaCB3YXMgY29tbWl0dGVkAAAAC3JldmVhbF9yb2xsAAAAAAMAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAAAAAAFdmFsdWUAAAAAAAPuAAAAIAAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAUVSZXZlYWwgdGhlIHdpbm5lciBvZiB0aGUgZ2FtZSBhbmQgc3VibWl0IG91dGNvbWUgdG8gR2FtZUh1Yi4KQ2FuIG9ubHkgYmUgY2FsbGVkIGFmdGVyIGJvdGggcGxheWVycyBoYXZlIHJvbGxlZCBhbmQgcmV2ZWFsZWQuClRoaXMgZ2VuZXJhdGVzIGRpY2Ugcm9sbHMgZm9yIGJvdGggcGxheWVycywgZGV0ZXJtaW5lcyB0aGUgd2lubmVyLCBhbmQgZW5kcyB0aGUgc2Vzc2lvbi4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVGhlIHNlc3Npb24gSUQgb2YgdGhlIGdhbWUKCiMgUmV0dXJucwoqIGBBZGRyZXNzYCAtIEFkZHJlc3Mgb2YgdGhlIHdpbm5pbmcgcGxheWVyAAAAAAAADXJldmVhbF93aW5uZXIAAAAAAAABAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAQAAA+kAAAATAAAAAw==",
+        "AAAAAAAAAVVDbGFpbSB0aGUgd2luIGFmdGVyIHRoZSBhY3Rpb24gZGVhZGxpbmUgaGFzIHBhc3NlZC4KVGhlIGNsYWltYW50IG11c3QgaGF2ZSBkb25lIHRoZWlyIHBhcnQgb2YgdGhlIGN1cnJlbnQgcGhhc2UgKHJvbGxlZCwKb3IgcmV2ZWFsZWQgb25jZSBib3RoIHJvbGxlZCkgd2hpbGUgdGhlIG9wcG9uZW50IGhhc24ndC4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVGhlIHNlc3Npb24gSUQgb2YgdGhlIGdhbWUKKiBgcGxheWVyYCAtIEFkZHJlc3Mgb2YgdGhlIHBsYXllciBjbGFpbWluZyB0aGUgd2luCgojIFJldHVybnMKKiBgQWRkcmVzc2AgLSBBZGRyZXNzIG9mIHRoZSB3aW5uaW5nIHBsYXllcgAAAAAAAA1jbGFpbV90aW1lb3V0AAAAAAAAAgAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAQAAA+kAAAATAAAAAw==",
+        "AAAAAAAAAM5SZXNpZ24gdGhlIGdhbWUsIGhhbmRpbmcgdGhlIHdpbiB0byB0aGUgb3Bwb25lbnQuCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIFRoZSBzZXNzaW9uIElEIG9mIHRoZSBnYW1lCiogYHBsYXllcmAgLSBBZGRyZXNzIG9mIHRoZSByZXNpZ25pbmcgcGxheWVyCgojIFJldHVybnMKKiBgQWRkcmVzc2AgLSBBZGRyZXNzIG9mIHRoZSB3aW5uaW5nIHBsYXllcgAAAAAABnJlc2lnbgAAAAAAAgAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAQAAA+kAAAATAAAAAw==",
+        "AAAAAAAAAPtPZmZlciB0aGUgb3Bwb25lbnQgYSBkcmF3LiBUaGUgb2ZmZXIgc3RhbmRzIHVudGlsIHRoZSBvcHBvbmVudCBhY2NlcHRzCml0IG9yIHRoZSBnYW1lIGVuZHM7IG9mZmVyaW5nIGFnYWluIHJlcGxhY2VzIHRoZSBvcHBvbmVudCdzIG93biBvZmZlci4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVGhlIHNlc3Npb24gSUQgb2YgdGhlIGdhbWUKKiBgcGxheWVyYCAtIEFkZHJlc3Mgb2YgdGhlIHBsYXllciBvZmZlcmluZyB0aGUgZHJhdwAAAAAKb2ZmZXJfZHJhdwAAAAAAAgAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAKtBY2NlcHQgdGhlIG9wcG9uZW50J3MgZHJhdyBvZmZlciwgZW5kaW5nIHRoZSBnYW1lIGFzIGEgZHJhdy4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVGhlIHNlc3Npb24gSUQgb2YgdGhlIGdhbWUKKiBgcGxheWVyYCAtIEFkZHJlc3Mgb2YgdGhlIHBsYXllciBhY2NlcHRpbmcgdGhlIGRyYXcAAAAAC2FjY2VwdF9kcmF3AAAAAAIAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAPpAAAAAgAAAAM=",
         "AAAAAAAAAJNHZXQgZ2FtZSBpbmZvcm1hdGlvbi4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVGhlIHNlc3Npb24gSUQgb2YgdGhlIGdhbWUKCiMgUmV0dXJucwoqIGBHYW1lYCAtIFRoZSBnYW1lIHN0YXRlIChpbmNsdWRlcyBkaWNlIGFmdGVyIGdhbWUgZW5kcykAAAAACGdldF9nYW1lAAAAAQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAPpAAAH0AAAAARHYW1lAAAAAw==",
         "AAAAAAAAAEhHZXQgdGhlIGN1cnJlbnQgYWRtaW4gYWRkcmVzcwoKIyBSZXR1cm5zCiogYEFkZHJlc3NgIC0gVGhlIGFkbWluIGFkZHJlc3MAAAAJZ2V0X2FkbWluAAAAAAAAAAAAAAEAAAAT",
         "AAAAAAAAAEpTZXQgYSBuZXcgYWRtaW4gYWRkcmVzcwoKIyBBcmd1bWVudHMKKiBgbmV3X2FkbWluYCAtIFRoZSBuZXcgYWRtaW4gYWRkcmVzcwAAAAAACXNldF9hZG1pbgAAAAAAAAEAAAAAAAAACW5ld19hZG1pbgAAAAAAABMAAAAA",
-        "AAAAAAAAAipTdGFydCBhIG5ldyBnYW1lIGJldHdlZW4gdHdvIHBsYXllcnMgd2l0aCBwb2ludHMuClRoaXMgY3JlYXRlcyBhIHNlc3Npb24gaW4gdGhlIEdhbWUgSHViIGFuZCBsb2NrcyBwb2ludHMgYmVmb3JlIHN0YXJ0aW5nIHRoZSBnYW1lLgoKKipDUklUSUNBTDoqKiBUaGlzIG1ldGhvZCByZXF1aXJlcyBhdXRob3JpemF0aW9uIGZyb20gVEhJUyBjb250cmFjdCAobm90IHBsYXllcnMpLgpUaGUgR2FtZSBIdWIgd2lsbCBjYWxsIGBnYW1lX2lkLnJlcXVpcmVfYXV0aCgpYCB3aGljaCBjaGVja3MgdGhpcyBjb250cmFjdCdzIGFkZHJlc3MuCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIFVuaXF1ZSBzZXNzaW9uIGlkZW50aWZpZXIgKHUzMikKKiBgcGxheWVyMWAgLSBBZGRyZXNzIG9mIGZpcnN0IHBsYXllcgoqIGBwbGF5ZXIyYCAtIEFkZHJlc3Mgb2Ygc2Vjb25kIHBsYXllcgoqIGBwbGF5ZXIxX3BvaW50c2AgLSBQb2ludHMgYW1vdW50IGNvbW1pdHRlZCBieSBwbGF5ZXIgMQoqIGBwbGF5ZXIyX3BvaW50c2AgLSBQb2ludHMgYW1vdW50IGNvbW1pdHRlZCBieSBwbGF5ZXIgMgAAAAAACnN0YXJ0X2dhbWUAAAAAAAUAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAAB3BsYXllcjEAAAAAEwAAAAAAAAAHcGxheWVyMgAAAAATAAAAAAAAAA5wbGF5ZXIxX3BvaW50cwAAAAAACwAAAAAAAAAOcGxheWVyMl9wb2ludHMAAAAAAAsAAAABAAAD6QAAA+0AAAAAAAAAAw==",
-        "AAAAAAAAAKNJbml0aWFsaXplIHRoZSBjb250cmFjdCB3aXRoIEdhbWVIdWIgYWRkcmVzcyBhbmQgYWRtaW4KCiMgQXJndW1lbnRzCiogYGFkbWluYCAtIEFkbWluIGFkZHJlc3MgKGNhbiB1cGdyYWRlIGNvbnRyYWN0KQoqIGBnYW1lX2h1YmAgLSBBZGRyZXNzIG9mIHRoZSBHYW1lSHViIGNvbnRyYWN0AAAAAA1fX2NvbnN0cnVjdG9yAAAAAAAAAgAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAAAAAAhnYW1lX2h1YgAAABMAAAAA",
-        "AAAAAAAAAThSZXZlYWwgdGhlIHdpbm5lciBvZiB0aGUgZ2FtZSBhbmQgc3VibWl0IG91dGNvbWUgdG8gR2FtZUh1Yi4KQ2FuIG9ubHkgYmUgY2FsbGVkIGFmdGVyIGJvdGggcGxheWVycyBoYXZlIHJvbGxlZC4KVGhpcyBnZW5lcmF0ZXMgZGljZSByb2xscyBmb3IgYm90aCBwbGF5ZXJzLCBkZXRlcm1pbmVzIHRoZSB3aW5uZXIsIGFuZCBlbmRzIHRoZSBzZXNzaW9uLgoKIyBBcmd1bWVudHMKKiBgc2Vzc2lvbl9pZGAgLSBUaGUgc2Vzc2lvbiBJRCBvZiB0aGUgZ2FtZQoKIyBSZXR1cm5zCiogYEFkZHJlc3NgIC0gQWRkcmVzcyBvZiB0aGUgd2lubmluZyBwbGF5ZXIAAAANcmV2ZWFsX3dpbm5lcgAAAAAAAAEAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAABAAAD6QAAABMAAAAD" ]),
+        "AAAAAAAAAF5HZXQgdGhlIGN1cnJlbnQgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzCgojIFJldHVybnMKKiBgQWRkcmVzc2AgLSBUaGUgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzAAAAAAAHZ2V0X2h1YgAAAAAAAAAAAQAAABM=",
+        "AAAAAAAAAF5TZXQgYSBuZXcgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzCgojIEFyZ3VtZW50cwoqIGBuZXdfaHViYCAtIFRoZSBuZXcgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzAAAAAAAHc2V0X2h1YgAAAAABAAAAAAAAAAduZXdfaHViAAAAABMAAAAA",
+        "AAAAAAAAAINHZXQgdGhlIHJhbmRvbW5lc3MgcHJvdmlkZXIgbWl4ZWQgaW50byBldmVyeSByb2xsLCBpZiBhbnkKCiMgUmV0dXJucwoqIGBPcHRpb248QWRkcmVzcz5gIC0gVGhlIHJhbmRvbW5lc3MgcHJvdmlkZXIgY29udHJhY3QgYWRkcmVzcwAAAAAXZ2V0X3JhbmRvbW5lc3NfcHJvdmlkZXIAAAAAAAAAAAEAAAPoAAAAEw==",
+        "AAAAAAAAAHVHZXQgdGhlIG51bWJlciBvZiBsZWRnZXJzIHBsYXllcnMgaGF2ZSB0byByb2xsLCBhbmQgdGhlbiB0byByZXZlYWwKCiMgUmV0dXJucwoqIGB1MzJgIC0gVGhlIGFjdGlvbiB0aW1lb3V0IGluIGxlZGdlcnMAAAAAAAASZ2V0X2FjdGlvbl90aW1lb3V0AAAAAAAAAAAAAQAAAAQ=",
+        "AAAAAAAAAIZTZXQgdGhlIGFjdGlvbiB0aW1lb3V0IGZvciBuZXcgcGhhc2VzIChnYW1lcyBpbiBwcm9ncmVzcyBrZWVwIHRoZWlyIGRlYWRsaW5lKQoKIyBBcmd1bWVudHMKKiBgbGVkZ2Vyc2AgLSBUaGUgYWN0aW9uIHRpbWVvdXQgaW4gbGVkZ2VycwAAAAAAEnNldF9hY3Rpb25fdGltZW91dAAAAAAAAQAAAAAAAAAHbGVkZ2VycwAAAAAEAAAAAA==",
+        "AAAAAAAAAHFVcGRhdGUgdGhlIGNvbnRyYWN0IFdBU00gaGFzaCAodXBncmFkZSBjb250cmFjdCkKCiMgQXJndW1lbnRzCiogYG5ld193YXNtX2hhc2hgIC0gVGhlIGhhc2ggb2YgdGhlIG5ldyBXQVNNIGJpbmFyeQAAAAAAAAd1cGdyYWRlAAAAAAEAAAAAAAAADW5ld193YXNtX2hhc2gAAAAAAAPuAAAAIAAAAAA=" ]),
       options
     )
   }
   public readonly fromJSON = {
-    roll: this.txFromJSON<Result<void>>,
-        get_hub: this.txFromJSON<string>,
-        set_hub: this.txFromJSON<null>,
-        upgrade: this.txFromJSON<null>,
+    start_game: this.txFromJSON<Result<void>>,
+        roll: this.txFromJSON<Result<void>>,
+        reveal_roll: this.txFromJSON<Result<void>>,
+        reveal_winner: this.txFromJSON<Result<string>>,
+        claim_timeout: this.txFromJSON<Result<string>>,
+        resign: this.txFromJSON<Result<string>>,
+        offer_draw: this.txFromJSON<Result<void>>,
+        accept_draw: this.txFromJSON<Result<void>>,
         get_game: this.txFromJSON<Result<Game>>,
         get_admin: this.txFromJSON<string>,
         set_admin: this.txFromJSON<null>,
-        start_game: this.txFromJSON<Result<void>>,
-        reveal_winner: this.txFromJSON<Result<string>>
+        get_hub: this.txFromJSON<string>,
+        set_hub: this.txFromJSON<null>,
+        get_randomness_provider: this.txFromJSON<Option<string>>,
+        get_action_timeout: this.txFromJSON<u32>,
+        set_action_timeout: this.txFromJSON<null>,
+        upgrade: this.txFromJSON<null>
   }
 }
\ No newline at end of file
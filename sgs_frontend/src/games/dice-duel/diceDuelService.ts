@@ -5,6 +5,7 @@ import { Buffer } from 'buffer';
 import { signAndSendViaLaunchtube } from '@/utils/transactionHelper';
 import { calculateValidUntilLedger } from '@/utils/ledgerUtils';
 import { injectSignedAuthEntry } from '@/utils/authEntryUtils';
+import { keccak256, randomSecret, saveCommitSecret, loadCommitSecret, clearCommitSecret } from '@/utils/commitReveal';
 
 type ClientOptions = contract.ClientOptions;
 
@@ -576,7 +577,9 @@ export class DiceDuelService {
   }
 
   /**
-   * Commit a dice roll
+   * Commit a dice roll.
+   * The commitment is keccak256 of 32 random bytes of entropy, which are
+   * stored in this browser for revealRoll.
    */
   async roll(
     sessionId: number,
@@ -584,10 +587,16 @@ export class DiceDuelService {
     signer: Pick<contract.ClientOptions, 'signTransaction' | 'signAuthEntry'>,
     authTtlMinutes?: number
   ) {
+    const entropy = randomSecret();
+
+    // Stored before sending, so a commitment that lands always has its entropy
+    saveCommitSecret(this.contractId, sessionId, playerAddress, entropy.toString('hex'));
+
     const client = this.createSigningClient(playerAddress, signer);
     const tx = await client.roll({
       session_id: sessionId,
       player: playerAddress,
+      commitment: keccak256(entropy),
     }, DEFAULT_METHOD_OPTIONS);
     // NOTE: Contract methods automatically simulate - footprint is already prepared
 
@@ -612,6 +621,50 @@ export class DiceDuelService {
     }
   }
 
+  /**
+   * Reveal the entropy committed from this browser, once both players have rolled
+   */
+  async revealRoll(
+    sessionId: number,
+    playerAddress: string,
+    signer: Pick<contract.ClientOptions, 'signTransaction' | 'signAuthEntry'>,
+    authTtlMinutes?: number
+  ) {
+    const entropy = loadCommitSecret<string>(this.contractId, sessionId, playerAddress);
+    if (!entropy) {
+      throw new Error('No roll for this game was committed from this browser');
+    }
+
+    const client = this.createSigningClient(playerAddress, signer);
+    const tx = await client.reveal_roll({
+      session_id: sessionId,
+      player: playerAddress,
+      value: Buffer.from(entropy, 'hex'),
+    }, DEFAULT_METHOD_OPTIONS);
+    // NOTE: Contract methods automatically simulate - footprint is already prepared
+
+    const validUntilLedgerSeq = authTtlMinutes
+      ? await calculateValidUntilLedger(RPC_URL, authTtlMinutes)
+      : await calculateValidUntilLedger(RPC_URL, DEFAULT_AUTH_TTL_MINUTES);
+
+    try {
+      const sentTx = await signAndSendViaLaunchtube(tx, DEFAULT_METHOD_OPTIONS.timeoutInSeconds, validUntilLedgerSeq);
+
+      if (sentTx.getTransactionResponse?.status === 'FAILED') {
+        const errorMessage = this.extractErrorFromDiagnostics(sentTx.getTransactionResponse);
+        throw new Error(`Transaction failed: ${errorMessage}`);
+      }
+
+      clearCommitSecret(this.contractId, sessionId, playerAddress);
+      return sentTx.result;
+    } catch (err) {
+      if (err instanceof Error && err.message.includes('Transaction failed!')) {
+        throw new Error('Transaction failed - check if both players have rolled and you haven\'t already revealed');
+      }
+      throw err;
+    }
+  }
+
   /**
    * Reveal the winner after both players have rolled
    */
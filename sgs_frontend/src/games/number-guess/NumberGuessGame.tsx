@@ -5,6 +5,7 @@ import { getLocationSearch } from '@/utils/location';
 import { useWallet } from '@/hooks/useWallet';
 import { NUMBER_GUESS_CONTRACT } from '@/utils/constants';
 import { getFundedSimulationSourceAddress } from '@/utils/simulationUtils';
+import { hasBeaconEntry } from '@/utils/commitReveal';
 import { devWalletService, DevWalletService } from '@/services/devWalletService';
 import type { Game } from './bindings';
 
@@ -699,7 +700,7 @@ export function NumberGuessGame({
             game.player2_guess !== null && game.player2_guess !== undefined) {
           // Both players guessed, waiting for reveal
           setGamePhase('reveal');
-          setSuccess('Game loaded! Both players have guessed. You can reveal the winner.');
+          setSuccess('Game loaded! Both players have revealed their guesses. You can reveal the winner.');
         } else {
           // Still in guessing phase
           setGamePhase('guess');
@@ -765,7 +766,7 @@ export function NumberGuessGame({
     }
   };
 
-  const handleMakeGuess = async () => {
+  const handleCommitGuess = async () => {
     if (guess === null) {
       setError('Select a number to guess');
       return;
@@ -778,13 +779,34 @@ export function NumberGuessGame({
         setSuccess(null);
 
         const signer = getContractSigner();
-        await numberGuessService.makeGuess(sessionId, userAddress, guess, signer);
+        await numberGuessService.commitGuess(sessionId, userAddress, guess, signer);
 
-        setSuccess(`Guess submitted: ${guess}`);
+        setSuccess(`Guess locked in: ${guess} (hidden until both players have guessed)`);
         await loadGameState();
       } catch (err) {
-        console.error('Make guess error:', err);
-        setError(err instanceof Error ? err.message : 'Failed to make guess');
+        console.error('Commit guess error:', err);
+        setError(err instanceof Error ? err.message : 'Failed to submit guess');
+      } finally {
+        setLoading(false);
+      }
+    });
+  };
+
+  const handleRevealGuess = async () => {
+    await runAction(async () => {
+      try {
+        setLoading(true);
+        setError(null);
+        setSuccess(null);
+
+        const signer = getContractSigner();
+        await numberGuessService.revealGuess(sessionId, userAddress, signer);
+
+        setSuccess('Guess revealed!');
+        await loadGameState();
+      } catch (err) {
+        console.error('Reveal guess error:', err);
+        setError(err instanceof Error ? err.message : 'Failed to reveal guess');
       } finally {
         setLoading(false);
       }
@@ -836,8 +858,13 @@ export function NumberGuessGame({
 
   const isPlayer1 = gameState && gameState.player1 === userAddress;
   const isPlayer2 = gameState && gameState.player2 === userAddress;
-  const hasGuessed = isPlayer1 ? gameState?.player1_guess !== null && gameState?.player1_guess !== undefined :
-                     isPlayer2 ? gameState?.player2_guess !== null && gameState?.player2_guess !== undefined : false;
+  // Guesses are committed first and revealed once both players have guessed
+  const hasGuessed = !!gameState && !!(isPlayer1 || isPlayer2) && hasBeaconEntry(gameState.beacon.commitments, userAddress);
+  const hasRevealed = !!gameState && hasBeaconEntry(gameState.beacon.reveals, userAddress);
+  const bothGuessed = !!gameState &&
+    hasBeaconEntry(gameState.beacon.commitments, gameState.player1) &&
+    hasBeaconEntry(gameState.beacon.commitments, gameState.player2);
+  const storedGuess = hasGuessed ? numberGuessService.getStoredGuess(sessionId, userAddress) : null;
 
   const winningNumber = gameState?.winning_number;
   const player1Guess = gameState?.player1_guess;
@@ -1231,7 +1258,11 @@ export function NumberGuessGame({
                 Points: {(Number(gameState.player1_points) / 10000000).toFixed(2)}
               </div>
               <div className="mt-3">
-                {gameState.player1_guess !== null && gameState.player1_guess !== undefined ? (
+                {hasBeaconEntry(gameState.beacon.reveals, gameState.player1) ? (
+                  <div className="inline-block px-3 py-1 rounded-full bg-gradient-to-r from-green-400 to-emerald-500 text-white text-xs font-bold shadow-md">
+                    ✓ Revealed
+                  </div>
+                ) : hasBeaconEntry(gameState.beacon.commitments, gameState.player1) ? (
                   <div className="inline-block px-3 py-1 rounded-full bg-gradient-to-r from-green-400 to-emerald-500 text-white text-xs font-bold shadow-md">
                     ✓ Guessed
                   </div>
@@ -1252,7 +1283,11 @@ export function NumberGuessGame({
                 Points: {(Number(gameState.player2_points) / 10000000).toFixed(2)}
               </div>
               <div className="mt-3">
-                {gameState.player2_guess !== null && gameState.player2_guess !== undefined ? (
+                {hasBeaconEntry(gameState.beacon.reveals, gameState.player2) ? (
+                  <div className="inline-block px-3 py-1 rounded-full bg-gradient-to-r from-green-400 to-emerald-500 text-white text-xs font-bold shadow-md">
+                    ✓ Revealed
+                  </div>
+                ) : hasBeaconEntry(gameState.beacon.commitments, gameState.player2) ? (
                   <div className="inline-block px-3 py-1 rounded-full bg-gradient-to-r from-green-400 to-emerald-500 text-white text-xs font-bold shadow-md">
                     ✓ Guessed
                   </div>
@@ -1286,7 +1321,7 @@ export function NumberGuessGame({
                 ))}
               </div>
               <button
-                onClick={handleMakeGuess}
+                onClick={handleCommitGuess}
                 disabled={isBusy || guess === null}
                 className="w-full mt-2.5 py-4 rounded-xl font-bold text-white text-lg bg-gradient-to-r from-purple-500 via-pink-500 to-red-500 hover:from-purple-600 hover:via-pink-600 hover:to-red-600 disabled:from-gray-200 disabled:to-gray-300 disabled:text-gray-500 transition-all shadow-xl hover:shadow-2xl transform hover:scale-105 disabled:transform-none"
               >
@@ -1295,10 +1330,33 @@ export function NumberGuessGame({
             </div>
           )}
 
-          {hasGuessed && (
+          {hasGuessed && !bothGuessed && (
+            <div className="p-4 bg-gradient-to-r from-blue-50 to-cyan-50 border-2 border-blue-200 rounded-xl">
+              <p className="text-sm font-semibold text-blue-700">
+                ✓ You've made your guess{storedGuess !== null ? ` (${storedGuess})` : ''}. It stays hidden until the other player guesses...
+              </p>
+            </div>
+          )}
+
+          {hasGuessed && bothGuessed && !hasRevealed && (
+            <div className="space-y-3">
+              <p className="text-sm font-semibold text-gray-700">
+                Both players have guessed. Reveal your guess{storedGuess !== null ? ` (${storedGuess})` : ''} to settle the game.
+              </p>
+              <button
+                onClick={handleRevealGuess}
+                disabled={isBusy}
+                className="w-full py-4 rounded-xl font-bold text-white text-lg bg-gradient-to-r from-purple-500 via-pink-500 to-red-500 hover:from-purple-600 hover:via-pink-600 hover:to-red-600 disabled:from-gray-200 disabled:to-gray-300 disabled:text-gray-500 transition-all shadow-xl hover:shadow-2xl transform hover:scale-105 disabled:transform-none"
+              >
+                {loading ? 'Revealing...' : 'Reveal Guess'}
+              </button>
+            </div>
+          )}
+
+          {hasRevealed && (
             <div className="p-4 bg-gradient-to-r from-blue-50 to-cyan-50 border-2 border-blue-200 rounded-xl">
               <p className="text-sm font-semibold text-blue-700">
-                ✓ You've made your guess. Waiting for other player...
+                ✓ You've revealed your guess. Waiting for the other player to reveal...
               </p>
             </div>
           )}
@@ -5,9 +5,16 @@ import { Buffer } from 'buffer';
 import { signAndSendViaLaunchtube } from '@/utils/transactionHelper';
 import { calculateValidUntilLedger } from '@/utils/ledgerUtils';
 import { injectSignedAuthEntry } from '@/utils/authEntryUtils';
+import { keccak256, randomSecret, saveCommitSecret, loadCommitSecret, clearCommitSecret } from '@/utils/commitReveal';
 
 type ClientOptions = contract.ClientOptions;
 
+/** A committed guess and its salt (hex), kept until the guess is revealed */
+interface StoredGuess {
+  guess: number;
+  salt: string;
+}
+
 /**
  * Service for interacting with the NumberGuess game contract
  */
@@ -576,9 +583,11 @@ export class NumberGuessService {
   }
 
   /**
-   * Make a guess (1-10)
+   * Commit to a guess (1-10) without revealing it.
+   * The commitment is keccak256(guess as 4 big-endian bytes || 32-byte salt);
+   * the guess and salt are stored in this browser for revealGuess.
    */
-  async makeGuess(
+  async commitGuess(
     sessionId: number,
     playerAddress: string,
     guess: number,
@@ -589,11 +598,22 @@ export class NumberGuessService {
       throw new Error('Guess must be between 1 and 10');
     }
 
+    const salt = randomSecret();
+    const guessBytes = Buffer.alloc(4);
+    guessBytes.writeUInt32BE(guess);
+    const commitment = keccak256(Buffer.concat([guessBytes, salt]));
+
+    // Stored before sending, so a commitment that lands always has its salt
+    saveCommitSecret<StoredGuess>(this.contractId, sessionId, playerAddress, {
+      guess,
+      salt: salt.toString('hex'),
+    });
+
     const client = this.createSigningClient(playerAddress, signer);
-    const tx = await client.make_guess({
+    const tx = await client.commit_guess({
       session_id: sessionId,
       player: playerAddress,
-      guess,
+      commitment,
     }, DEFAULT_METHOD_OPTIONS);
     // NOTE: Contract methods automatically simulate - footprint is already prepared
 
@@ -618,6 +638,58 @@ export class NumberGuessService {
     }
   }
 
+  /**
+   * Reveal the guess committed from this browser, once both players have committed
+   */
+  async revealGuess(
+    sessionId: number,
+    playerAddress: string,
+    signer: Pick<contract.ClientOptions, 'signTransaction' | 'signAuthEntry'>,
+    authTtlMinutes?: number
+  ) {
+    const stored = loadCommitSecret<StoredGuess>(this.contractId, sessionId, playerAddress);
+    if (!stored) {
+      throw new Error('No guess for this game was committed from this browser');
+    }
+
+    const client = this.createSigningClient(playerAddress, signer);
+    const tx = await client.reveal_guess({
+      session_id: sessionId,
+      player: playerAddress,
+      guess: stored.guess,
+      salt: Buffer.from(stored.salt, 'hex'),
+    }, DEFAULT_METHOD_OPTIONS);
+    // NOTE: Contract methods automatically simulate - footprint is already prepared
+
+    const validUntilLedgerSeq = authTtlMinutes
+      ? await calculateValidUntilLedger(RPC_URL, authTtlMinutes)
+      : await calculateValidUntilLedger(RPC_URL, DEFAULT_AUTH_TTL_MINUTES);
+
+    try {
+      const sentTx = await signAndSendViaLaunchtube(tx, DEFAULT_METHOD_OPTIONS.timeoutInSeconds, validUntilLedgerSeq);
+
+      if (sentTx.getTransactionResponse?.status === 'FAILED') {
+        const errorMessage = this.extractErrorFromDiagnostics(sentTx.getTransactionResponse);
+        throw new Error(`Transaction failed: ${errorMessage}`);
+      }
+
+      clearCommitSecret(this.contractId, sessionId, playerAddress);
+      return sentTx.result;
+    } catch (err) {
+      if (err instanceof Error && err.message.includes('Transaction failed!')) {
+        throw new Error('Transaction failed - check if both players have guessed and you haven\'t already revealed');
+      }
+      throw err;
+    }
+  }
+
+  /**
+   * The guess this browser committed for a player, if any
+   */
+  getStoredGuess(sessionId: number, playerAddress: string): number | null {
+    return loadCommitSecret<StoredGuess>(this.contractId, sessionId, playerAddress)?.guess ?? null;
+  }
+
   /**
    * Reveal the winner after both players have guessed
    */
@@ -716,11 +716,18 @@ export function TwentyOneGame({
 
         const signer = getContractSigner();
         const winnerResult = await twentyOneService.revealWinner(sessionId, userAddress, signer);
+        const winner = (winnerResult as any).unwrap ? (winnerResult as any).unwrap() : winnerResult;
+
+        // A tie has no winner: the contract deals a new round instead
+        if (!winner) {
+          await loadGameState();
+          setSuccess('Tie! New hands dealt for the next round.');
+          return;
+        }
 
         await waitForWinner();
         await loadGameState();
 
-        const winner = (winnerResult as any).unwrap ? (winnerResult as any).unwrap() : winnerResult;
         const isWinner = normalizeAddress(winner) === normalizedUserAddress;
         setSuccess(isWinner ? '🎉 You won!' : 'Game complete! Winner revealed.');
 
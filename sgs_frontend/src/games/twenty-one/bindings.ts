@@ -40,13 +40,46 @@ export const networks = {
 
 
 export interface Game {
+  /**
+   * Last ledger of the current phase: playing the hands (in a hidden game,
+   * revealing the shares first and the secrets last)
+   */
+  action_deadline: u32;
+  /**
+   * Set when a hidden game was called off because the randomness provider
+   * never answered, refunding both stakes
+   */
+  cancelled: boolean;
+  /**
+   * Player whose draw offer is waiting for the opponent to accept
+   */
+  draw_offer: Option<string>;
+  /**
+   * Set when the players agreed to a draw, ending the game without a winner
+   */
+  drawn: boolean;
+  /**
+   * Hidden-hand game: hands stay empty until `reveal_winner` deals them,
+   * and the round's commitments are stored under `DataKey::Beacon`
+   */
+  hidden: boolean;
   player1: string;
+  /**
+   * Cards dealt so far in a hidden-hand game
+   */
+  player1_card_count: u32;
   player1_hand: Buffer;
   player1_points: i128;
+  /**
+   * Secret a player revealed for their hidden hand this round
+   */
+  player1_secret: Option<Buffer>;
   player1_stuck: boolean;
   player2: string;
+  player2_card_count: u32;
   player2_hand: Buffer;
   player2_points: i128;
+  player2_secret: Option<Buffer>;
   player2_stuck: boolean;
   round: u32;
   winner: Option<string>;
@@ -63,12 +96,111 @@ export const Errors = {
   8: {message:"Draw"},
   9: {message:"SelfPlay"},
   10: {message:"RoundOverflow"},
-  11: {message:"InvalidHandData"}
+  11: {message:"InvalidHandData"},
+  12: {message:"SessionExists"},
+  13: {message:"NotHiddenGame"},
+  14: {message:"HandNotCommitted"},
+  15: {message:"AlreadyCommitted"},
+  16: {message:"CommitmentMismatch"},
+  17: {message:"AlreadyRevealed"},
+  18: {message:"HandsNotRevealed"},
+  19: {message:"CommitDeadlinePassed"},
+  20: {message:"RevealDeadlinePassed"},
+  21: {message:"RandomnessNotReady"},
+  22: {message:"DeadlineNotReached"},
+  23: {message:"OpponentNotStalled"},
+  24: {message:"PlayerStalled"},
+  25: {message:"NoDrawOffer"},
+  26: {message:"TooManyCards"},
+  27: {message:"RandomnessReady"}
 }
 
-export type DataKey = {tag: "Game", values: readonly [u32]} | {tag: "GameHubAddress", values: void} | {tag: "Admin", values: void};
+export type DataKey = {tag: "Game", values: readonly [u32]} | {tag: "Beacon", values: readonly [u32]};
+
+
+/**
+ * Commitment/reveal state of one beacon round
+ */
+export interface Beacon {
+  /**
+   * Last ledger on which a commitment is accepted
+   */
+  commit_deadline: u32;
+  commitments: Map<string, Buffer>;
+  parties: Array<string>;
+  /**
+   * Last ledger on which a reveal is accepted, set by the last commitment
+   */
+  reveal_deadline: Option<u32>;
+  /**
+   * Ledgers parties have to reveal once the last commitment lands
+   */
+  reveal_window: u32;
+  reveals: Map<string, Buffer>;
+}
 
 export interface Client {
+  /**
+   * Construct and simulate a start_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Start a new game between two players with points.
+   * This creates a session in the Game Hub and locks points before starting the game.
+   * Each player is dealt 2 cards to start.
+   * 
+   * **CRITICAL:** This method requires authorization from THIS contract (not players).
+   * The Game Hub will call `game_id.require_auth()` which checks this contract's address.
+   * 
+   * # Arguments
+   * * `session_id` - Unique session identifier (u32)
+   * * `player1` - Address of first player
+   * * `player2` - Address of second player
+   * * `player1_points` - Points amount committed by player 1
+   * * `player2_points` - Points amount committed by player 2
+   */
+  start_game: ({session_id, player1, player2, player1_points, player2_points}: {session_id: u32, player1: string, player2: string, player1_points: i128, player2_points: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a start_hidden_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Start a new game in which both hands stay hidden until `reveal_winner`.
+   * Works like `start_game`, but each player commits to a secret their hand
+   * is dealt from. The commitments are part of what each player authorizes.
+   * 
+   * # Arguments
+   * * `session_id` - Unique session identifier (u32)
+   * * `player1` - Address of first player
+   * * `player2` - Address of second player
+   * * `player1_points` - Points amount committed by player 1
+   * * `player2_points` - Points amount committed by player 2
+   * * `player1_commitment` - `keccak256(keccak256(secret))` of player 1's 32-byte secret
+   * * `player2_commitment` - `keccak256(keccak256(secret))` of player 2's 32-byte secret
+   */
+  start_hidden_game: ({session_id, player1, player2, player1_points, player2_points, player1_commitment, player2_commitment}: {session_id: u32, player1: string, player2: string, player1_points: i128, player2_points: i128, player1_commitment: Buffer, player2_commitment: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a commit_hand transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Commit to a fresh secret for a new round of a hidden-hand game.
+   * Needed after a tie, since the previous secrets have been revealed.
+   * 
+   * # Arguments
+   * * `session_id` - The session ID of the game
+   * * `player` - Address of the player committing
+   * * `commitment` - `keccak256(keccak256(secret))` of a new 32-byte secret
+   */
+  commit_hand: ({session_id, player, commitment}: {session_id: u32, player: string, commitment: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a reveal_share transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Reveal a player's share of the round's deal salt, `keccak256(secret)`.
+   * Only allowed once both players have committed, and must match the
+   * player's commitment. Once both shares are in, each player can compute
+   * their own hand and play it.
+   * 
+   * # Arguments
+   * * `session_id` - The session ID of the game
+   * * `player` - Address of the player revealing
+   * * `share` - `keccak256(secret)`, whose hash was committed
+   */
+  reveal_share: ({session_id, player, share}: {session_id: u32, player: string, share: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
   /**
    * Construct and simulate a hit transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    * Player draws another card ("hit").
@@ -92,31 +224,96 @@ export interface Client {
   stick: ({session_id, player}: {session_id: u32, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
   /**
-   * Construct and simulate a get_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get the current GameHub contract address
+   * Construct and simulate a reveal_hand transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Reveal a hidden hand's secret once both players have stuck.
+   * The secret must hash to the player's share. The hands are dealt
+   * by `reveal_winner` once both secrets are revealed.
+   * 
+   * # Arguments
+   * * `session_id` - The session ID of the game
+   * * `player` - Address of the player revealing
+   * * `secret` - The secret whose hash is the player's share
+   */
+  reveal_hand: ({session_id, player, secret}: {session_id: u32, player: string, secret: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a reveal_winner transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Reveal the winner of the game and submit outcome to GameHub.
+   * Can only be called after both players have stuck (and, for a hidden-hand
+   * game, revealed their secrets, from which both hands are dealt here).
+   * This calculates hand values, determines the winner (closest to 21),
+   * and handles draws by starting a new round.
+   * 
+   * # Arguments
+   * * `session_id` - The session ID of the game
    * 
    * # Returns
-   * * `Address` - The GameHub contract address
+   * * `Option<Address>` - Address of the winning player, or `None` when the
+   * hands tied and a new round started
    */
-  get_hub: (options?: MethodOptions) => Promise<AssembledTransaction<string>>
+  reveal_winner: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Option<string>>>>
 
   /**
-   * Construct and simulate a set_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Set a new GameHub contract address
+   * Construct and simulate a claim_timeout transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Claim the win after the action deadline has passed.
+   * The claimant must have done their part of the current phase (in a
+   * hidden game committing, revealing their share, sticking, then revealing
+   * their secret) while the opponent hasn't.
    * 
    * # Arguments
-   * * `new_hub` - The new GameHub contract address
+   * * `session_id` - The session ID of the game
+   * * `player` - Address of the player claiming the win
+   * 
+   * # Returns
+   * * `Address` - Address of the winning player
    */
-  set_hub: ({new_hub}: {new_hub: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  claim_timeout: ({session_id, player}: {session_id: u32, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<string>>>
 
   /**
-   * Construct and simulate a upgrade transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Update the contract WASM hash (upgrade contract)
+   * Construct and simulate a cancel_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Call off a hidden game whose randomness provider hasn't answered for the
+   * current round by its deadline. The session ends as cancelled, refunding
+   * both stakes.
    * 
    * # Arguments
-   * * `new_wasm_hash` - The hash of the new WASM binary
+   * * `session_id` - The session ID of the game
+   * * `player` - Address of the player cancelling
    */
-  upgrade: ({new_wasm_hash}: {new_wasm_hash: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  cancel_game: ({session_id, player}: {session_id: u32, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a resign transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Resign the game, handing the win to the opponent.
+   * 
+   * # Arguments
+   * * `session_id` - The session ID of the game
+   * * `player` - Address of the resigning player
+   * 
+   * # Returns
+   * * `Address` - Address of the winning player
+   */
+  resign: ({session_id, player}: {session_id: u32, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<string>>>
+
+  /**
+   * Construct and simulate a offer_draw transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Offer the opponent a draw. The offer stands until the opponent accepts
+   * it or the game ends; offering again replaces the opponent's own offer.
+   * 
+   * # Arguments
+   * * `session_id` - The session ID of the game
+   * * `player` - Address of the player offering the draw
+   */
+  offer_draw: ({session_id, player}: {session_id: u32, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a accept_draw transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Accept the opponent's draw offer, ending the game as a draw.
+   * 
+   * # Arguments
+   * * `session_id` - The session ID of the game
+   * * `player` - Address of the player accepting the draw
+   */
+  accept_draw: ({session_id, player}: {session_id: u32, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
   /**
    * Construct and simulate a get_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
@@ -130,6 +327,45 @@ export interface Client {
    */
   get_game: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Game>>>
 
+  /**
+   * Construct and simulate a get_hand_value transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the current hand value for a player.
+   * 
+   * # Arguments
+   * * `session_id` - The session ID of the game
+   * * `player` - Address of the player
+   * 
+   * # Returns
+   * * `u32` - The total value of the player's hand
+   */
+  get_hand_value: ({session_id, player}: {session_id: u32, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u32>>>
+
+  /**
+   * Construct and simulate a get_beacon transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the commitments and shares of a hidden-hand game's current round.
+   * 
+   * # Arguments
+   * * `session_id` - The session ID of the game
+   * 
+   * # Returns
+   * * `Beacon` - The round's commitments, shares and deadlines
+   */
+  get_beacon: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Beacon>>>
+
+  /**
+   * Construct and simulate a get_deal_salt transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the salt the current round's hidden hands are dealt from. Each
+   * player deals their own hand from it and their secret off-chain, the
+   * same way `reveal_winner` does.
+   * 
+   * # Arguments
+   * * `session_id` - The session ID of the game
+   * 
+   * # Returns
+   * * `BytesN<32>` - The round's deal salt, once both shares are revealed
+   */
+  get_deal_salt: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Buffer>>>
+
   /**
    * Construct and simulate a get_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    * Get the current admin address
@@ -149,56 +385,64 @@ export interface Client {
   set_admin: ({new_admin}: {new_admin: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
 
   /**
-   * Construct and simulate a start_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Start a new game between two players with points.
-   * This creates a session in the Game Hub and locks points before starting the game.
-   * Each player is dealt 2 cards to start.
+   * Construct and simulate a get_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the current GameHub contract address
    * 
-   * **CRITICAL:** This method requires authorization from THIS contract (not players).
-   * The Game Hub will call `game_id.require_auth()` which checks this contract's address.
+   * # Returns
+   * * `Address` - The GameHub contract address
+   */
+  get_hub: (options?: MethodOptions) => Promise<AssembledTransaction<string>>
+
+  /**
+   * Construct and simulate a set_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set a new GameHub contract address
    * 
    * # Arguments
-   * * `session_id` - Unique session identifier (u32)
-   * * `player1` - Address of first player
-   * * `player2` - Address of second player
-   * * `player1_points` - Points amount committed by player 1
-   * * `player2_points` - Points amount committed by player 2
+   * * `new_hub` - The new GameHub contract address
    */
-  start_game: ({session_id, player1, player2, player1_points, player2_points}: {session_id: u32, player1: string, player2: string, player1_points: i128, player2_points: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+  set_hub: ({new_hub}: {new_hub: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
 
   /**
-   * Construct and simulate a reveal_winner transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Reveal the winner of the game and submit outcome to GameHub.
-   * Can only be called after both players have stuck.
-   * This calculates hand values, determines the winner (closest to 21),
-   * and handles draws by dealing new hands.
+   * Construct and simulate a get_randomness_provider transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the randomness provider mixed into hidden hands, if any
    * 
-   * # Arguments
-   * * `session_id` - The session ID of the game
+   * # Returns
+   * * `Option<Address>` - The randomness provider contract address
+   */
+  get_randomness_provider: (options?: MethodOptions) => Promise<AssembledTransaction<Option<string>>>
+
+  /**
+   * Construct and simulate a get_action_timeout transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the number of ledgers players have to play, and then to reveal
    * 
    * # Returns
-   * * `Address` - Address of the winning player
+   * * `u32` - The action timeout in ledgers
    */
-  reveal_winner: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<string>>>
+  get_action_timeout: (options?: MethodOptions) => Promise<AssembledTransaction<u32>>
 
   /**
-   * Construct and simulate a get_hand_value transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get the current hand value for a player.
+   * Construct and simulate a set_action_timeout transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set the action timeout for new phases (games in progress keep their deadline)
    * 
    * # Arguments
-   * * `session_id` - The session ID of the game
-   * * `player` - Address of the player
+   * * `ledgers` - The action timeout in ledgers
+   */
+  set_action_timeout: ({ledgers}: {ledgers: u32}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a upgrade transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Update the contract WASM hash (upgrade contract)
    * 
-   * # Returns
-   * * `u32` - The total value of the player's hand
+   * # Arguments
+   * * `new_wasm_hash` - The hash of the new WASM binary
    */
-  get_hand_value: ({session_id, player}: {session_id: u32, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u32>>>
+  upgrade: ({new_wasm_hash}: {new_wasm_hash: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
 
 }
 export class Client extends ContractClient {
   static async deploy<T = Client>(
         /** Constructor/Initialization Args for the contract's `__constructor` method */
-        {admin, game_hub}: {admin: string, game_hub: string},
+        {admin, game_hub, randomness_provider}: {admin: string, game_hub: string, randomness_provider: Option<string>},
     /** Options for initializing a Client as well as for calling a method, with extras specific to deploying. */
     options: MethodOptions &
       Omit<ContractClientOptions, "contractId"> & {
@@ -210,39 +454,68 @@ export class Client extends ContractClient {
         format?: "hex" | "base64";
       }
   ): Promise<AssembledTransaction<T>> {
-    return ContractClient.deploy({admin, game_hub}, options)
+    return ContractClient.deploy({admin, game_hub, randomness_provider}, options)
   }
   constructor(public readonly options: ContractClientOptions) {
     super(
-      new ContractSpec([ "AAAAAQAAAAAAAAAAAAAABEdhbWUAAAAKAAAAAAAAAAdwbGF5ZXIxAAAAABMAAAAAAAAADHBsYXllcjFfaGFuZAAAAA4AAAAAAAAADnBsYXllcjFfcG9pbnRzAAAAAAALAAAAAAAAAA1wbGF5ZXIxX3N0dWNrAAAAAAAAAQAAAAAAAAAHcGxheWVyMgAAAAATAAAAAAAAAAxwbGF5ZXIyX2hhbmQAAAAOAAAAAAAAAA5wbGF5ZXIyX3BvaW50cwAAAAAACwAAAAAAAAANcGxheWVyMl9zdHVjawAAAAAAAAEAAAAAAAAABXJvdW5kAAAAAAAABAAAAAAAAAAGd2lubmVyAAAAAAPoAAAAEw==",
-        "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAACwAAAAAAAAAMR2FtZU5vdEZvdW5kAAAAAQAAAAAAAAAJTm90UGxheWVyAAAAAAAAAgAAAAAAAAAMQWxyZWFkeVN0dWNrAAAAAwAAAAAAAAAQR2FtZUFscmVhZHlFbmRlZAAAAAQAAAAAAAAADFBsYXllckJ1c3RlZAAAAAUAAAAAAAAAE0JvdGhQbGF5ZXJzTm90U3R1Y2sAAAAABgAAAAAAAAAQT3Bwb25lbnROb3RTdHVjawAAAAcAAAAAAAAABERyYXcAAAAIAAAAAAAAAAhTZWxmUGxheQAAAAkAAAAAAAAADVJvdW5kT3ZlcmZsb3cAAAAAAAAKAAAAAAAAAA9JbnZhbGlkSGFuZERhdGEAAAAACw==",
-        "AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAAAwAAAAEAAAAAAAAABEdhbWUAAAABAAAABAAAAAAAAAAAAAAADkdhbWVIdWJBZGRyZXNzAAAAAAAAAAAAAAAAAAVBZG1pbgAAAA==",
-        "AAAAAAAAANRQbGF5ZXIgZHJhd3MgYW5vdGhlciBjYXJkICgiaGl0IikuCklmIHRoZSBwbGF5ZXIncyBoYW5kIHZhbHVlIGV4Y2VlZHMgMjEsIHRoZXkgYnVzdCBhbmQgbG9zZSBpbW1lZGlhdGVseS4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVGhlIHNlc3Npb24gSUQgb2YgdGhlIGdhbWUKKiBgcGxheWVyYCAtIEFkZHJlc3Mgb2YgdGhlIHBsYXllciBkcmF3aW5nIGEgY2FyZAAAAANoaXQAAAAAAgAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
-        "AAAAAAAAANZQbGF5ZXIgY2hvb3NlcyB0byBzdGljayAoZW5kIHRoZWlyIHR1cm4gd2l0aCBjdXJyZW50IGhhbmQpLgpJZiBib3RoIHBsYXllcnMgaGF2ZSBzdHVjaywgdGhlIGdhbWUgY2FuIGJlIHJldmVhbGVkLgoKIyBBcmd1bWVudHMKKiBgc2Vzc2lvbl9pZGAgLSBUaGUgc2Vzc2lvbiBJRCBvZiB0aGUgZ2FtZQoqIGBwbGF5ZXJgIC0gQWRkcmVzcyBvZiB0aGUgcGxheWVyIHN0aWNraW5nAAAAAAAFc3RpY2sAAAAAAAACAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAABAAAD6QAAA+0AAAAAAAAAAw==",
-        "AAAAAAAAAF5HZXQgdGhlIGN1cnJlbnQgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzCgojIFJldHVybnMKKiBgQWRkcmVzc2AgLSBUaGUgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzAAAAAAAHZ2V0X2h1YgAAAAAAAAAAAQAAABM=",
-        "AAAAAAAAAF5TZXQgYSBuZXcgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzCgojIEFyZ3VtZW50cwoqIGBuZXdfaHViYCAtIFRoZSBuZXcgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzAAAAAAAHc2V0X2h1YgAAAAABAAAAAAAAAAduZXdfaHViAAAAABMAAAAA",
-        "AAAAAAAAAHFVcGRhdGUgdGhlIGNvbnRyYWN0IFdBU00gaGFzaCAodXBncmFkZSBjb250cmFjdCkKCiMgQXJndW1lbnRzCiogYG5ld193YXNtX2hhc2hgIC0gVGhlIGhhc2ggb2YgdGhlIG5ldyBXQVNNIGJpbmFyeQAAAAAAAAd1cGdyYWRlAAAAAAEAAAAAAAAADW5ld193YXNtX2hhc2gAAAAAAAPuAAAAIAAAAAA=",
+      new ContractSpec([ "AAAAAQAAAAAAAAAAAAAABEdhbWUAAAATAAAAd0xhc3QgbGVkZ2VyIG9mIHRoZSBjdXJyZW50IHBoYXNlOiBwbGF5aW5nIHRoZSBoYW5kcyAoaW4gYSBoaWRkZW4gZ2FtZSwKcmV2ZWFsaW5nIHRoZSBzaGFyZXMgZmlyc3QgYW5kIHRoZSBzZWNyZXRzIGxhc3QpAAAAAA9hY3Rpb25fZGVhZGxpbmUAAAAABAAAAGtTZXQgd2hlbiBhIGhpZGRlbiBnYW1lIHdhcyBjYWxsZWQgb2ZmIGJlY2F1c2UgdGhlIHJhbmRvbW5lc3MgcHJvdmlkZXIKbmV2ZXIgYW5zd2VyZWQsIHJlZnVuZGluZyBib3RoIHN0YWtlcwAAAAAJY2FuY2VsbGVkAAAAAAAAAQAAAD1QbGF5ZXIgd2hvc2UgZHJhdyBvZmZlciBpcyB3YWl0aW5nIGZvciB0aGUgb3Bwb25lbnQgdG8gYWNjZXB0AAAAAAAACmRyYXdfb2ZmZXIAAAAAA+gAAAATAAAAR1NldCB3aGVuIHRoZSBwbGF5ZXJzIGFncmVlZCB0byBhIGRyYXcsIGVuZGluZyB0aGUgZ2FtZSB3aXRob3V0IGEgd2lubmVyAAAAAAVkcmF3bgAAAAAAAAEAAACDSGlkZGVuLWhhbmQgZ2FtZTogaGFuZHMgc3RheSBlbXB0eSB1bnRpbCBgcmV2ZWFsX3dpbm5lcmAgZGVhbHMgdGhlbSwKYW5kIHRoZSByb3VuZCdzIGNvbW1pdG1lbnRzIGFyZSBzdG9yZWQgdW5kZXIgYERhdGFLZXk6OkJlYWNvbmAAAAAABmhpZGRlbgAAAAAAAQAAAAAAAAAHcGxheWVyMQAAAAATAAAAKENhcmRzIGRlYWx0IHNvIGZhciBpbiBhIGhpZGRlbi1oYW5kIGdhbWUAAAAScGxheWVyMV9jYXJkX2NvdW50AAAAAAAEAAAAAAAAAAxwbGF5ZXIxX2hhbmQAAAAOAAAAAAAAAA5wbGF5ZXIxX3BvaW50cwAAAAAACwAAADlTZWNyZXQgYSBwbGF5ZXIgcmV2ZWFsZWQgZm9yIHRoZWlyIGhpZGRlbiBoYW5kIHRoaXMgcm91bmQAAAAAAAAOcGxheWVyMV9zZWNyZXQAAAAAA+gAAAPuAAAAIAAAAAAAAAANcGxheWVyMV9zdHVjawAAAAAAAAEAAAAAAAAAB3BsYXllcjIAAAAAEwAAAAAAAAAScGxheWVyMl9jYXJkX2NvdW50AAAAAAAEAAAAAAAAAAxwbGF5ZXIyX2hhbmQAAAAOAAAAAAAAAA5wbGF5ZXIyX3BvaW50cwAAAAAACwAAAAAAAAAOcGxheWVyMl9zZWNyZXQAAAAAA+gAAAPuAAAAIAAAAAAAAAANcGxheWVyMl9zdHVjawAAAAAAAAEAAAAAAAAABXJvdW5kAAAAAAAABAAAAAAAAAAGd2lubmVyAAAAAAPoAAAAEw==",
+        "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAAGwAAAAAAAAAMR2FtZU5vdEZvdW5kAAAAAQAAAAAAAAAJTm90UGxheWVyAAAAAAAAAgAAAAAAAAAMQWxyZWFkeVN0dWNrAAAAAwAAAAAAAAAQR2FtZUFscmVhZHlFbmRlZAAAAAQAAAAAAAAADFBsYXllckJ1c3RlZAAAAAUAAAAAAAAAE0JvdGhQbGF5ZXJzTm90U3R1Y2sAAAAABgAAAAAAAAAQT3Bwb25lbnROb3RTdHVjawAAAAcAAAAAAAAABERyYXcAAAAIAAAAAAAAAAhTZWxmUGxheQAAAAkAAAAAAAAADVJvdW5kT3ZlcmZsb3cAAAAAAAAKAAAAAAAAAA9JbnZhbGlkSGFuZERhdGEAAAAACwAAAAAAAAANU2Vzc2lvbkV4aXN0cwAAAAAAAAwAAAAAAAAADU5vdEhpZGRlbkdhbWUAAAAAAAANAAAAAAAAABBIYW5kTm90Q29tbWl0dGVkAAAADgAAAAAAAAAQQWxyZWFkeUNvbW1pdHRlZAAAAA8AAAAAAAAAEkNvbW1pdG1lbnRNaXNtYXRjaAAAAAAAEAAAAAAAAAAPQWxyZWFkeVJldmVhbGVkAAAAABEAAAAAAAAAEEhhbmRzTm90UmV2ZWFsZWQAAAASAAAAAAAAABRDb21taXREZWFkbGluZVBhc3NlZAAAABMAAAAAAAAAFFJldmVhbERlYWRsaW5lUGFzc2VkAAAAFAAAAAAAAAASUmFuZG9tbmVzc05vdFJlYWR5AAAAAAAVAAAAAAAAABJEZWFkbGluZU5vdFJlYWNoZWQAAAAAABYAAAAAAAAAEk9wcG9uZW50Tm90U3RhbGxlZAAAAAAAFwAAAAAAAAANUGxheWVyU3RhbGxlZAAAAAAAABgAAAAAAAAAC05vRHJhd09mZmVyAAAAABkAAAAAAAAADFRvb01hbnlDYXJkcwAAABoAAAAAAAAAD1JhbmRvbW5lc3NSZWFkeQAAAAAb",
+        "AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAAAgAAAAEAAAAAAAAABEdhbWUAAAABAAAABAAAAAEAAAAAAAAABkJlYWNvbgAAAAAAAQAAAAQ=",
+        "AAAAAQAAACtDb21taXRtZW50L3JldmVhbCBzdGF0ZSBvZiBvbmUgYmVhY29uIHJvdW5kAAAAAAAAAAAGQmVhY29uAAAAAAAGAAAALUxhc3QgbGVkZ2VyIG9uIHdoaWNoIGEgY29tbWl0bWVudCBpcyBhY2NlcHRlZAAAAAAAAA9jb21taXRfZGVhZGxpbmUAAAAABAAAAAAAAAALY29tbWl0bWVudHMAAAAD7AAAABMAAAPuAAAAIAAAAAAAAAAHcGFydGllcwAAAAPqAAAAEwAAAEVMYXN0IGxlZGdlciBvbiB3aGljaCBhIHJldmVhbCBpcyBhY2NlcHRlZCwgc2V0IGJ5IHRoZSBsYXN0IGNvbW1pdG1lbnQAAAAAAAAPcmV2ZWFsX2RlYWRsaW5lAAAAA+gAAAAEAAAAPUxlZGdlcnMgcGFydGllcyBoYXZlIHRvIHJldmVhbCBvbmNlIHRoZSBsYXN0IGNvbW1pdG1lbnQgbGFuZHMAAAAAAAANcmV2ZWFsX3dpbmRvdwAAAAAAAAQAAAAAAAAAB3JldmVhbHMAAAAD7AAAABMAAAAO",
+        "AAAAAAAAAPJJbml0aWFsaXplIHRoZSBjb250cmFjdCB3aXRoIEdhbWVIdWIgYWRkcmVzcyBhbmQgYWRtaW4KCiMgQXJndW1lbnRzCiogYGFkbWluYCAtIEFkbWluIGFkZHJlc3MgKGNhbiB1cGdyYWRlIGNvbnRyYWN0KQoqIGBnYW1lX2h1YmAgLSBBZGRyZXNzIG9mIHRoZSBHYW1lSHViIGNvbnRyYWN0CiogYHJhbmRvbW5lc3NfcHJvdmlkZXJgIC0gT3B0aW9uYWwgcmFuZG9tbmVzcyBjb250cmFjdCBtaXhlZCBpbnRvIGhpZGRlbiBoYW5kcwAAAAAADV9fY29uc3RydWN0b3IAAAAAAAADAAAAAAAAAAVhZG1pbgAAAAAAABMAAAAAAAAACGdhbWVfaHViAAAAEwAAAAAAAAATcmFuZG9tbmVzc19wcm92aWRlcgAAAAPoAAAAEwAAAAA=",
+        "AAAAAAAAAlFTdGFydCBhIG5ldyBnYW1lIGJldHdlZW4gdHdvIHBsYXllcnMgd2l0aCBwb2ludHMuClRoaXMgY3JlYXRlcyBhIHNlc3Npb24gaW4gdGhlIEdhbWUgSHViIGFuZCBsb2NrcyBwb2ludHMgYmVmb3JlIHN0YXJ0aW5nIHRoZSBnYW1lLgpFYWNoIHBsYXllciBpcyBkZWFsdCAyIGNhcmRzIHRvIHN0YXJ0LgoKKipDUklUSUNBTDoqKiBUaGlzIG1ldGhvZCByZXF1aXJlcyBhdXRob3JpemF0aW9uIGZyb20gVEhJUyBjb250cmFjdCAobm90IHBsYXllcnMpLgpUaGUgR2FtZSBIdWIgd2lsbCBjYWxsIGBnYW1lX2lkLnJlcXVpcmVfYXV0aCgpYCB3aGljaCBjaGVja3MgdGhpcyBjb250cmFjdCdzIGFkZHJlc3MuCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIFVuaXF1ZSBzZXNzaW9uIGlkZW50aWZpZXIgKHUzMikKKiBgcGxheWVyMWAgLSBBZGRyZXNzIG9mIGZpcnN0IHBsYXllcgoqIGBwbGF5ZXIyYCAtIEFkZHJlc3Mgb2Ygc2Vjb25kIHBsYXllcgoqIGBwbGF5ZXIxX3BvaW50c2AgLSBQb2ludHMgYW1vdW50IGNvbW1pdHRlZCBieSBwbGF5ZXIgMQoqIGBwbGF5ZXIyX3BvaW50c2AgLSBQb2ludHMgYW1vdW50IGNvbW1pdHRlZCBieSBwbGF5ZXIgMgAAAAAAAApzdGFydF9nYW1lAAAAAAAFAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAdwbGF5ZXIxAAAAABMAAAAAAAAAB3BsYXllcjIAAAAAEwAAAAAAAAAOcGxheWVyMV9wb2ludHMAAAAAAAsAAAAAAAAADnBsYXllcjJfcG9pbnRzAAAAAAALAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAn5TdGFydCBhIG5ldyBnYW1lIGluIHdoaWNoIGJvdGggaGFuZHMgc3RheSBoaWRkZW4gdW50aWwgYHJldmVhbF93aW5uZXJgLgpXb3JrcyBsaWtlIGBzdGFydF9nYW1lYCwgYnV0IGVhY2ggcGxheWVyIGNvbW1pdHMgdG8gYSBzZWNyZXQgdGhlaXIgaGFuZAppcyBkZWFsdCBmcm9tLiBUaGUgY29tbWl0bWVudHMgYXJlIHBhcnQgb2Ygd2hhdCBlYWNoIHBsYXllciBhdXRob3JpemVzLgoKIyBBcmd1bWVudHMKKiBgc2Vzc2lvbl9pZGAgLSBVbmlxdWUgc2Vzc2lvbiBpZGVudGlmaWVyICh1MzIpCiogYHBsYXllcjFgIC0gQWRkcmVzcyBvZiBmaXJzdCBwbGF5ZXIKKiBgcGxheWVyMmAgLSBBZGRyZXNzIG9mIHNlY29uZCBwbGF5ZXIKKiBgcGxheWVyMV9wb2ludHNgIC0gUG9pbnRzIGFtb3VudCBjb21taXR0ZWQgYnkgcGxheWVyIDEKKiBgcGxheWVyMl9wb2ludHNgIC0gUG9pbnRzIGFtb3VudCBjb21taXR0ZWQgYnkgcGxheWVyIDIKKiBgcGxheWVyMV9jb21taXRtZW50YCAtIGBrZWNjYWsyNTYoa2VjY2FrMjU2KHNlY3JldCkpYCBvZiBwbGF5ZXIgMSdzIDMyLWJ5dGUgc2VjcmV0CiogYHBsYXllcjJfY29tbWl0bWVudGAgLSBga2VjY2FrMjU2KGtlY2NhazI1NihzZWNyZXQpKWAgb2YgcGxheWVyIDIncyAzMi1ieXRlIHNlY3JldAAAAAAAEXN0YXJ0X2hpZGRlbl9nYW1lAAAAAAAABwAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAHcGxheWVyMQAAAAATAAAAAAAAAAdwbGF5ZXIyAAAAABMAAAAAAAAADnBsYXllcjFfcG9pbnRzAAAAAAALAAAAAAAAAA5wbGF5ZXIyX3BvaW50cwAAAAAACwAAAAAAAAAScGxheWVyMV9jb21taXRtZW50AAAAAAPuAAAAIAAAAAAAAAAScGxheWVyMl9jb21taXRtZW50AAAAAAPuAAAAIAAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAATFDb21taXQgdG8gYSBmcmVzaCBzZWNyZXQgZm9yIGEgbmV3IHJvdW5kIG9mIGEgaGlkZGVuLWhhbmQgZ2FtZS4KTmVlZGVkIGFmdGVyIGEgdGllLCBzaW5jZSB0aGUgcHJldmlvdXMgc2VjcmV0cyBoYXZlIGJlZW4gcmV2ZWFsZWQuCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIFRoZSBzZXNzaW9uIElEIG9mIHRoZSBnYW1lCiogYHBsYXllcmAgLSBBZGRyZXNzIG9mIHRoZSBwbGF5ZXIgY29tbWl0dGluZwoqIGBjb21taXRtZW50YCAtIGBrZWNjYWsyNTYoa2VjY2FrMjU2KHNlY3JldCkpYCBvZiBhIG5ldyAzMi1ieXRlIHNlY3JldAAAAAAAAAtjb21taXRfaGFuZAAAAAADAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAACmNvbW1pdG1lbnQAAAAAA+4AAAAgAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAYpSZXZlYWwgYSBwbGF5ZXIncyBzaGFyZSBvZiB0aGUgcm91bmQncyBkZWFsIHNhbHQsIGBrZWNjYWsyNTYoc2VjcmV0KWAuCk9ubHkgYWxsb3dlZCBvbmNlIGJvdGggcGxheWVycyBoYXZlIGNvbW1pdHRlZCwgYW5kIG11c3QgbWF0Y2ggdGhlCnBsYXllcidzIGNvbW1pdG1lbnQuIE9uY2UgYm90aCBzaGFyZXMgYXJlIGluLCBlYWNoIHBsYXllciBjYW4gY29tcHV0ZQp0aGVpciBvd24gaGFuZCBhbmQgcGxheSBpdC4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVGhlIHNlc3Npb24gSUQgb2YgdGhlIGdhbWUKKiBgcGxheWVyYCAtIEFkZHJlc3Mgb2YgdGhlIHBsYXllciByZXZlYWxpbmcKKiBgc2hhcmVgIC0gYGtlY2NhazI1NihzZWNyZXQpYCwgd2hvc2UgaGFzaCB3YXMgY29tbWl0dGVkAAAAAAAMcmV2ZWFsX3NoYXJlAAAAAwAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAAVzaGFyZQAAAAAAA+4AAAAgAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAANRQbGF5ZXIgZHJhd3MgYW5vdGhlciBjYXJkICgiaGl0IikuCklmIHRoZSBwbGF5ZXIncyBoYW5kIHZhbHVlIGV4Y2VlZHMgMjEsIHRoZXkgYnVzdCBhbmQgbG9zZSBpbW1lZGlhdGVseS4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVGhlIHNlc3Npb24gSUQgb2YgdGhlIGdhbWUKKiBgcGxheWVyYCAtIEFkZHJlc3Mgb2YgdGhlIHBsYXllciBkcmF3aW5nIGEgY2FyZAAAAANoaXQAAAAAAgAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAANZQbGF5ZXIgY2hvb3NlcyB0byBzdGljayAoZW5kIHRoZWlyIHR1cm4gd2l0aCBjdXJyZW50IGhhbmQpLgpJZiBib3RoIHBsYXllcnMgaGF2ZSBzdHVjaywgdGhlIGdhbWUgY2FuIGJlIHJldmVhbGVkLgoKIyBBcmd1bWVudHMKKiBgc2Vzc2lvbl9pZGAgLSBUaGUgc2Vzc2lvbiBJRCBvZiB0aGUgZ2FtZQoqIGBwbGF5ZXJgIC0gQWRkcmVzcyBvZiB0aGUgcGxheWVyIHN0aWNraW5nAAAAAAAFc3RpY2sAAAAAAAACAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAU1SZXZlYWwgYSBoaWRkZW4gaGFuZCdzIHNlY3JldCBvbmNlIGJvdGggcGxheWVycyBoYXZlIHN0dWNrLgpUaGUgc2VjcmV0IG11c3QgaGFzaCB0byB0aGUgcGxheWVyJ3Mgc2hhcmUuIFRoZSBoYW5kcyBhcmUgZGVhbHQKYnkgYHJldmVhbF93aW5uZXJgIG9uY2UgYm90aCBzZWNyZXRzIGFyZSByZXZlYWxlZC4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVGhlIHNlc3Npb24gSUQgb2YgdGhlIGdhbWUKKiBgcGxheWVyYCAtIEFkZHJlc3Mgb2YgdGhlIHBsYXllciByZXZlYWxpbmcKKiBgc2VjcmV0YCAtIFRoZSBzZWNyZXQgd2hvc2UgaGFzaCBpcyB0aGUgcGxheWVyJ3Mgc2hhcmUAAAAAAAALcmV2ZWFsX2hhbmQAAAAAAwAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAAZzZWNyZXQAAAAAA+4AAAAgAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAehSZXZlYWwgdGhlIHdpbm5lciBvZiB0aGUgZ2FtZSBhbmQgc3VibWl0IG91dGNvbWUgdG8gR2FtZUh1Yi4KQ2FuIG9ubHkgYmUgY2FsbGVkIGFmdGVyIGJvdGggcGxheWVycyBoYXZlIHN0dWNrIChhbmQsIGZvciBhIGhpZGRlbi1oYW5kCmdhbWUsIHJldmVhbGVkIHRoZWlyIHNlY3JldHMsIGZyb20gd2hpY2ggYm90aCBoYW5kcyBhcmUgZGVhbHQgaGVyZSkuClRoaXMgY2FsY3VsYXRlcyBoYW5kIHZhbHVlcywgZGV0ZXJtaW5lcyB0aGUgd2lubmVyIChjbG9zZXN0IHRvIDIxKSwKYW5kIGhhbmRsZXMgZHJhd3MgYnkgc3RhcnRpbmcgYSBuZXcgcm91bmQuCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIFRoZSBzZXNzaW9uIElEIG9mIHRoZSBnYW1lCgojIFJldHVybnMKKiBgT3B0aW9uPEFkZHJlc3M+YCAtIEFkZHJlc3Mgb2YgdGhlIHdpbm5pbmcgcGxheWVyLCBvciBgTm9uZWAgd2hlbiB0aGUKaGFuZHMgdGllZCBhbmQgYSBuZXcgcm91bmQgc3RhcnRlZAAAAA1yZXZlYWxfd2lubmVyAAAAAAAAAQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAPpAAAD6AAAABMAAAAD",
+        "AAAAAAAAAYpDbGFpbSB0aGUgd2luIGFmdGVyIHRoZSBhY3Rpb24gZGVhZGxpbmUgaGFzIHBhc3NlZC4KVGhlIGNsYWltYW50IG11c3QgaGF2ZSBkb25lIHRoZWlyIHBhcnQgb2YgdGhlIGN1cnJlbnQgcGhhc2UgKGluIGEKaGlkZGVuIGdhbWUgY29tbWl0dGluZywgcmV2ZWFsaW5nIHRoZWlyIHNoYXJlLCBzdGlja2luZywgdGhlbiByZXZlYWxpbmcKdGhlaXIgc2VjcmV0KSB3aGlsZSB0aGUgb3Bwb25lbnQgaGFzbid0LgoKIyBBcmd1bWVudHMKKiBgc2Vzc2lvbl9pZGAgLSBUaGUgc2Vzc2lvbiBJRCBvZiB0aGUgZ2FtZQoqIGBwbGF5ZXJgIC0gQWRkcmVzcyBvZiB0aGUgcGxheWVyIGNsYWltaW5nIHRoZSB3aW4KCiMgUmV0dXJucwoqIGBBZGRyZXNzYCAtIEFkZHJlc3Mgb2YgdGhlIHdpbm5pbmcgcGxheWVyAAAAAAANY2xhaW1fdGltZW91dAAAAAAAAAIAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAPpAAAAEwAAAAM=",
+        "AAAAAAAAAQRDYWxsIG9mZiBhIGhpZGRlbiBnYW1lIHdob3NlIHJhbmRvbW5lc3MgcHJvdmlkZXIgaGFzbid0IGFuc3dlcmVkIGZvciB0aGUKY3VycmVudCByb3VuZCBieSBpdHMgZGVhZGxpbmUuIFRoZSBzZXNzaW9uIGVuZHMgYXMgY2FuY2VsbGVkLCByZWZ1bmRpbmcKYm90aCBzdGFrZXMuCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIFRoZSBzZXNzaW9uIElEIG9mIHRoZSBnYW1lCiogYHBsYXllcmAgLSBBZGRyZXNzIG9mIHRoZSBwbGF5ZXIgY2FuY2VsbGluZwAAAAtjYW5jZWxfZ2FtZQAAAAACAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAM5SZXNpZ24gdGhlIGdhbWUsIGhhbmRpbmcgdGhlIHdpbiB0byB0aGUgb3Bwb25lbnQuCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIFRoZSBzZXNzaW9uIElEIG9mIHRoZSBnYW1lCiogYHBsYXllcmAgLSBBZGRyZXNzIG9mIHRoZSByZXNpZ25pbmcgcGxheWVyCgojIFJldHVybnMKKiBgQWRkcmVzc2AgLSBBZGRyZXNzIG9mIHRoZSB3aW5uaW5nIHBsYXllcgAAAAAABnJlc2lnbgAAAAAAAgAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAQAAA+kAAAATAAAAAw==",
+        "AAAAAAAAAPtPZmZlciB0aGUgb3Bwb25lbnQgYSBkcmF3LiBUaGUgb2ZmZXIgc3RhbmRzIHVudGlsIHRoZSBvcHBvbmVudCBhY2NlcHRzCml0IG9yIHRoZSBnYW1lIGVuZHM7IG9mZmVyaW5nIGFnYWluIHJlcGxhY2VzIHRoZSBvcHBvbmVudCdzIG93biBvZmZlci4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVGhlIHNlc3Npb24gSUQgb2YgdGhlIGdhbWUKKiBgcGxheWVyYCAtIEFkZHJlc3Mgb2YgdGhlIHBsYXllciBvZmZlcmluZyB0aGUgZHJhdwAAAAAKb2ZmZXJfZHJhdwAAAAAAAgAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAKtBY2NlcHQgdGhlIG9wcG9uZW50J3MgZHJhdyBvZmZlciwgZW5kaW5nIHRoZSBnYW1lIGFzIGEgZHJhdy4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVGhlIHNlc3Npb24gSUQgb2YgdGhlIGdhbWUKKiBgcGxheWVyYCAtIEFkZHJlc3Mgb2YgdGhlIHBsYXllciBhY2NlcHRpbmcgdGhlIGRyYXcAAAAAC2FjY2VwdF9kcmF3AAAAAAIAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAPpAAAAAgAAAAM=",
         "AAAAAAAAAJ9HZXQgZ2FtZSBpbmZvcm1hdGlvbi4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVGhlIHNlc3Npb24gSUQgb2YgdGhlIGdhbWUKCiMgUmV0dXJucwoqIGBHYW1lYCAtIFRoZSBnYW1lIHN0YXRlIChpbmNsdWRlcyBoYW5kcyBhbmQgd2lubmVyIGFmdGVyIGdhbWUgZW5kcykAAAAACGdldF9nYW1lAAAAAQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAPpAAAH0AAAAARHYW1lAAAAAw==",
+        "AAAAAAAAAL5HZXQgdGhlIGN1cnJlbnQgaGFuZCB2YWx1ZSBmb3IgYSBwbGF5ZXIuCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIFRoZSBzZXNzaW9uIElEIG9mIHRoZSBnYW1lCiogYHBsYXllcmAgLSBBZGRyZXNzIG9mIHRoZSBwbGF5ZXIKCiMgUmV0dXJucwoqIGB1MzJgIC0gVGhlIHRvdGFsIHZhbHVlIG9mIHRoZSBwbGF5ZXIncyBoYW5kAAAAAAAOZ2V0X2hhbmRfdmFsdWUAAAAAAAIAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAPpAAAABAAAAAM=",
+        "AAAAAAAAAMRHZXQgdGhlIGNvbW1pdG1lbnRzIGFuZCBzaGFyZXMgb2YgYSBoaWRkZW4taGFuZCBnYW1lJ3MgY3VycmVudCByb3VuZC4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVGhlIHNlc3Npb24gSUQgb2YgdGhlIGdhbWUKCiMgUmV0dXJucwoqIGBCZWFjb25gIC0gVGhlIHJvdW5kJ3MgY29tbWl0bWVudHMsIHNoYXJlcyBhbmQgZGVhZGxpbmVzAAAACmdldF9iZWFjb24AAAAAAAEAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAABAAAD6QAAB9AAAAAGQmVhY29uAAAAAAAD",
+        "AAAAAAAAAS9HZXQgdGhlIHNhbHQgdGhlIGN1cnJlbnQgcm91bmQncyBoaWRkZW4gaGFuZHMgYXJlIGRlYWx0IGZyb20uIEVhY2gKcGxheWVyIGRlYWxzIHRoZWlyIG93biBoYW5kIGZyb20gaXQgYW5kIHRoZWlyIHNlY3JldCBvZmYtY2hhaW4sIHRoZQpzYW1lIHdheSBgcmV2ZWFsX3dpbm5lcmAgZG9lcy4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVGhlIHNlc3Npb24gSUQgb2YgdGhlIGdhbWUKCiMgUmV0dXJucwoqIGBCeXRlc048MzI+YCAtIFRoZSByb3VuZCdzIGRlYWwgc2FsdCwgb25jZSBib3RoIHNoYXJlcyBhcmUgcmV2ZWFsZWQAAAAADWdldF9kZWFsX3NhbHQAAAAAAAABAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAQAAA+kAAAPuAAAAIAAAAAM=",
         "AAAAAAAAAEhHZXQgdGhlIGN1cnJlbnQgYWRtaW4gYWRkcmVzcwoKIyBSZXR1cm5zCiogYEFkZHJlc3NgIC0gVGhlIGFkbWluIGFkZHJlc3MAAAAJZ2V0X2FkbWluAAAAAAAAAAAAAAEAAAAT",
         "AAAAAAAAAEpTZXQgYSBuZXcgYWRtaW4gYWRkcmVzcwoKIyBBcmd1bWVudHMKKiBgbmV3X2FkbWluYCAtIFRoZSBuZXcgYWRtaW4gYWRkcmVzcwAAAAAACXNldF9hZG1pbgAAAAAAAAEAAAAAAAAACW5ld19hZG1pbgAAAAAAABMAAAAA",
-        "AAAAAAAAAlFTdGFydCBhIG5ldyBnYW1lIGJldHdlZW4gdHdvIHBsYXllcnMgd2l0aCBwb2ludHMuClRoaXMgY3JlYXRlcyBhIHNlc3Npb24gaW4gdGhlIEdhbWUgSHViIGFuZCBsb2NrcyBwb2ludHMgYmVmb3JlIHN0YXJ0aW5nIHRoZSBnYW1lLgpFYWNoIHBsYXllciBpcyBkZWFsdCAyIGNhcmRzIHRvIHN0YXJ0LgoKKipDUklUSUNBTDoqKiBUaGlzIG1ldGhvZCByZXF1aXJlcyBhdXRob3JpemF0aW9uIGZyb20gVEhJUyBjb250cmFjdCAobm90IHBsYXllcnMpLgpUaGUgR2FtZSBIdWIgd2lsbCBjYWxsIGBnYW1lX2lkLnJlcXVpcmVfYXV0aCgpYCB3aGljaCBjaGVja3MgdGhpcyBjb250cmFjdCdzIGFkZHJlc3MuCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIFVuaXF1ZSBzZXNzaW9uIGlkZW50aWZpZXIgKHUzMikKKiBgcGxheWVyMWAgLSBBZGRyZXNzIG9mIGZpcnN0IHBsYXllcgoqIGBwbGF5ZXIyYCAtIEFkZHJlc3Mgb2Ygc2Vjb25kIHBsYXllcgoqIGBwbGF5ZXIxX3BvaW50c2AgLSBQb2ludHMgYW1vdW50IGNvbW1pdHRlZCBieSBwbGF5ZXIgMQoqIGBwbGF5ZXIyX3BvaW50c2AgLSBQb2ludHMgYW1vdW50IGNvbW1pdHRlZCBieSBwbGF5ZXIgMgAAAAAAAApzdGFydF9nYW1lAAAAAAAFAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAdwbGF5ZXIxAAAAABMAAAAAAAAAB3BsYXllcjIAAAAAEwAAAAAAAAAOcGxheWVyMV9wb2ludHMAAAAAAAsAAAAAAAAADnBsYXllcjJfcG9pbnRzAAAAAAALAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
-        "AAAAAAAAAKNJbml0aWFsaXplIHRoZSBjb250cmFjdCB3aXRoIEdhbWVIdWIgYWRkcmVzcyBhbmQgYWRtaW4KCiMgQXJndW1lbnRzCiogYGFkbWluYCAtIEFkbWluIGFkZHJlc3MgKGNhbiB1cGdyYWRlIGNvbnRyYWN0KQoqIGBnYW1lX2h1YmAgLSBBZGRyZXNzIG9mIHRoZSBHYW1lSHViIGNvbnRyYWN0AAAAAA1fX2NvbnN0cnVjdG9yAAAAAAAAAgAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAAAAAAhnYW1lX2h1YgAAABMAAAAA",
-        "AAAAAAAAAUpSZXZlYWwgdGhlIHdpbm5lciBvZiB0aGUgZ2FtZSBhbmQgc3VibWl0IG91dGNvbWUgdG8gR2FtZUh1Yi4KQ2FuIG9ubHkgYmUgY2FsbGVkIGFmdGVyIGJvdGggcGxheWVycyBoYXZlIHN0dWNrLgpUaGlzIGNhbGN1bGF0ZXMgaGFuZCB2YWx1ZXMsIGRldGVybWluZXMgdGhlIHdpbm5lciAoY2xvc2VzdCB0byAyMSksCmFuZCBoYW5kbGVzIGRyYXdzIGJ5IGRlYWxpbmcgbmV3IGhhbmRzLgoKIyBBcmd1bWVudHMKKiBgc2Vzc2lvbl9pZGAgLSBUaGUgc2Vzc2lvbiBJRCBvZiB0aGUgZ2FtZQoKIyBSZXR1cm5zCiogYEFkZHJlc3NgIC0gQWRkcmVzcyBvZiB0aGUgd2lubmluZyBwbGF5ZXIAAAAAAA1yZXZlYWxfd2lubmVyAAAAAAAAAQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAPpAAAAEwAAAAM=",
-        "AAAAAAAAAL5HZXQgdGhlIGN1cnJlbnQgaGFuZCB2YWx1ZSBmb3IgYSBwbGF5ZXIuCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIFRoZSBzZXNzaW9uIElEIG9mIHRoZSBnYW1lCiogYHBsYXllcmAgLSBBZGRyZXNzIG9mIHRoZSBwbGF5ZXIKCiMgUmV0dXJucwoqIGB1MzJgIC0gVGhlIHRvdGFsIHZhbHVlIG9mIHRoZSBwbGF5ZXIncyBoYW5kAAAAAAAOZ2V0X2hhbmRfdmFsdWUAAAAAAAIAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAPpAAAABAAAAAM=" ]),
+        "AAAAAAAAAF5HZXQgdGhlIGN1cnJlbnQgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzCgojIFJldHVybnMKKiBgQWRkcmVzc2AgLSBUaGUgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzAAAAAAAHZ2V0X2h1YgAAAAAAAAAAAQAAABM=",
+        "AAAAAAAAAF5TZXQgYSBuZXcgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzCgojIEFyZ3VtZW50cwoqIGBuZXdfaHViYCAtIFRoZSBuZXcgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzAAAAAAAHc2V0X2h1YgAAAAABAAAAAAAAAAduZXdfaHViAAAAABMAAAAA",
+        "AAAAAAAAAIVHZXQgdGhlIHJhbmRvbW5lc3MgcHJvdmlkZXIgbWl4ZWQgaW50byBoaWRkZW4gaGFuZHMsIGlmIGFueQoKIyBSZXR1cm5zCiogYE9wdGlvbjxBZGRyZXNzPmAgLSBUaGUgcmFuZG9tbmVzcyBwcm92aWRlciBjb250cmFjdCBhZGRyZXNzAAAAAAAAF2dldF9yYW5kb21uZXNzX3Byb3ZpZGVyAAAAAAAAAAABAAAD6AAAABM=",
+        "AAAAAAAAAHVHZXQgdGhlIG51bWJlciBvZiBsZWRnZXJzIHBsYXllcnMgaGF2ZSB0byBwbGF5LCBhbmQgdGhlbiB0byByZXZlYWwKCiMgUmV0dXJucwoqIGB1MzJgIC0gVGhlIGFjdGlvbiB0aW1lb3V0IGluIGxlZGdlcnMAAAAAAAASZ2V0X2FjdGlvbl90aW1lb3V0AAAAAAAAAAAAAQAAAAQ=",
+        "AAAAAAAAAIZTZXQgdGhlIGFjdGlvbiB0aW1lb3V0IGZvciBuZXcgcGhhc2VzIChnYW1lcyBpbiBwcm9ncmVzcyBrZWVwIHRoZWlyIGRlYWRsaW5lKQoKIyBBcmd1bWVudHMKKiBgbGVkZ2Vyc2AgLSBUaGUgYWN0aW9uIHRpbWVvdXQgaW4gbGVkZ2VycwAAAAAAEnNldF9hY3Rpb25fdGltZW91dAAAAAAAAQAAAAAAAAAHbGVkZ2VycwAAAAAEAAAAAA==",
+        "AAAAAAAAAHFVcGRhdGUgdGhlIGNvbnRyYWN0IFdBU00gaGFzaCAodXBncmFkZSBjb250cmFjdCkKCiMgQXJndW1lbnRzCiogYG5ld193YXNtX2hhc2hgIC0gVGhlIGhhc2ggb2YgdGhlIG5ldyBXQVNNIGJpbmFyeQAAAAAAAAd1cGdyYWRlAAAAAAEAAAAAAAAADW5ld193YXNtX2hhc2gAAAAAAAPuAAAAIAAAAAA=" ]),
       options
     )
   }
   public readonly fromJSON = {
-    hit: this.txFromJSON<Result<void>>,
+    start_game: this.txFromJSON<Result<void>>,
+        start_hidden_game: this.txFromJSON<Result<void>>,
+        commit_hand: this.txFromJSON<Result<void>>,
+        reveal_share: this.txFromJSON<Result<void>>,
+        hit: this.txFromJSON<Result<void>>,
         stick: this.txFromJSON<Result<void>>,
-        get_hub: this.txFromJSON<string>,
-        set_hub: this.txFromJSON<null>,
-        upgrade: this.txFromJSON<null>,
+        reveal_hand: this.txFromJSON<Result<void>>,
+        reveal_winner: this.txFromJSON<Result<Option<string>>>,
+        claim_timeout: this.txFromJSON<Result<string>>,
+        cancel_game: this.txFromJSON<Result<void>>,
+        resign: this.txFromJSON<Result<string>>,
+        offer_draw: this.txFromJSON<Result<void>>,
+        accept_draw: this.txFromJSON<Result<void>>,
         get_game: this.txFromJSON<Result<Game>>,
+        get_hand_value: this.txFromJSON<Result<u32>>,
+        get_beacon: this.txFromJSON<Result<Beacon>>,
+        get_deal_salt: this.txFromJSON<Result<Buffer>>,
         get_admin: this.txFromJSON<string>,
         set_admin: this.txFromJSON<null>,
-        start_game: this.txFromJSON<Result<void>>,
-        reveal_winner: this.txFromJSON<Result<string>>,
-        get_hand_value: this.txFromJSON<Result<u32>>
+        get_hub: this.txFromJSON<string>,
+        set_hub: this.txFromJSON<null>,
+        get_randomness_provider: this.txFromJSON<Option<string>>,
+        get_action_timeout: this.txFromJSON<u32>,
+        set_action_timeout: this.txFromJSON<null>,
+        upgrade: this.txFromJSON<null>
   }
 }
\ No newline at end of file
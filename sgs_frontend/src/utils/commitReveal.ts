@@ -0,0 +1,71 @@
+/**
+ * Commit-reveal utility functions
+ *
+ * Games that commit-reveal have each player submit `keccak256(secret data)`
+ * first and the data itself once both players have committed. The secret
+ * data only exists in the committing browser until then, so it is kept in
+ * localStorage, keyed by game contract, session and player.
+ */
+
+import { keccak_256 } from '@noble/hashes/sha3';
+import { Buffer } from 'buffer';
+
+/**
+ * keccak256 hash, as computed on-chain by `env.crypto().keccak256`
+ * @param data - The bytes to hash
+ * @returns 32-byte hash
+ */
+export function keccak256(data: Uint8Array): Buffer {
+  return Buffer.from(keccak_256(data));
+}
+
+/**
+ * Generate a fresh secret from the browser's cryptographic RNG
+ * @returns 32 random bytes
+ */
+export function randomSecret(): Buffer {
+  return Buffer.from(crypto.getRandomValues(new Uint8Array(32)));
+}
+
+function secretKey(contractId: string, sessionId: number, player: string): string {
+  return `commit-reveal:${contractId}:${sessionId}:${player}`;
+}
+
+/**
+ * Store the data a player committed to, so it can be revealed later
+ * @param contractId - The game contract the commitment was sent to
+ * @param sessionId - The game session
+ * @param player - The committing player's address
+ * @param secret - JSON-serializable secret data
+ */
+export function saveCommitSecret<T>(contractId: string, sessionId: number, player: string, secret: T): void {
+  localStorage.setItem(secretKey(contractId, sessionId, player), JSON.stringify(secret));
+}
+
+/**
+ * Load the data a player committed to from this browser
+ * @returns The stored secret data, or null if it was committed elsewhere
+ */
+export function loadCommitSecret<T>(contractId: string, sessionId: number, player: string): T | null {
+  const stored = localStorage.getItem(secretKey(contractId, sessionId, player));
+  return stored === null ? null : (JSON.parse(stored) as T);
+}
+
+/**
+ * Forget a player's secret data once it has been revealed
+ */
+export function clearCommitSecret(contractId: string, sessionId: number, player: string): void {
+  localStorage.removeItem(secretKey(contractId, sessionId, player));
+}
+
+/**
+ * Whether a beacon's commitments or reveals hold an entry for `player`.
+ * Contract maps decode either as a Map or as a plain object.
+ */
+export function hasBeaconEntry(
+  entries: Map<string, Buffer> | Record<string, Buffer> | undefined,
+  player: string
+): boolean {
+  if (!entries) return false;
+  return entries instanceof Map ? entries.has(player) : player in entries;
+}